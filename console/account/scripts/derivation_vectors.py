@@ -0,0 +1,235 @@
+#!/usr/bin/env python3
+"""Generates the known-answer vectors for hierarchical deterministic accounts and mnemonics.
+
+This is an independent implementation of the Testnet3 account derivation, used to compute the vectors in
+`src/private_key/derive.rs` and `src/mnemonic/string.rs`. It implements the Poseidon sponge (including the
+Grain LFSR parameter generation), the Edwards BLS12 curve, and the key encodings from their specifications,
+and does not share any code with snarkVM.
+
+Before printing any vectors, it checks that it reproduces the Poseidon parameter snapshots in
+`console/algorithms`, and the account vector in `src/lib.rs`. The group generator is recovered from that
+account vector, as `address / view_key`.
+
+Usage: `python3 console/account/scripts/derivation_vectors.py`
+"""
+
+import hashlib
+import json
+import os
+
+P = 8444461749428370424248824938781546531375899335154063827935233455917409239041
+R = 2111115437357092606062206234695386632838870926408408195193685246394721360383
+NBITS = 253
+
+class LFSR:
+    def __init__(self, t, rf=8, rp=31):
+        s = [0]*80
+        s[1] = 1
+        def put(lo, hi, v):
+            for i in range(hi, lo-1, -1):
+                s[i] = v & 1; v >>= 1
+        put(6, 17, NBITS); put(18, 29, t); put(30, 39, rf); put(40, 49, rp)
+        for i in range(50, 80): s[i] = 1
+        self.s = s; self.h = 0
+        for _ in range(160): self.nb()
+    def nb(self):
+        s, h = self.s, self.h
+        b = s[(h+62)%80]^s[(h+51)%80]^s[(h+38)%80]^s[(h+23)%80]^s[(h+13)%80]^s[h]
+        s[h] = b; self.h = (h+1)%80
+        return b
+    def bits(self, n):
+        out = []
+        for _ in range(n):
+            b = self.nb()
+            while not b:
+                self.nb(); b = self.nb()
+            out.append(self.nb())
+        return out
+    def num(self):
+        v = 0
+        for b in self.bits(NBITS): v = (v << 1) | b
+        return v
+    def rej(self, n):
+        out = []
+        for _ in range(n):
+            while True:
+                v = self.num()
+                if v < P: out.append(v); break
+        return out
+    def modp(self, n):
+        return [self.num() % P for _ in range(n)]
+
+_params = {}
+def params(rate):
+    if rate not in _params:
+        t = rate + 1
+        l = LFSR(t)
+        ark = [l.rej(t) for _ in range(39)]
+        xs = l.modp(t); ys = l.modp(t)
+        mds = [[pow(x+y, P-2, P) for y in ys] for x in xs]
+        _params[rate] = (ark, mds)
+    return _params[rate]
+
+def permute(st, rate):
+    ark, mds = params(rate)
+    for i in range(39):
+        st = [(a+b) % P for a, b in zip(st, ark[i])]
+        if 4 <= i < 35:
+            st[0] = pow(st[0], 17, P)
+        else:
+            st = [pow(a, 17, P) for a in st]
+        st = [sum(m*a for m, a in zip(row, st)) % P for row in mds]
+    return st
+
+def domain(s):
+    return int.from_bytes(s.encode(), 'little') % P
+
+def hash_many(rate, dom, inp, nout=1):
+    pre = [domain(dom), len(inp)] + [0]*(rate-2) + list(inp)
+    st = [0]*(rate+1)
+    idx = 0
+    for x in pre:
+        if idx == rate:
+            st = permute(st, rate); idx = 0
+        st[1+idx] = (st[1+idx] + x) % P; idx += 1
+    st = permute(st, rate)
+    assert nout <= rate
+    return st[1:1+nout]
+
+def hash_psd(rate, inp): return hash_many(rate, "AleoPoseidon%d" % rate, inp)[0]
+def hash_to_scalar(rate, inp): return hash_psd(rate, inp) & ((1 << 250) - 1)
+
+# Edwards curve a = -1, d = 3021.
+D = 3021
+def add(p, q):
+    (x1, y1), (x2, y2) = p, q
+    t = D*x1*x2*y1*y2 % P
+    x3 = (x1*y2 + y1*x2) * pow(1+t, P-2, P) % P
+    y3 = (y1*y2 + x1*x2) * pow(1-t, P-2, P) % P
+    return (x3, y3)
+def mul(k, p):
+    r = (0, 1)
+    while k:
+        if k & 1: r = add(r, p)
+        p = add(p, p); k >>= 1
+    return r
+
+def sqrt(a):
+    # Tonelli-Shanks.
+    q, s = P - 1, 0
+    while q % 2 == 0: q //= 2; s += 1
+    z = 2
+    while pow(z, (P - 1) // 2, P) != P - 1: z += 1
+    m, c, t, r = s, pow(z, q, P), pow(a, q, P), pow(a, (q + 1) // 2, P)
+    while t != 1:
+        i, t2 = 0, t
+        while t2 != 1: t2 = t2 * t2 % P; i += 1
+        b = pow(c, 1 << (m - i - 1), P)
+        m, c, t, r = i, b * b % P, t * b * b % P, r * b % P
+    return r
+def from_x(x):
+    y2 = (1 + x*x) * pow(1 - D*x*x, P-2, P) % P
+    y = sqrt(y2)
+    for yy in (y, P - y):
+        pt = (x, yy)
+        if mul(R, pt) == (0, 1): return pt
+    raise Exception("no subgroup point")
+
+B58 = '123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz'
+def b58enc(b):
+    n = int.from_bytes(b, 'big'); s = ''
+    while n: n, r = divmod(n, 58); s = B58[r] + s
+    return '1'*(len(b) - len(b.lstrip(b'\0'))) + s
+def b58dec(s):
+    n = 0
+    for c in s: n = n*58 + B58.index(c)
+    b = n.to_bytes((n.bit_length()+7)//8, 'big')
+    return b'\0'*(len(s)-len(s.lstrip('1'))) + b
+
+CH = 'qpzry9x8gf2tvdw0s3jn54khce6mua7l'
+def polymod(v):
+    g = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3]; c = 1
+    for x in v:
+        b = c >> 25; c = (c & 0x1ffffff) << 5 ^ x
+        for i in range(5): c ^= g[i] if (b >> i) & 1 else 0
+    return c
+def hrp_exp(h): return [ord(x) >> 5 for x in h] + [0] + [ord(x) & 31 for x in h]
+def bech32m(hrp, data8):
+    acc = 0; bits = 0; d = []
+    for b in data8:
+        acc = (acc << 8) | b; bits += 8
+        while bits >= 5: bits -= 5; d.append((acc >> bits) & 31)
+    if bits: d.append((acc << (5-bits)) & 31)
+    pm = polymod(hrp_exp(hrp) + d + [0]*6) ^ 0x2bc830a3
+    return hrp + '1' + ''.join(CH[x] for x in d + [(pm >> 5*(5-i)) & 31 for i in range(6)])
+def bech32m_dec(s):
+    hrp, data = s.rsplit('1', 1)
+    d = [CH.index(c) for c in data[:-6]]
+    acc = 0; bits = 0; out = []
+    for x in d:
+        acc = (acc << 5) | x; bits += 5
+        if bits >= 8: bits -= 8; out.append((acc >> bits) & 255)
+    return bytes(out)
+
+PK_PREFIX = bytes([127, 134, 189, 116, 210, 221, 210, 137, 145, 18, 253])
+VK_PREFIX = bytes([14, 138, 223, 204, 247, 224, 122])
+
+ALEO_PRIVATE_KEY = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p"
+ALEO_VIEW_KEY = "AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD"
+ALEO_ADDRESS = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf"
+
+def seed_of(pk):
+    b = b58dec(pk); assert b[:11] == PK_PREFIX and len(b) == 43
+    return int.from_bytes(b[11:], 'little')
+def vk_of(vks):
+    b = b58dec(vks); assert b[:7] == VK_PREFIX
+    return int.from_bytes(b[7:], 'little')
+
+addr_x = int.from_bytes(bech32m_dec(ALEO_ADDRESS), 'little')
+G = mul(pow(vk_of(ALEO_VIEW_KEY), -1, R), from_x(addr_x))
+
+def account(seed):
+    sk_sig = hash_to_scalar(2, [domain("AleoAccountSignatureSecretKey0"), seed])
+    r_sig = hash_to_scalar(2, [domain("AleoAccountSignatureRandomizer0.0"), seed])
+    pk_sig = mul(sk_sig, G); pr_sig = mul(r_sig, G)
+    sk_prf = hash_to_scalar(4, [pk_sig[0], pr_sig[0]])
+    vk = (sk_sig + r_sig + sk_prf) % R
+    pk = b58enc(PK_PREFIX + seed.to_bytes(32, 'little'))
+    vks = b58enc(VK_PREFIX + vk.to_bytes(32, 'little'))
+    addr = bech32m("aleo", mul(vk, G)[0].to_bytes(32, 'little'))
+    return pk, vks, addr
+
+HARDENED = 0x80000000
+def child(seed, index):
+    assert index < HARDENED
+    return hash_psd(4, [domain("AleoAccountChildSeed0"), seed, HARDENED | index])
+
+
+# The mnemonic encoding, as 17 proquints of the 32 seed bytes and a 2-byte SHA-256 checksum.
+C = 'bdfghjklmnprstvz'; V = 'aiou'
+def word(b): return C[b>>12&15]+V[b>>10&3]+C[b>>6&15]+V[b>>4&3]+C[b&15]
+def mnemonic(seed):
+    b = seed.to_bytes(32, 'little'); b += hashlib.sha256(b).digest()[:2]
+    return ' '.join(word(int.from_bytes(b[i:i+2], 'big')) for i in range(0, 34, 2))
+
+def check():
+    resources = os.path.join(os.path.dirname(os.path.abspath(__file__)), '../../algorithms/src/poseidon/resources')
+    load = lambda path: json.load(open(os.path.join(resources, path)))
+    assert LFSR(3).rej(1) == load('test_grain_lfsr/first_sample.snap'), "The Grain LFSR sample does not match"
+    for rate in (2, 4):
+        ark, mds = params(rate)
+        assert ark == load('test_parameters/rate_%d_ark.snap' % rate), "The ARK for rate %d does not match" % rate
+        assert mds == load('test_parameters/rate_%d_mds.snap' % rate), "The MDS for rate %d does not match" % rate
+    seed = seed_of(ALEO_PRIVATE_KEY)
+    assert account(seed) == (ALEO_PRIVATE_KEY, ALEO_VIEW_KEY, ALEO_ADDRESS), "The account vector does not match"
+
+if __name__ == '__main__':
+    check()
+    for seed in (1, 0x1234, 2**64 - 1):
+        print(seed, mnemonic(seed))
+    seed = seed_of(ALEO_PRIVATE_KEY)
+    print(ALEO_PRIVATE_KEY, mnemonic(seed))
+    for path in ([0], [1], [2**31 - 1], [44, 0, 0], [44, 0, 1]):
+        s = seed
+        for index in path: s = child(s, index)
+        print('m/' + '/'.join("%d'" % index for index in path), *account(s))
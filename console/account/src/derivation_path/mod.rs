@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod string;
+
+use snarkvm_console_network::prelude::*;
+
+/// The offset of hardened child indices, following BIP-32 path notation.
+pub const HARDENED_OFFSET: u32 = 1 << 31;
+
+/// A path of hardened child indices, written as `m/0'/1'/2'`, that selects a descendant of a master account.
+///
+/// Aleo private keys are derived from their account seed by hashing, so a child address cannot be derived
+/// from the parent address or compute key alone. As a result, only hardened derivation is supported.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DerivationPath {
+    /// The child indices, without the hardened offset.
+    indices: Vec<u32>,
+}
+
+impl DerivationPath {
+    /// The maximum depth of a derivation path.
+    pub const MAX_DEPTH: usize = 255;
+
+    /// Initializes a new derivation path from the given child indices.
+    pub fn new(indices: &[u32]) -> Result<Self> {
+        // Ensure the path does not exceed the maximum depth.
+        ensure!(indices.len() <= Self::MAX_DEPTH, "Derivation path exceeds the maximum depth of {}", Self::MAX_DEPTH);
+        // Ensure the indices are within the hardened range.
+        for index in indices {
+            ensure!(*index < HARDENED_OFFSET, "Child index {index} exceeds the maximum of {}", HARDENED_OFFSET - 1);
+        }
+        Ok(Self { indices: indices.to_vec() })
+    }
+
+    /// Returns the master path, `m`.
+    pub const fn master() -> Self {
+        Self { indices: Vec::new() }
+    }
+
+    /// Returns the path extended by the given child index.
+    pub fn child(&self, index: u32) -> Result<Self> {
+        let mut indices = self.indices.clone();
+        indices.push(index);
+        Self::new(&indices)
+    }
+
+    /// Returns the child indices, without the hardened offset.
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    /// Returns the depth of the path.
+    pub fn depth(&self) -> usize {
+        self.indices.len()
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    /// Reads in a derivation path of the form `m/0'/1'/2'`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut components = s.split('/');
+        // Ensure the path starts at the master account.
+        match components.next() {
+            Some("m") => (),
+            _ => bail!("Derivation path '{s}' must start with 'm'"),
+        }
+        // Parse the child indices.
+        let indices = components
+            .map(|component| {
+                let Some(index) = component.strip_suffix('\'') else {
+                    bail!(
+                        "Child index '{component}' must be hardened, as Aleo accounts only support hardened derivation"
+                    )
+                };
+                // Ensure the index is in canonical decimal form.
+                ensure!(
+                    !index.is_empty()
+                        && index.bytes().all(|byte| byte.is_ascii_digit())
+                        && (index == "0" || !index.starts_with('0')),
+                    "Child index '{component}' is not a canonical decimal"
+                );
+                index.parse::<u32>().map_err(|_| anyhow!("Child index '{component}' is not a valid index"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::new(&indices)
+    }
+}
+
+impl Display for DerivationPath {
+    /// Writes the derivation path in the form `m/0'/1'/2'`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "m")?;
+        for index in &self.indices {
+            write!(f, "/{index}'")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        for (string, indices) in [
+            ("m", vec![]),
+            ("m/0'", vec![0]),
+            ("m/44'/0'/7'", vec![44, 0, 7]),
+            ("m/2147483647'", vec![HARDENED_OFFSET - 1]),
+        ] {
+            let candidate = DerivationPath::from_str(string)?;
+            assert_eq!(indices, candidate.indices());
+            assert_eq!(string, candidate.to_string());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_string() {
+        for string in
+            ["", "m/", "n/0'", "/0'", "m/0", "m/1'/2", "m/01'", "m/+1'", "m/-1'", "m/2147483648'", "m/a'", "m/0''"]
+        {
+            assert!(DerivationPath::from_str(string).is_err(), "'{string}' should be rejected");
+        }
+        // Ensure the maximum depth is enforced.
+        let string = format!("m{}", "/0'".repeat(DerivationPath::MAX_DEPTH + 1));
+        assert!(DerivationPath::from_str(&string).is_err());
+    }
+}
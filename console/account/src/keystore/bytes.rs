@@ -0,0 +1,85 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for Keystore<N> {
+    /// Reads a keystore from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != Self::VERSION {
+            return Err(error(format!("Invalid keystore version: found {version}, expected {}", Self::VERSION)));
+        }
+        // Read the address.
+        let address = Address::read_le(&mut reader)?;
+        // Read the KDF parameters.
+        let log_n = u8::read_le(&mut reader)?;
+        let r = u32::read_le(&mut reader)?;
+        let p = u32::read_le(&mut reader)?;
+        let salt = FromBytes::read_le(&mut reader)?;
+        // Read the nonce.
+        let nonce = FromBytes::read_le(&mut reader)?;
+        // Read the ciphertext.
+        let ciphertext = FromBytes::read_le(&mut reader)?;
+        // Return the keystore.
+        Ok(Self { address, log_n, r, p, salt, nonce, ciphertext })
+    }
+}
+
+impl<N: Network> ToBytes for Keystore<N> {
+    /// Writes a keystore to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        Self::VERSION.write_le(&mut writer)?;
+        // Write the address.
+        self.address.write_le(&mut writer)?;
+        // Write the KDF parameters.
+        self.log_n.write_le(&mut writer)?;
+        self.r.write_le(&mut writer)?;
+        self.p.write_le(&mut writer)?;
+        self.salt.write_le(&mut writer)?;
+        // Write the nonce.
+        self.nonce.write_le(&mut writer)?;
+        // Write the ciphertext.
+        self.ciphertext.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            // Sample a new keystore.
+            let (private_key, expected) = test_helpers::sample_keystore("password", &mut rng);
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            let candidate = Keystore::<CurrentNetwork>::read_le(&expected_bytes[..])?;
+            assert_eq!(expected, candidate);
+            assert_eq!(private_key, candidate.decrypt("password")?);
+            assert!(Keystore::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,241 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::{Address, PrivateKey};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+use chacha20poly1305::{
+    aead::{Aead, NewAead, Payload},
+    Key,
+    XChaCha20Poly1305,
+    XNonce,
+};
+
+/// The number of bytes in the KDF salt.
+const SALT_SIZE_IN_BYTES: usize = 32;
+/// The number of bytes in the cipher nonce.
+const NONCE_SIZE_IN_BYTES: usize = 24;
+/// The number of bytes in the ciphertext, which is the encrypted account seed followed by the authentication tag.
+const CIPHERTEXT_SIZE_IN_BYTES: usize = 32 + 16;
+
+/// A password-protected private key.
+///
+/// The account seed is encrypted with XChaCha20-Poly1305 under a key derived from the password with scrypt.
+/// The version, address hint, and KDF parameters are authenticated as associated data.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Keystore<N: Network> {
+    /// The address of the encrypted private key.
+    address: Address<N>,
+    /// The scrypt cost parameter, as a base-2 logarithm.
+    log_n: u8,
+    /// The scrypt block size parameter.
+    r: u32,
+    /// The scrypt parallelization parameter.
+    p: u32,
+    /// The scrypt salt.
+    salt: [u8; SALT_SIZE_IN_BYTES],
+    /// The cipher nonce.
+    nonce: [u8; NONCE_SIZE_IN_BYTES],
+    /// The encrypted account seed and authentication tag.
+    ciphertext: [u8; CIPHERTEXT_SIZE_IN_BYTES],
+}
+
+impl<N: Network> Keystore<N> {
+    /// The version of the keystore format.
+    pub const VERSION: u8 = 1;
+    /// The default scrypt cost parameter, as a base-2 logarithm.
+    pub const DEFAULT_LOG_N: u8 = 15;
+    /// The default scrypt block size parameter.
+    pub const DEFAULT_R: u32 = 8;
+    /// The default scrypt parallelization parameter.
+    pub const DEFAULT_P: u32 = 1;
+    /// The maximum scrypt cost parameter, which bounds the memory used to unlock a keystore to 1 GiB with the default block size.
+    pub const MAX_LOG_N: u8 = 20;
+    /// The maximum scrypt block size parameter.
+    pub const MAX_R: u32 = 32;
+    /// The maximum scrypt parallelization parameter.
+    pub const MAX_P: u32 = 16;
+
+    /// Encrypts the given private key under the given password, with the default KDF parameters.
+    pub fn new<R: Rng + CryptoRng>(private_key: &PrivateKey<N>, password: &str, rng: &mut R) -> Result<Self> {
+        Self::new_with_params(private_key, password, Self::DEFAULT_LOG_N, Self::DEFAULT_R, Self::DEFAULT_P, rng)
+    }
+
+    /// Encrypts the given private key under the given password, with the given KDF parameters.
+    pub fn new_with_params<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        password: &str,
+        log_n: u8,
+        r: u32,
+        p: u32,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Sample a random salt and nonce.
+        let salt = rng.gen::<[u8; SALT_SIZE_IN_BYTES]>();
+        let nonce = rng.gen::<[u8; NONCE_SIZE_IN_BYTES]>();
+        // Construct the keystore with an empty ciphertext.
+        let mut keystore = Self {
+            address: Address::try_from(private_key)?,
+            log_n,
+            r,
+            p,
+            salt,
+            nonce,
+            ciphertext: [0u8; CIPHERTEXT_SIZE_IN_BYTES],
+        };
+        // Encrypt the account seed.
+        let seed = private_key.seed().to_bytes_le()?;
+        let ciphertext = keystore
+            .cipher(password)?
+            .encrypt(XNonce::from_slice(&nonce), Payload { msg: &seed, aad: &keystore.associated_data()? })
+            .map_err(|_| anyhow!("Failed to encrypt the private key"))?;
+        keystore.ciphertext =
+            ciphertext.try_into().map_err(|_| anyhow!("Expected a ciphertext of {CIPHERTEXT_SIZE_IN_BYTES} bytes"))?;
+        Ok(keystore)
+    }
+
+    /// Decrypts the private key with the given password.
+    pub fn decrypt(&self, password: &str) -> Result<PrivateKey<N>> {
+        // Decrypt the account seed.
+        let seed = self
+            .cipher(password)?
+            .decrypt(XNonce::from_slice(&self.nonce), Payload { msg: &self.ciphertext, aad: &self.associated_data()? })
+            .map_err(|_| anyhow!("Failed to unlock the keystore: incorrect password or corrupted keystore"))?;
+        // Recover the private key.
+        let private_key = PrivateKey::try_from(Field::<N>::from_bytes_le(&seed)?)?;
+        // Ensure the private key matches the address hint.
+        ensure!(
+            Address::try_from(&private_key)? == self.address,
+            "The keystore address does not match its private key"
+        );
+        Ok(private_key)
+    }
+
+    /// Returns the address of the encrypted private key.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+}
+
+impl<N: Network> Keystore<N> {
+    /// Returns the cipher keyed by the given password.
+    fn cipher(&self, password: &str) -> Result<XChaCha20Poly1305> {
+        // Ensure the KDF parameters are within the supported bounds.
+        ensure!(self.log_n <= Self::MAX_LOG_N, "The keystore scrypt cost exceeds the maximum of 2^{}", Self::MAX_LOG_N);
+        ensure!(self.r <= Self::MAX_R, "The keystore scrypt block size exceeds the maximum of {}", Self::MAX_R);
+        ensure!(self.p <= Self::MAX_P, "The keystore scrypt parallelization exceeds the maximum of {}", Self::MAX_P);
+        // Derive the encryption key.
+        let params = scrypt::Params::new(self.log_n, self.r, self.p, 32)
+            .map_err(|error| anyhow!("Invalid keystore scrypt parameters: {error}"))?;
+        let mut key = [0u8; 32];
+        scrypt::scrypt(password.as_bytes(), &self.salt, &params, &mut key)
+            .map_err(|error| anyhow!("Failed to derive the keystore key: {error}"))?;
+        Ok(XChaCha20Poly1305::new(Key::from_slice(&key)))
+    }
+
+    /// Returns the associated data, which authenticates the version, address hint, and KDF parameters.
+    fn associated_data(&self) -> Result<Vec<u8>> {
+        let mut bytes = vec![Self::VERSION];
+        self.address.write_le(&mut bytes)?;
+        self.log_n.write_le(&mut bytes)?;
+        self.r.write_le(&mut bytes)?;
+        self.p.write_le(&mut bytes)?;
+        bytes.extend_from_slice(&self.salt);
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// A low scrypt cost, to keep the tests fast.
+    pub(crate) const TEST_LOG_N: u8 = 4;
+
+    /// Samples a random private key and its keystore under the given password.
+    pub(crate) fn sample_keystore(
+        password: &str,
+        rng: &mut TestRng,
+    ) -> (PrivateKey<CurrentNetwork>, Keystore<CurrentNetwork>) {
+        let private_key = PrivateKey::new(rng).unwrap();
+        let keystore = Keystore::new_with_params(&private_key, password, TEST_LOG_N, 8, 1, rng).unwrap();
+        (private_key, keystore)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_decrypt() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            let (private_key, keystore) = test_helpers::sample_keystore("password", &mut rng);
+            assert_eq!(Address::try_from(&private_key)?, keystore.address());
+            assert_eq!(private_key, keystore.decrypt("password")?);
+
+            // Ensure an incorrect password is rejected.
+            assert!(keystore.decrypt("Password").is_err());
+            assert!(keystore.decrypt("").is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_tampered() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let (_, keystore) = test_helpers::sample_keystore("password", &mut rng);
+
+        // Ensure a modified address hint is rejected.
+        let mut candidate = keystore.clone();
+        candidate.address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        assert!(candidate.decrypt("password").is_err());
+
+        // Ensure modified KDF parameters are rejected.
+        let mut candidate = keystore.clone();
+        candidate.salt[0] ^= 1;
+        assert!(candidate.decrypt("password").is_err());
+        let mut candidate = keystore.clone();
+        candidate.log_n += 1;
+        assert!(candidate.decrypt("password").is_err());
+
+        // Ensure a modified nonce or ciphertext is rejected.
+        let mut candidate = keystore.clone();
+        candidate.nonce[0] ^= 1;
+        assert!(candidate.decrypt("password").is_err());
+        let mut candidate = keystore.clone();
+        candidate.ciphertext[0] ^= 1;
+        assert!(candidate.decrypt("password").is_err());
+
+        // Ensure an excessive scrypt cost is rejected before deriving the key.
+        let mut candidate = keystore;
+        candidate.log_n = Keystore::<CurrentNetwork>::MAX_LOG_N + 1;
+        assert!(candidate.decrypt("password").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for Keystore<N> {
+    /// Serializes the keystore into a JSON object or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut keystore = serializer.serialize_struct("Keystore", 10)?;
+                keystore.serialize_field("version", &Self::VERSION)?;
+                keystore.serialize_field("address", &self.address)?;
+                keystore.serialize_field("kdf", KDF)?;
+                keystore.serialize_field("log_n", &self.log_n)?;
+                keystore.serialize_field("r", &self.r)?;
+                keystore.serialize_field("p", &self.p)?;
+                keystore.serialize_field("salt", &hex::encode(self.salt))?;
+                keystore.serialize_field("cipher", CIPHER)?;
+                keystore.serialize_field("nonce", &hex::encode(self.nonce))?;
+                keystore.serialize_field("ciphertext", &hex::encode(self.ciphertext))?;
+                keystore.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for Keystore<N> {
+    /// Deserializes the keystore from a JSON object or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the keystore from a string into a value.
+                let mut keystore = serde_json::Value::deserialize(deserializer)?;
+
+                // Ensure the version, KDF, and cipher are supported.
+                let version: u8 = DeserializeExt::take_from_value::<D>(&mut keystore, "version")?;
+                if version != Self::VERSION {
+                    return Err(de::Error::custom(format!(
+                        "Invalid keystore version: found {version}, expected {}",
+                        Self::VERSION
+                    )));
+                }
+                let kdf: String = DeserializeExt::take_from_value::<D>(&mut keystore, "kdf")?;
+                if kdf != KDF {
+                    return Err(de::Error::custom(format!("Unsupported keystore KDF '{kdf}', expected '{KDF}'")));
+                }
+                let cipher: String = DeserializeExt::take_from_value::<D>(&mut keystore, "cipher")?;
+                if cipher != CIPHER {
+                    return Err(de::Error::custom(format!(
+                        "Unsupported keystore cipher '{cipher}', expected '{CIPHER}'"
+                    )));
+                }
+
+                // Recover the keystore.
+                Ok(Self {
+                    address: DeserializeExt::take_from_value::<D>(&mut keystore, "address")?,
+                    log_n: DeserializeExt::take_from_value::<D>(&mut keystore, "log_n")?,
+                    r: DeserializeExt::take_from_value::<D>(&mut keystore, "r")?,
+                    p: DeserializeExt::take_from_value::<D>(&mut keystore, "p")?,
+                    salt: take_hex_from_value::<D, SALT_SIZE_IN_BYTES>(&mut keystore, "salt")?,
+                    nonce: take_hex_from_value::<D, NONCE_SIZE_IN_BYTES>(&mut keystore, "nonce")?,
+                    ciphertext: take_hex_from_value::<D, CIPHERTEXT_SIZE_IN_BYTES>(&mut keystore, "ciphertext")?,
+                })
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "keystore"),
+        }
+    }
+}
+
+/// The name of the keystore KDF.
+const KDF: &str = "scrypt";
+/// The name of the keystore cipher.
+const CIPHER: &str = "xchacha20-poly1305";
+
+/// Returns the fixed-size bytes of the given hex-encoded field.
+fn take_hex_from_value<'de, D: Deserializer<'de>, const SIZE: usize>(
+    value: &mut serde_json::Value,
+    field: &str,
+) -> Result<[u8; SIZE], D::Error> {
+    let string: String = DeserializeExt::take_from_value::<D>(value, field)?;
+    let bytes =
+        hex::decode(string).map_err(|error| de::Error::custom(format!("Invalid \"{field}\" field: {error}")))?;
+    bytes.try_into().map_err(|_| de::Error::custom(format!("The \"{field}\" field must be {SIZE} bytes")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            // Sample a new keystore.
+            let (private_key, expected) = test_helpers::sample_keystore("password", &mut rng);
+
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected)?;
+            assert_eq!(expected_string, &candidate_string);
+
+            // Deserialize
+            let candidate = serde_json::from_str::<Keystore<CurrentNetwork>>(&candidate_string)?;
+            assert_eq!(expected, candidate);
+            assert_eq!(private_key, candidate.decrypt("password")?);
+
+            // Ensure an unsupported version, KDF, or cipher is rejected.
+            for (field, value) in [("version", "2"), ("kdf", "\"pbkdf2\""), ("cipher", "\"aes-256-gcm\"")] {
+                let mut json = serde_json::from_str::<serde_json::Value>(&candidate_string)?;
+                json[field] = serde_json::from_str(value)?;
+                assert!(serde_json::from_value::<Keystore<CurrentNetwork>>(json).is_err());
+            }
+            // Ensure a truncated salt is rejected.
+            let mut json = serde_json::from_str::<serde_json::Value>(&candidate_string)?;
+            json["salt"] = serde_json::Value::String(hex::encode([0u8; SALT_SIZE_IN_BYTES - 1]));
+            assert!(serde_json::from_value::<Keystore<CurrentNetwork>>(json).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            // Sample a new keystore.
+            let (_, expected) = test_helpers::sample_keystore("password", &mut rng);
+
+            // Serialize
+            let expected_bytes = expected.to_bytes_le()?;
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected, Keystore::read_le(&expected_bytes[..])?);
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromStr for Keystore<N> {
+    type Err = Error;
+
+    /// Initializes the keystore from a JSON-string.
+    fn from_str(keystore: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(keystore)?)
+    }
+}
+
+impl<N: Network> Debug for Keystore<N> {
+    /// Prints the keystore as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Keystore<N> {
+    /// Displays the keystore as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            // Sample a new keystore.
+            let (private_key, expected) = test_helpers::sample_keystore("password", &mut rng);
+
+            // Check the string representation.
+            let candidate = Keystore::<CurrentNetwork>::from_str(&expected.to_string())?;
+            assert_eq!(expected, candidate);
+            assert_eq!(private_key, candidate.decrypt("password")?);
+            // Ensure the address hint is readable without the password.
+            assert!(expected.to_string().contains(&expected.address().to_string()));
+        }
+        Ok(())
+    }
+}
@@ -26,11 +26,26 @@ pub mod compute_key;
 #[cfg(feature = "compute_key")]
 pub use compute_key::*;
 
+#[cfg(feature = "private_key")]
+pub mod derivation_path;
+#[cfg(feature = "private_key")]
+pub use derivation_path::*;
+
 #[cfg(feature = "graph_key")]
 pub mod graph_key;
 #[cfg(feature = "graph_key")]
 pub use graph_key::*;
 
+#[cfg(feature = "keystore")]
+pub mod keystore;
+#[cfg(feature = "keystore")]
+pub use keystore::*;
+
+#[cfg(feature = "mnemonic")]
+pub mod mnemonic;
+#[cfg(feature = "mnemonic")]
+pub use mnemonic::*;
+
 #[cfg(feature = "private_key")]
 pub mod private_key;
 #[cfg(feature = "private_key")]
@@ -41,6 +56,11 @@ pub mod signature;
 #[cfg(feature = "signature")]
 pub use signature::*;
 
+#[cfg(feature = "threshold")]
+pub mod threshold;
+#[cfg(feature = "threshold")]
+pub use threshold::*;
+
 #[cfg(feature = "view_key")]
 pub mod view_key;
 #[cfg(feature = "view_key")]
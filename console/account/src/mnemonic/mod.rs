@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod string;
+
+use crate::{DerivationPath, PrivateKey};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+/// A mnemonic phrase that encodes the master account seed of a hierarchical deterministic wallet.
+///
+/// **This format is specific to snarkVM.** The phrase is not a BIP-39 mnemonic: it does not use the BIP-39
+/// word list, and BIP-39 wallets can neither import it nor produce it. Wallets that do not use snarkVM must
+/// implement the following encoding to interoperate:
+///  1. Serialize the seed as its 32 little-endian bytes.
+///  2. Append the first 2 bytes of the SHA-256 digest of those 32 bytes, as a checksum.
+///  3. Split the 34 bytes into 17 big-endian 16-bit values, and encode each as a
+///     proquint, a pronounceable five-letter word of the form `CVCVC`.
+///     The 4-bit consonants are `bdfghjklmnprstvz` and the 2-bit vowels are `aiou`, most significant bits first.
+///  4. Join the words with single spaces (e.g. `bahab babab ... balib` for the seed `1`).
+///
+/// Parsing is case-insensitive, accepts any whitespace between words, and rejects an invalid checksum.
+/// The phrase encodes the master seed directly, so its accounts are derived with [`PrivateKey::derive_path`].
+/// Known-answer vectors for the encoding and the derivation are generated by `scripts/derivation_vectors.py`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Mnemonic<N: Network> {
+    /// The master account seed.
+    seed: Field<N>,
+}
+
+impl<N: Network> Mnemonic<N> {
+    /// Samples a new random mnemonic.
+    #[inline]
+    pub fn new<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        Self::from_seed(Uniform::rand(rng))
+    }
+
+    /// Initializes a mnemonic from the given master account seed.
+    pub const fn from_seed(seed: Field<N>) -> Self {
+        Self { seed }
+    }
+
+    /// Returns the master account seed.
+    pub const fn seed(&self) -> Field<N> {
+        self.seed
+    }
+
+    /// Returns the master private key.
+    pub fn to_private_key(&self) -> Result<PrivateKey<N>> {
+        PrivateKey::try_from(self.seed)
+    }
+
+    /// Returns the private key at the given derivation path from the master private key.
+    pub fn derive(&self, path: &DerivationPath) -> Result<PrivateKey<N>> {
+        self.to_private_key()?.derive_path(path)
+    }
+}
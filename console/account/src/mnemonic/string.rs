@@ -0,0 +1,213 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use sha2::{Digest, Sha256};
+
+/// The consonants of a proquint, each encoding 4 bits.
+const CONSONANTS: &[u8; 16] = b"bdfghjklmnprstvz";
+/// The vowels of a proquint, each encoding 2 bits.
+const VOWELS: &[u8; 4] = b"aiou";
+
+/// The number of bytes in the encoded seed.
+const SEED_SIZE_IN_BYTES: usize = 32;
+/// The number of bytes in the checksum.
+const CHECKSUM_SIZE_IN_BYTES: usize = 2;
+/// The number of words in a mnemonic phrase.
+const NUM_WORDS: usize = (SEED_SIZE_IN_BYTES + CHECKSUM_SIZE_IN_BYTES) / 2;
+
+/// Returns the checksum of the given seed bytes.
+fn checksum(seed: &[u8]) -> [u8; CHECKSUM_SIZE_IN_BYTES] {
+    let digest = Sha256::digest(seed);
+    [digest[0], digest[1]]
+}
+
+/// Returns the proquint for the given 16 bits.
+fn encode_word(bits: u16) -> String {
+    let consonant = |shift: u16| CONSONANTS[usize::from((bits >> shift) & 0xF)] as char;
+    let vowel = |shift: u16| VOWELS[usize::from((bits >> shift) & 0x3)] as char;
+    [consonant(12), vowel(10), consonant(6), vowel(4), consonant(0)].iter().collect()
+}
+
+/// Returns the 16 bits for the given proquint.
+fn decode_word(word: &str) -> Result<u16> {
+    let bytes = word.as_bytes();
+    ensure!(bytes.len() == 5, "Invalid mnemonic word '{word}': expected 5 letters");
+    let consonant =
+        |byte: u8| CONSONANTS.iter().position(|c| *c == byte).ok_or_else(|| anyhow!("Invalid mnemonic word '{word}'"));
+    let vowel =
+        |byte: u8| VOWELS.iter().position(|v| *v == byte).ok_or_else(|| anyhow!("Invalid mnemonic word '{word}'"));
+    let bits = (consonant(bytes[0])? << 12)
+        | (vowel(bytes[1])? << 10)
+        | (consonant(bytes[2])? << 6)
+        | (vowel(bytes[3])? << 4)
+        | consonant(bytes[4])?;
+    Ok(u16::try_from(bits)?)
+}
+
+impl<N: Network> FromStr for Mnemonic<N> {
+    type Err = Error;
+
+    /// Reads in a mnemonic from a phrase of whitespace-separated words.
+    fn from_str(phrase: &str) -> Result<Self, Self::Err> {
+        let words = phrase.split_whitespace().collect::<Vec<_>>();
+        ensure!(words.len() == NUM_WORDS, "Invalid mnemonic length: found {} words, expected {NUM_WORDS}", words.len());
+        // Decode the words into bytes.
+        let mut bytes = Vec::with_capacity(SEED_SIZE_IN_BYTES + CHECKSUM_SIZE_IN_BYTES);
+        for word in words {
+            bytes.extend_from_slice(&decode_word(&word.to_lowercase())?.to_be_bytes());
+        }
+        // Ensure the checksum is valid.
+        let (seed, expected_checksum) = bytes.split_at(SEED_SIZE_IN_BYTES);
+        ensure!(checksum(seed) == expected_checksum, "Invalid mnemonic checksum");
+        // Output the mnemonic.
+        Ok(Self::from_seed(Field::read_le(seed).map_err(|_| anyhow!("Invalid mnemonic seed"))?))
+    }
+}
+
+impl<N: Network> Display for Mnemonic<N> {
+    /// Writes the mnemonic as a phrase of space-separated words.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Write the seed bytes, followed by the checksum.
+        let mut bytes = self.seed.to_bytes_le().map_err(|_| fmt::Error)?;
+        bytes.extend_from_slice(&checksum(&bytes));
+        // Encode the bytes into words.
+        let words = bytes.chunks(2).map(|pair| encode_word(u16::from_be_bytes([pair[0], pair[1]])));
+        write!(f, "{}", words.collect::<Vec<_>>().join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Address;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new mnemonic.
+            let expected = Mnemonic::<CurrentNetwork>::new(&mut rng);
+
+            // Check the string representation.
+            let candidate = expected.to_string();
+            assert_eq!(NUM_WORDS, candidate.split(' ').count());
+            assert_eq!(expected, Mnemonic::from_str(&candidate)?);
+            // Ensure the phrase is case-insensitive and tolerates extra whitespace.
+            assert_eq!(expected, Mnemonic::from_str(&format!("  {}\n", candidate.to_uppercase().replace(' ', "\t")))?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_vectors() -> Result<()> {
+        // Note: These vectors are generated by `scripts/derivation_vectors.py`, an independent implementation.
+        for (seed, expected) in [
+            (
+                Field::<CurrentNetwork>::from_u64(1),
+                "bahab babab babab babab babab babab babab babab babab babab babab babab babab babab babab babab balib",
+            ),
+            (
+                Field::from_u64(0x1234),
+                "gibif babab babab babab babab babab babab babab babab babab babab babab babab babab babab babab kozib",
+            ),
+            (
+                Field::from_u64(u64::MAX),
+                "zuzuz zuzuz zuzuz zuzuz babab babab babab babab babab babab babab babab babab babab babab babab gutad",
+            ),
+        ] {
+            let mnemonic = Mnemonic::from_seed(seed);
+            assert_eq!(expected, mnemonic.to_string());
+            assert_eq!(mnemonic, Mnemonic::from_str(expected)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_string() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let phrase = Mnemonic::<CurrentNetwork>::new(&mut rng).to_string();
+            let mut words = phrase.split(' ').map(ToString::to_string).collect::<Vec<_>>();
+
+            // Ensure a missing or extra word is rejected.
+            assert!(Mnemonic::<CurrentNetwork>::from_str(&words[1..].join(" ")).is_err());
+            assert!(Mnemonic::<CurrentNetwork>::from_str(&format!("{phrase} babab")).is_err());
+
+            // Ensure a changed checksum word is rejected.
+            let mut tampered = words.clone();
+            let bits = decode_word(&tampered[NUM_WORDS - 1])?;
+            tampered[NUM_WORDS - 1] = encode_word(bits ^ (1 << rng.gen_range(0..16)));
+            assert!(Mnemonic::<CurrentNetwork>::from_str(&tampered.join(" ")).is_err());
+
+            // Ensure a changed seed word never decodes to the original seed.
+            let index = rng.gen_range(0..NUM_WORDS - 1);
+            let bits = decode_word(&words[index])?;
+            words[index] = encode_word(bits ^ (1 << rng.gen_range(0..16)));
+            if let Ok(candidate) = Mnemonic::<CurrentNetwork>::from_str(&words.join(" ")) {
+                assert_ne!(phrase, candidate.to_string());
+            }
+        }
+
+        // Ensure invalid words are rejected.
+        assert!(decode_word("aaaaa").is_err());
+        assert!(decode_word("baba").is_err());
+        assert!(decode_word("bababa").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let mnemonic = Mnemonic::<CurrentNetwork>::new(&mut rng);
+            let master = PrivateKey::try_from(mnemonic.seed())?;
+            assert_eq!(master, mnemonic.to_private_key()?);
+
+            // Ensure the mnemonic derives the same accounts as the master private key.
+            let path = DerivationPath::from_str("m/0'/1'")?;
+            assert_eq!(master.derive_path(&path)?, mnemonic.derive(&path)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_vector() -> Result<()> {
+        // Note: This vector is generated by `scripts/derivation_vectors.py`, an independent implementation.
+        // The mnemonic for the account vector in the crate root.
+        let mnemonic = Mnemonic::<CurrentNetwork>::from_str(
+            "kidon bugak nomof bapis bisok bariv hotor zozil gifal kohas jibib majul gajad jirov gozoh sumag lizog",
+        )?;
+        let master = mnemonic.to_private_key()?;
+        assert_eq!("APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p", master.to_string());
+
+        // Ensure the mnemonic derives the expected account.
+        let private_key = mnemonic.derive(&DerivationPath::from_str("m/44'/0'/0'")?)?;
+        assert_eq!("APrivateKey1zkpGUZ4WtYptZ1csEHnEbvL2azxMAhrQLbFLpNtd767GdjU", private_key.to_string());
+        assert_eq!(
+            "aleo1ly0r57gqmhnup2xaggj79s0685jlnxj7ra0z95rvxfnkq6le8vqqw24yxq",
+            Address::try_from(&private_key)?.to_string()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::derivation_path::{DerivationPath, HARDENED_OFFSET};
+
+static ACCOUNT_CHILD_SEED_DOMAIN: &str = "AleoAccountChildSeed0";
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the hardened child private key at the given index.
+    ///
+    /// The child seed is a domain-separated hash of the parent seed and the index, so the child
+    /// does not reveal the parent or its siblings.
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        // Ensure the index is within the hardened range.
+        ensure!(index < HARDENED_OFFSET, "Child index {index} exceeds the maximum of {}", HARDENED_OFFSET - 1);
+        // Construct the child seed domain separator.
+        let domain = Field::<N>::new_domain_separator(ACCOUNT_CHILD_SEED_DOMAIN);
+        // Compute the child seed.
+        let child_seed = N::hash_psd4(&[domain, self.seed, Field::from_u32(HARDENED_OFFSET | index)])?;
+        // Derive the child private key.
+        Self::try_from(child_seed)
+    }
+
+    /// Returns the descendant private key at the given derivation path.
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self> {
+        path.indices().iter().try_fold(*self, |private_key, index| private_key.derive_child(*index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Address;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_derive_child() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+            // Ensure derivation is deterministic.
+            let index = rng.gen_range(0..HARDENED_OFFSET);
+            let child = private_key.derive_child(index)?;
+            assert_eq!(child, private_key.derive_child(index)?);
+
+            // Ensure the child differs from the parent and its siblings.
+            assert_ne!(child, private_key);
+            assert_ne!(child, private_key.derive_child(index ^ 1)?);
+
+            // Ensure non-hardened indices are rejected.
+            assert!(private_key.derive_child(HARDENED_OFFSET | index).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_vectors() -> Result<()> {
+        // Note: These vectors are generated by `scripts/derivation_vectors.py`, an independent implementation.
+        // The master private key, which is also the account vector in the crate root.
+        let master =
+            PrivateKey::<CurrentNetwork>::from_str("APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p")?;
+
+        for (path, expected_private_key, expected_address) in [
+            (
+                "m/0'",
+                "APrivateKey1zkp3hcQQJphjHaVP6qqA2cFiC7JtgVPTq1EpSkYRsDaTrmB",
+                "aleo1v0q0zesd9cpvtn9v44le8xlsdsju28px7vcynmx4axq4c6d2qcgqhvhkae",
+            ),
+            (
+                "m/1'",
+                "APrivateKey1zkpFHvAzzJdBer9rSYogi9XexZ2K7XdVjVBJvntGdc9cAeF",
+                "aleo16rhrpsc0pxyqz6y5zdeseehdej0fhhkpld3vkcv9kj3l555fvsysysdfgf",
+            ),
+            (
+                "m/2147483647'",
+                "APrivateKey1zkp9sKnStfTwo2NKG3GET5WzRULA44h7TGQf4U2KcRmjkq9",
+                "aleo1sfs47hhgshc73f7ccx78546jfhutepmcvz0kld67346jlvdwhuyqx45npv",
+            ),
+            (
+                "m/44'/0'/0'",
+                "APrivateKey1zkpGUZ4WtYptZ1csEHnEbvL2azxMAhrQLbFLpNtd767GdjU",
+                "aleo1ly0r57gqmhnup2xaggj79s0685jlnxj7ra0z95rvxfnkq6le8vqqw24yxq",
+            ),
+            (
+                "m/44'/0'/1'",
+                "APrivateKey1zkp2SowpNLZTCDDNGQQd9gqzwQzgLAvh1gCp3AQygNi9iMD",
+                "aleo16enak55fl8wjrfhks93felmg9r6yy9wspxkz3cse304xw6rh5sfqpr9fd8",
+            ),
+        ] {
+            let private_key = master.derive_path(&DerivationPath::from_str(path)?)?;
+            assert_eq!(expected_private_key, private_key.to_string());
+            assert_eq!(expected_address, Address::try_from(&private_key)?.to_string());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_path() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+            // Ensure the master path returns the private key itself.
+            assert_eq!(private_key, private_key.derive_path(&DerivationPath::master())?);
+
+            // Ensure the path is derived one child at a time.
+            let path = DerivationPath::from_str("m/44'/0'/3'")?;
+            let expected = private_key.derive_child(44)?.derive_child(0)?.derive_child(3)?;
+            assert_eq!(expected, private_key.derive_path(&path)?);
+
+            // Ensure the order of the indices matters.
+            let path = DerivationPath::from_str("m/0'/44'/3'")?;
+            assert_ne!(expected, private_key.derive_path(&path)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> ThresholdKey<N> {
+    /// Splits the given private key into a `threshold`-of-`num_participants` threshold account with a trusted dealer,
+    /// returning the threshold key and the key share of each participant.
+    ///
+    /// The threshold account has the same address as the private key, so an existing account can be moved into
+    /// multi-party custody. The dealer learns every key share, and must erase the private key and the key shares
+    /// once they are distributed. Note that `r_sig` is not shared, so the view key of the account is not recoverable
+    /// from the key shares alone.
+    pub fn split<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut R,
+    ) -> Result<(Self, Vec<KeyShare<N>>)> {
+        // Ensure the threshold is valid.
+        ensure_threshold(threshold, usize::from(num_participants))?;
+
+        // Sample a random polynomial of degree `threshold - 1`, with `sk_sig` as its constant term.
+        let mut coefficients = Vec::with_capacity(usize::from(threshold));
+        coefficients.push(private_key.sk_sig());
+        coefficients.extend((1..threshold).map(|_| Scalar::rand(rng)));
+
+        // Compute the key share of each participant as the evaluation of the polynomial at their index.
+        let key_shares = (1..=num_participants)
+            .map(|index| Ok(KeyShare::new(index, evaluate_polynomial(&coefficients, index)?)))
+            .collect::<Result<Vec<_>>>()?;
+        // Compute the verification share of each participant.
+        let verification_shares = key_shares.iter().map(KeyShare::to_verification_share).collect();
+
+        // Output the threshold key and the key shares.
+        Ok((Self::new(ComputeKey::try_from(private_key)?, threshold, verification_shares)?, key_shares))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for (threshold, num_participants) in [(1, 1), (1, 3), (2, 3), (3, 3), (3, 5), (7, 10)] {
+            let (private_key, threshold_key, key_shares) =
+                test_helpers::sample_threshold_key(threshold, num_participants, &mut rng);
+
+            // Ensure the threshold account has the address of the private key.
+            assert_eq!(Address::try_from(&private_key)?, threshold_key.to_address());
+            assert_eq!(threshold, threshold_key.threshold());
+            assert_eq!(num_participants, threshold_key.num_participants());
+            assert_eq!(usize::from(num_participants), key_shares.len());
+
+            // Ensure any `threshold` key shares recover `sk_sig`.
+            let indices = (num_participants - threshold + 1..=num_participants).collect::<Vec<_>>();
+            let sk_sig = indices
+                .iter()
+                .map(|index| {
+                    let key_share = key_shares[usize::from(*index - 1)];
+                    assert_eq!(key_share.to_verification_share(), threshold_key.verification_share(*index)?);
+                    Ok(key_share.sk_sig_share() * lagrange_coefficient(*index, &indices)?)
+                })
+                .sum::<Result<Scalar<_>>>()?;
+            assert_eq!(private_key.sk_sig(), sk_sig);
+        }
+
+        // Ensure an invalid threshold is rejected.
+        let private_key = PrivateKey::<snarkvm_console_network::Testnet3>::new(&mut rng)?;
+        assert!(ThresholdKey::split(&private_key, 0, 3, &mut rng).is_err());
+        assert!(ThresholdKey::split(&private_key, 4, 3, &mut rng).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,301 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+static THRESHOLD_DKG_PROOF_DOMAIN: &str = "AleoThresholdKeyGenerationProof0";
+static THRESHOLD_R_SIG_DOMAIN: &str = "AleoThresholdSignatureRandomizer0";
+
+/// The secret state of a participant in distributed key generation, kept between the two rounds.
+///
+/// Distributed key generation follows the Pedersen DKG of FROST: in the first round, each participant broadcasts
+/// a commitment to a random polynomial with a proof of knowledge of its constant term; in the second round, each
+/// participant privately sends the evaluation of their polynomial to every other participant. No participant
+/// learns `sk_sig`, which is the sum of the constant terms.
+pub struct DkgSecret<N: Network> {
+    /// The index of the participant, starting from `1`.
+    index: u16,
+    /// The number of participants required to sign.
+    threshold: u16,
+    /// The number of participants.
+    num_participants: u16,
+    /// The coefficients of the secret polynomial of the participant.
+    coefficients: Vec<Scalar<N>>,
+}
+
+/// The commitment broadcast by a participant in the first round of distributed key generation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DkgCommitment<N: Network> {
+    /// The index of the participant.
+    index: u16,
+    /// The commitments to the coefficients of the secret polynomial of the participant.
+    commitments: Vec<Group<N>>,
+    /// The proof of knowledge `(k * G, k + challenge * coefficient_0)` of the constant term.
+    proof: (Group<N>, Scalar<N>),
+}
+
+/// The share sent privately from one participant to another in the second round of distributed key generation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DkgShare<N: Network> {
+    /// The index of the sending participant.
+    sender: u16,
+    /// The index of the receiving participant.
+    receiver: u16,
+    /// The evaluation of the secret polynomial of the sender at the index of the receiver.
+    share: Scalar<N>,
+}
+
+impl<N: Network> DkgSecret<N> {
+    /// Runs the first round of distributed key generation for the participant at the given index,
+    /// returning the secret state of the participant and the commitment to broadcast.
+    pub fn new<R: Rng + CryptoRng>(
+        index: u16,
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut R,
+    ) -> Result<(Self, DkgCommitment<N>)> {
+        // Ensure the threshold and index are valid.
+        ensure_threshold(threshold, usize::from(num_participants))?;
+        ensure!(index >= 1 && index <= num_participants, "Participant {index} is not in 1..={num_participants}");
+
+        // Sample a random polynomial of degree `threshold - 1`.
+        let coefficients = (0..threshold).map(|_| Scalar::rand(rng)).collect::<Vec<_>>();
+        // Commit to the coefficients.
+        let commitments = coefficients.iter().map(N::g_scalar_multiply).collect::<Vec<_>>();
+
+        // Prove knowledge of the constant term.
+        let k = Scalar::rand(rng);
+        let g_k = N::g_scalar_multiply(&k);
+        let challenge = proof_challenge::<N>(index, commitments[0], g_k)?;
+        let proof = (g_k, k + challenge * coefficients[0]);
+
+        Ok((Self { index, threshold, num_participants, coefficients }, DkgCommitment { index, commitments, proof }))
+    }
+
+    /// Returns the index of the participant.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Runs the second round of distributed key generation, returning the share to send privately to each
+    /// of the other participants.
+    pub fn shares(&self) -> Result<Vec<DkgShare<N>>> {
+        (1..=self.num_participants)
+            .filter(|receiver| *receiver != self.index)
+            .map(|receiver| {
+                let share = evaluate_polynomial(&self.coefficients, receiver)?;
+                Ok(DkgShare { sender: self.index, receiver, share })
+            })
+            .collect()
+    }
+
+    /// Completes distributed key generation from the commitments of all participants and the shares received
+    /// from the other participants, returning the threshold key and the key share of this participant.
+    pub fn finalize(
+        self,
+        commitments: &[DkgCommitment<N>],
+        shares: &[DkgShare<N>],
+    ) -> Result<(ThresholdKey<N>, KeyShare<N>)> {
+        let num_participants = usize::from(self.num_participants);
+        // Ensure there is one commitment from each participant, in order.
+        ensure!(
+            commitments.len() == num_participants,
+            "Expected {num_participants} commitments, found {}",
+            commitments.len()
+        );
+        for (commitment, index) in commitments.iter().zip(1..) {
+            ensure!(
+                commitment.index == index,
+                "Expected the commitment of participant {index}, found {}",
+                commitment.index
+            );
+            commitment.verify(self.threshold)?;
+        }
+        // Ensure the commitment of this participant is to its own polynomial.
+        let own_commitments = self.coefficients.iter().map(N::g_scalar_multiply).collect::<Vec<_>>();
+        ensure!(
+            commitments[usize::from(self.index - 1)].commitments == own_commitments,
+            "The commitment of participant {} does not match its polynomial",
+            self.index
+        );
+
+        // Ensure there is one share from each other participant, in order.
+        ensure!(
+            shares.len() == num_participants - 1,
+            "Expected {} shares, found {}",
+            num_participants - 1,
+            shares.len()
+        );
+        let mut sk_sig_share = evaluate_polynomial(&self.coefficients, self.index)?;
+        for (share, sender) in shares.iter().zip((1..=self.num_participants).filter(|sender| *sender != self.index)) {
+            ensure!(share.sender == sender, "Expected the share of participant {sender}, found {}", share.sender);
+            ensure!(share.receiver == self.index, "Participant {} received a share for {}", self.index, share.receiver);
+            // Ensure the share is the evaluation of the committed polynomial of the sender.
+            let expected = evaluate_commitments(&commitments[usize::from(sender - 1)].commitments, self.index)?;
+            ensure!(N::g_scalar_multiply(&share.share) == expected, "Participant {sender} sent an invalid share");
+            sk_sig_share += share.share;
+        }
+
+        // Compute `pk_sig` as the sum of the committed constant terms.
+        let pk_sig = commitments.iter().map(|commitment| commitment.commitments[0]).sum::<Group<N>>();
+        // Compute `r_sig` publicly from `pk_sig`, as there is no dealer to sample it.
+        // Note: `r_sig` only blinds `pk_sig` in the address, and the view key still requires `sk_sig`.
+        let domain = Field::<N>::new_domain_separator(THRESHOLD_R_SIG_DOMAIN);
+        let r_sig = N::hash_to_scalar_psd2(&[domain, pk_sig.to_x_coordinate()])?;
+        let compute_key = ComputeKey::try_from((pk_sig, N::g_scalar_multiply(&r_sig)))?;
+
+        // Compute the verification share of each participant.
+        let verification_shares = (1..=self.num_participants)
+            .map(|index| {
+                commitments
+                    .iter()
+                    .map(|commitment| evaluate_commitments(&commitment.commitments, index))
+                    .sum::<Result<Group<N>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Output the threshold key and the key share.
+        let threshold_key = ThresholdKey::new(compute_key, self.threshold, verification_shares)?;
+        let key_share = KeyShare::new(self.index, sk_sig_share);
+        ensure!(
+            key_share.to_verification_share() == threshold_key.verification_share(self.index)?,
+            "The key share does not match its verification share"
+        );
+        Ok((threshold_key, key_share))
+    }
+}
+
+impl<N: Network> DkgCommitment<N> {
+    /// Returns the index of the participant.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the commitments to the coefficients of the secret polynomial of the participant.
+    pub fn commitments(&self) -> &[Group<N>] {
+        &self.commitments
+    }
+
+    /// Returns the proof of knowledge of the constant term.
+    pub const fn proof(&self) -> (Group<N>, Scalar<N>) {
+        self.proof
+    }
+
+    /// Ensures the commitment is to a polynomial of degree `threshold - 1`, with a valid proof of knowledge.
+    pub fn verify(&self, threshold: u16) -> Result<()> {
+        ensure!(
+            self.commitments.len() == usize::from(threshold),
+            "Participant {} committed to {} coefficients, expected {threshold}",
+            self.index,
+            self.commitments.len()
+        );
+        // Ensure `response * G == k * G + challenge * commitment_0`.
+        let (g_k, response) = self.proof;
+        let challenge = proof_challenge::<N>(self.index, self.commitments[0], g_k)?;
+        ensure!(
+            N::g_scalar_multiply(&response) == g_k + self.commitments[0] * challenge,
+            "Participant {} has an invalid proof of knowledge",
+            self.index
+        );
+        Ok(())
+    }
+}
+
+impl<N: Network> DkgShare<N> {
+    /// Returns the index of the sending participant.
+    pub const fn sender(&self) -> u16 {
+        self.sender
+    }
+
+    /// Returns the index of the receiving participant.
+    pub const fn receiver(&self) -> u16 {
+        self.receiver
+    }
+}
+
+/// Returns the challenge of the proof of knowledge of a constant term, for the participant at the given index.
+fn proof_challenge<N: Network>(index: u16, commitment: Group<N>, g_k: Group<N>) -> Result<Scalar<N>> {
+    let domain = Field::<N>::new_domain_separator(THRESHOLD_DKG_PROOF_DOMAIN);
+    let (commitment_x, commitment_y) = commitment.to_xy_coordinates();
+    let (g_k_x, g_k_y) = g_k.to_xy_coordinates();
+    N::hash_to_scalar_psd8(&[domain, Field::from_u16(index), commitment_x, commitment_y, g_k_x, g_k_y])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_dkg() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for (threshold, num_participants) in [(1, 1), (2, 3), (3, 5)] {
+            let outputs = test_helpers::run_dkg(threshold, num_participants, &mut rng)?;
+
+            // Ensure all participants agree on the threshold key.
+            let threshold_key = outputs[0].0.clone();
+            for (candidate, key_share) in &outputs {
+                assert_eq!(&threshold_key, candidate);
+                assert_eq!(key_share.to_verification_share(), threshold_key.verification_share(key_share.index())?);
+            }
+
+            // Ensure any `threshold` key shares recover `sk_sig`.
+            let indices = (1..=threshold).collect::<Vec<_>>();
+            let sk_sig = indices
+                .iter()
+                .map(|index| {
+                    Ok(outputs[usize::from(*index - 1)].1.sk_sig_share() * lagrange_coefficient(*index, &indices)?)
+                })
+                .sum::<Result<Scalar<CurrentNetwork>>>()?;
+            assert_eq!(threshold_key.compute_key().pk_sig(), CurrentNetwork::g_scalar_multiply(&sk_sig));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_dkg_invalid_share() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let (secrets, commitments): (Vec<_>, Vec<_>) = (1..=3)
+            .map(|index| DkgSecret::<CurrentNetwork>::new(index, 2, 3, &mut rng))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        let shares = secrets.iter().map(DkgSecret::shares).collect::<Result<Vec<_>>>()?.concat();
+        let mut received = shares.iter().filter(|share| share.receiver == 1).copied().collect::<Vec<_>>();
+
+        // Ensure a tampered share is rejected.
+        received[0].share += Scalar::one();
+        let secret = secrets.into_iter().next().unwrap();
+        assert!(secret.finalize(&commitments, &received).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_dkg_invalid_proof() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let (_, mut commitment) = DkgSecret::<CurrentNetwork>::new(1, 2, 3, &mut rng)?;
+        assert!(commitment.verify(2).is_ok());
+        // Ensure a commitment with the wrong number of coefficients is rejected.
+        assert!(commitment.verify(3).is_err());
+        // Ensure a tampered proof is rejected.
+        commitment.proof.1 += Scalar::one();
+        assert!(commitment.verify(2).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,292 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod dealer;
+mod dkg;
+mod sign;
+
+pub use dkg::*;
+pub use sign::*;
+
+use crate::{Address, ComputeKey, PrivateKey, Signature};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, Group, Scalar};
+
+/// The public key of a t-of-n threshold account.
+///
+/// The signature secret key `sk_sig` is Shamir-shared among `n` participants, indexed from `1` to `n`,
+/// such that any `t` of them can jointly produce a standard account `Signature` with FROST.
+/// The account compute key and address are public, and the verification share `pk_sig_i := sk_sig_i * G` of
+/// each participant is used to identify participants that submit invalid signature shares.
+///
+/// Note that a program execution `Request` carries its transition secret key, which is the signing nonce,
+/// and together with the signature it reveals `sk_sig` to the prover. As such, requests are not threshold signed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdKey<N: Network> {
+    /// The compute key of the threshold account.
+    compute_key: ComputeKey<N>,
+    /// The number of participants required to sign.
+    threshold: u16,
+    /// The verification shares of the participants, where the participant at index `i` is at position `i - 1`.
+    verification_shares: Vec<Group<N>>,
+}
+
+/// The signature secret key share of a participant in a threshold account.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct KeyShare<N: Network> {
+    /// The index of the participant, starting from `1`.
+    index: u16,
+    /// The share of the signature secret key.
+    sk_sig_share: Scalar<N>,
+}
+
+impl<N: Network> ThresholdKey<N> {
+    /// Initializes a threshold key from the compute key, threshold, and verification shares.
+    pub fn new(compute_key: ComputeKey<N>, threshold: u16, verification_shares: Vec<Group<N>>) -> Result<Self> {
+        // Ensure the threshold is valid.
+        ensure_threshold(threshold, verification_shares.len())?;
+        // Interpolate the committed polynomial at the given index from the first `threshold` verification shares.
+        let indices = (1..=threshold).collect::<Vec<_>>();
+        let interpolate = |x: u16| {
+            indices
+                .iter()
+                .map(|index| {
+                    let lagrange = lagrange_coefficient_at::<N>(x, *index, &indices)?;
+                    Ok(verification_shares[usize::from(*index - 1)] * lagrange)
+                })
+                .sum::<Result<Group<N>>>()
+        };
+        // Ensure the verification shares interpolate to `pk_sig`.
+        ensure!(interpolate(0)? == compute_key.pk_sig(), "The verification shares do not match the threshold account");
+        // Ensure every remaining verification share lies on the same polynomial of degree `threshold - 1`,
+        // as otherwise a different set of `threshold` participants would not sign for the threshold account.
+        for index in threshold + 1..=verification_shares.len() as u16 {
+            ensure!(
+                interpolate(index)? == verification_shares[usize::from(index - 1)],
+                "The verification share of participant {index} does not match the threshold account"
+            );
+        }
+        Ok(Self { compute_key, threshold, verification_shares })
+    }
+
+    /// Returns the compute key of the threshold account.
+    pub const fn compute_key(&self) -> &ComputeKey<N> {
+        &self.compute_key
+    }
+
+    /// Returns the address of the threshold account.
+    pub fn to_address(&self) -> Address<N> {
+        self.compute_key.to_address()
+    }
+
+    /// Returns the number of participants required to sign.
+    pub const fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Returns the number of participants.
+    pub fn num_participants(&self) -> u16 {
+        // Note: The number of participants is checked to fit in a `u16` on initialization.
+        self.verification_shares.len() as u16
+    }
+
+    /// Returns the verification share of the participant at the given index.
+    pub fn verification_share(&self, index: u16) -> Result<Group<N>> {
+        ensure!(index >= 1 && index <= self.num_participants(), "Participant {index} is not in the threshold account");
+        Ok(self.verification_shares[usize::from(index - 1)])
+    }
+}
+
+impl<N: Network> KeyShare<N> {
+    /// Initializes a key share from the participant index and the share of the signature secret key.
+    pub const fn new(index: u16, sk_sig_share: Scalar<N>) -> Self {
+        Self { index, sk_sig_share }
+    }
+
+    /// Returns the index of the participant.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the share of the signature secret key.
+    pub const fn sk_sig_share(&self) -> Scalar<N> {
+        self.sk_sig_share
+    }
+
+    /// Returns the verification share, `sk_sig_share * G`.
+    pub fn to_verification_share(&self) -> Group<N> {
+        N::g_scalar_multiply(&self.sk_sig_share)
+    }
+}
+
+/// Ensures the threshold is between `1` and the number of participants, which must fit in a `u16`.
+fn ensure_threshold(threshold: u16, num_participants: usize) -> Result<()> {
+    ensure!(num_participants <= usize::from(u16::MAX), "The number of participants must not exceed {}", u16::MAX);
+    ensure!(threshold >= 1, "The threshold must be at least 1");
+    ensure!(
+        usize::from(threshold) <= num_participants,
+        "The threshold ({threshold}) must not exceed the number of participants ({num_participants})"
+    );
+    Ok(())
+}
+
+/// Returns the participant index as a scalar.
+fn index_to_scalar<N: Network>(index: u16) -> Result<Scalar<N>> {
+    Scalar::from_field(&Field::from_u16(index))
+}
+
+/// Returns the evaluation of the polynomial with the given coefficients at the given participant index.
+fn evaluate_polynomial<N: Network>(coefficients: &[Scalar<N>], index: u16) -> Result<Scalar<N>> {
+    let x = index_to_scalar::<N>(index)?;
+    Ok(coefficients.iter().rev().fold(Scalar::zero(), |acc, coefficient| acc * x + coefficient))
+}
+
+/// Returns the evaluation of the committed polynomial at the given participant index, `f(index) * G`.
+fn evaluate_commitments<N: Network>(commitments: &[Group<N>], index: u16) -> Result<Group<N>> {
+    let x = index_to_scalar::<N>(index)?;
+    Ok(commitments.iter().rev().fold(Group::zero(), |acc, commitment| acc * x + commitment))
+}
+
+/// Returns the Lagrange coefficient at zero of the given participant index, for the given set of participant indices.
+fn lagrange_coefficient<N: Network>(index: u16, indices: &[u16]) -> Result<Scalar<N>> {
+    lagrange_coefficient_at(0, index, indices)
+}
+
+/// Returns the Lagrange coefficient at `x` of the given participant index, for the given set of participant indices.
+fn lagrange_coefficient_at<N: Network>(x: u16, index: u16, indices: &[u16]) -> Result<Scalar<N>> {
+    ensure!(index != 0, "Participant indices start from 1");
+    ensure!(indices.contains(&index), "Participant {index} is not among the signers");
+    let x = index_to_scalar::<N>(x)?;
+    let x_i = index_to_scalar::<N>(index)?;
+    let mut numerator = Scalar::<N>::one();
+    let mut denominator = Scalar::<N>::one();
+    for j in indices.iter().filter(|j| **j != index) {
+        ensure!(*j != 0, "Participant indices start from 1");
+        let x_j = index_to_scalar::<N>(*j)?;
+        numerator *= x_j - x;
+        denominator *= x_j - x_i;
+    }
+    Ok(numerator / denominator)
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a random private key and splits it into a `threshold`-of-`num_participants` threshold account.
+    pub(crate) fn sample_threshold_key(
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut TestRng,
+    ) -> (PrivateKey<CurrentNetwork>, ThresholdKey<CurrentNetwork>, Vec<KeyShare<CurrentNetwork>>) {
+        let private_key = PrivateKey::new(rng).unwrap();
+        let (threshold_key, key_shares) = ThresholdKey::split(&private_key, threshold, num_participants, rng).unwrap();
+        (private_key, threshold_key, key_shares)
+    }
+
+    /// Runs distributed key generation among `num_participants` participants.
+    pub(crate) fn run_dkg(
+        threshold: u16,
+        num_participants: u16,
+        rng: &mut TestRng,
+    ) -> Result<Vec<(ThresholdKey<CurrentNetwork>, KeyShare<CurrentNetwork>)>> {
+        // Run the first round.
+        let (secrets, commitments): (Vec<_>, Vec<_>) = (1..=num_participants)
+            .map(|index| DkgSecret::new(index, threshold, num_participants, rng))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        // Run the second round.
+        let shares = secrets.iter().map(DkgSecret::shares).collect::<Result<Vec<_>>>()?.concat();
+        // Complete key generation for each participant.
+        secrets
+            .into_iter()
+            .map(|secret| {
+                let index = secret.index();
+                let received = shares.iter().filter(|share| share.receiver() == index).copied().collect::<Vec<_>>();
+                secret.finalize(&commitments, &received)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_lagrange_interpolation() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a random polynomial of degree 2.
+        let coefficients = (0..3).map(|_| Scalar::<CurrentNetwork>::rand(&mut rng)).collect::<Vec<_>>();
+        // Ensure any 3 evaluations interpolate to the constant term.
+        for indices in [[1u16, 2, 3], [2, 4, 5], [5, 1, 3]] {
+            let secret = indices
+                .iter()
+                .map(|index| Ok(evaluate_polynomial(&coefficients, *index)? * lagrange_coefficient(*index, &indices)?))
+                .sum::<Result<Scalar<CurrentNetwork>>>()?;
+            assert_eq!(coefficients[0], secret);
+        }
+        // Ensure 2 evaluations do not.
+        let indices = [1u16, 2];
+        let secret = indices
+            .iter()
+            .map(|index| Ok(evaluate_polynomial(&coefficients, *index)? * lagrange_coefficient(*index, &indices)?))
+            .sum::<Result<Scalar<CurrentNetwork>>>()?;
+        assert_ne!(coefficients[0], secret);
+
+        // Ensure the committed polynomial matches the polynomial.
+        let commitments = coefficients.iter().map(CurrentNetwork::g_scalar_multiply).collect::<Vec<_>>();
+        for index in 1..=5 {
+            assert_eq!(
+                CurrentNetwork::g_scalar_multiply(&evaluate_polynomial(&coefficients, index)?),
+                evaluate_commitments(&commitments, index)?
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_invalid_verification_share() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for (threshold, num_participants) in [(1, 3), (2, 3), (3, 5)] {
+            let (_, threshold_key, _) = test_helpers::sample_threshold_key(threshold, num_participants, &mut rng);
+            let compute_key = *threshold_key.compute_key();
+
+            // Ensure tampering with any verification share is rejected, including those past the threshold.
+            for index in 1..=num_participants {
+                let mut verification_shares = threshold_key.verification_shares.clone();
+                verification_shares[usize::from(index - 1)] += CurrentNetwork::g_scalar_multiply(&Scalar::one());
+                assert!(ThresholdKey::new(compute_key, threshold, verification_shares).is_err());
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_threshold() {
+        assert!(ensure_threshold(0, 3).is_err());
+        assert!(ensure_threshold(4, 3).is_err());
+        assert!(ensure_threshold(1, 1).is_ok());
+        assert!(ensure_threshold(3, 3).is_ok());
+    }
+}
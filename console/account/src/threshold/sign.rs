@@ -0,0 +1,373 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+static THRESHOLD_BINDING_FACTOR_DOMAIN: &str = "AleoThresholdBindingFactor0";
+
+/// The secret nonces of a signer for a single signing session.
+///
+/// The nonces are consumed when signing, and must never be reused, as two signature shares under the
+/// same nonces reveal the key share of the signer.
+pub struct SigningNonces<N: Network> {
+    /// The hiding nonce.
+    hiding: Scalar<N>,
+    /// The binding nonce.
+    binding: Scalar<N>,
+    /// The public commitments to the nonces.
+    commitments: SigningCommitments<N>,
+}
+
+/// The public commitments to the nonces of a signer, broadcast in the first round of signing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SigningCommitments<N: Network> {
+    /// The index of the signer.
+    index: u16,
+    /// The commitment to the hiding nonce.
+    hiding: Group<N>,
+    /// The commitment to the binding nonce.
+    binding: Group<N>,
+}
+
+/// The signature share of a signer, sent to the aggregator in the second round of signing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SignatureShare<N: Network> {
+    /// The index of the signer.
+    index: u16,
+    /// The share of the prover response.
+    response: Scalar<N>,
+}
+
+impl<N: Network> SigningNonces<N> {
+    /// Runs the first round of signing for the given key share, returning the secret nonces of the signer.
+    /// The commitments to the nonces are broadcast to the other signers.
+    pub fn new<R: Rng + CryptoRng>(key_share: &KeyShare<N>, rng: &mut R) -> Self {
+        let hiding = Scalar::rand(rng);
+        let binding = Scalar::rand(rng);
+        let commitments = SigningCommitments {
+            index: key_share.index(),
+            hiding: N::g_scalar_multiply(&hiding),
+            binding: N::g_scalar_multiply(&binding),
+        };
+        Self { hiding, binding, commitments }
+    }
+
+    /// Returns the public commitments to the nonces.
+    pub const fn commitments(&self) -> SigningCommitments<N> {
+        self.commitments
+    }
+}
+
+impl<N: Network> SigningCommitments<N> {
+    /// Returns the index of the signer.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the commitment to the hiding nonce.
+    pub const fn hiding(&self) -> Group<N> {
+        self.hiding
+    }
+
+    /// Returns the commitment to the binding nonce.
+    pub const fn binding(&self) -> Group<N> {
+        self.binding
+    }
+}
+
+impl<N: Network> SignatureShare<N> {
+    /// Returns the index of the signer.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the share of the prover response.
+    pub const fn response(&self) -> Scalar<N> {
+        self.response
+    }
+}
+
+impl<N: Network> KeyShare<N> {
+    /// Runs the second round of signing, returning the signature share of this signer for the given message, where:
+    ///     response_i := hiding_i + binding_factor_i * binding_i - challenge * lagrange_i * sk_sig_share_i
+    ///
+    /// The commitments are those of all signers in the session, ordered by index, and must include the
+    /// commitments of the given nonces.
+    pub fn sign(
+        &self,
+        threshold_key: &ThresholdKey<N>,
+        nonces: SigningNonces<N>,
+        commitments: &[SigningCommitments<N>],
+        message: &[Field<N>],
+    ) -> Result<SignatureShare<N>> {
+        // Ensure the key share belongs to the threshold account.
+        ensure!(
+            self.to_verification_share() == threshold_key.verification_share(self.index)?,
+            "The key share of participant {} does not belong to the threshold account",
+            self.index
+        );
+        // Ensure the nonces are for this signer, and are committed to in the session.
+        ensure!(nonces.commitments.index == self.index, "The nonces are not for participant {}", self.index);
+        ensure!(
+            commitments.contains(&nonces.commitments),
+            "The nonces of participant {} are not in the session",
+            self.index
+        );
+
+        // Compute the binding factors, Lagrange coefficients, and challenge of the session.
+        let session = Session::new(threshold_key, commitments, message)?;
+        let (binding_factor, lagrange) = session.signer(self.index)?;
+
+        // Compute the signature share.
+        let response =
+            nonces.hiding + binding_factor * nonces.binding - session.challenge * lagrange * self.sk_sig_share;
+        Ok(SignatureShare { index: self.index, response })
+    }
+}
+
+impl<N: Network> ThresholdKey<N> {
+    /// Aggregates the signature shares of all signers in the session into a standard account signature for the given
+    /// message, which verifies with `Signature::verify` against the threshold account address.
+    ///
+    /// Each signature share is checked against the verification share of its signer, so an invalid signature share
+    /// identifies the signer that produced it.
+    pub fn aggregate(
+        &self,
+        commitments: &[SigningCommitments<N>],
+        message: &[Field<N>],
+        signature_shares: &[SignatureShare<N>],
+    ) -> Result<Signature<N>> {
+        // Compute the binding factors, Lagrange coefficients, and challenge of the session.
+        let session = Session::new(self, commitments, message)?;
+        // Ensure there is one signature share from each signer, in order.
+        ensure!(
+            signature_shares.len() == commitments.len(),
+            "Expected {} signature shares, found {}",
+            commitments.len(),
+            signature_shares.len()
+        );
+
+        let mut response = Scalar::<N>::zero();
+        for (share, commitment) in signature_shares.iter().zip_eq(commitments) {
+            ensure!(
+                share.index == commitment.index,
+                "Expected the signature share of participant {}",
+                commitment.index
+            );
+            // Ensure `response_i * G == D_i + binding_factor_i * E_i - challenge * lagrange_i * verification_share_i`.
+            let (binding_factor, lagrange) = session.signer(share.index)?;
+            let expected = commitment.hiding + commitment.binding * binding_factor
+                - self.verification_share(share.index)? * (session.challenge * lagrange);
+            ensure!(
+                N::g_scalar_multiply(&share.response) == expected,
+                "Participant {} sent an invalid signature share",
+                share.index
+            );
+            response += share.response;
+        }
+
+        // Construct the signature.
+        let signature = Signature::from((session.challenge, response, self.compute_key));
+        ensure!(signature.verify(&self.to_address(), message), "The aggregated signature is invalid");
+        Ok(signature)
+    }
+}
+
+/// The public state of a signing session, shared by the signers and the aggregator.
+struct Session<N: Network> {
+    /// The indices of the signers.
+    indices: Vec<u16>,
+    /// The binding factor of each signer.
+    binding_factors: Vec<Scalar<N>>,
+    /// The verifier challenge.
+    challenge: Scalar<N>,
+}
+
+impl<N: Network> Session<N> {
+    /// Computes the binding factors and challenge for the given commitments and message, where:
+    ///     binding_factor_i := HashToScalar(i, pk_sig, message, commitments)
+    ///     challenge := HashToScalar(Σ (hiding_i + binding_factor_i * binding_i), pk_sig, pr_sig, address, message)
+    fn new(
+        threshold_key: &ThresholdKey<N>,
+        commitments: &[SigningCommitments<N>],
+        message: &[Field<N>],
+    ) -> Result<Self> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            bail!("Cannot sign the message: the message exceeds maximum allowed size")
+        }
+        // Ensure there are enough signers, ordered by index without duplicates.
+        ensure!(
+            commitments.len() >= usize::from(threshold_key.threshold()),
+            "Expected at least {} signers, found {}",
+            threshold_key.threshold(),
+            commitments.len()
+        );
+        ensure!(
+            commitments.windows(2).all(|pair| pair[0].index < pair[1].index),
+            "The signing commitments must be ordered by index, without duplicates"
+        );
+        for commitment in commitments {
+            threshold_key.verification_share(commitment.index)?;
+        }
+
+        let pk_sig = threshold_key.compute_key().pk_sig();
+        let pr_sig = threshold_key.compute_key().pr_sig();
+        let address = threshold_key.to_address();
+
+        // Construct the binding factor preimage as `(pk_sig, message length, message, commitments)`.
+        let mut preimage = Vec::with_capacity(5 + message.len() + 5 * commitments.len());
+        preimage.push(Field::new_domain_separator(THRESHOLD_BINDING_FACTOR_DOMAIN));
+        preimage.push(Field::from_u16(0));
+        preimage.extend([pk_sig.to_x_coordinate(), pk_sig.to_y_coordinate()]);
+        preimage.push(Field::from_u64(message.len() as u64));
+        preimage.extend(message);
+        for commitment in commitments {
+            let (hiding_x, hiding_y) = commitment.hiding.to_xy_coordinates();
+            let (binding_x, binding_y) = commitment.binding.to_xy_coordinates();
+            preimage.extend([Field::from_u16(commitment.index), hiding_x, hiding_y, binding_x, binding_y]);
+        }
+
+        // Compute the binding factor of each signer, and the group commitment.
+        let mut binding_factors = Vec::with_capacity(commitments.len());
+        let mut group_commitment = Group::<N>::zero();
+        for commitment in commitments {
+            preimage[1] = Field::from_u16(commitment.index);
+            let binding_factor = N::hash_to_scalar_psd8(&preimage)?;
+            group_commitment += commitment.hiding + commitment.binding * binding_factor;
+            binding_factors.push(binding_factor);
+        }
+
+        // Construct the challenge preimage as `(g_r, pk_sig, pr_sig, address, message)`, as in `Signature::sign`.
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage.extend([group_commitment, pk_sig, pr_sig, *address].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+
+        let indices = commitments.iter().map(|commitment| commitment.index).collect();
+        Ok(Self { indices, binding_factors, challenge })
+    }
+
+    /// Returns the binding factor and Lagrange coefficient of the signer at the given index.
+    fn signer(&self, index: u16) -> Result<(Scalar<N>, Scalar<N>)> {
+        let position = self.indices.iter().position(|i| *i == index);
+        let Some(position) = position else { bail!("Participant {index} is not among the signers") };
+        Ok((self.binding_factors[position], lagrange_coefficient(index, &self.indices)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Signs the given message with the given signers, returning the commitments and signature shares.
+    fn sign(
+        threshold_key: &ThresholdKey<CurrentNetwork>,
+        signers: &[KeyShare<CurrentNetwork>],
+        message: &[Field<CurrentNetwork>],
+        rng: &mut TestRng,
+    ) -> Result<(Vec<SigningCommitments<CurrentNetwork>>, Vec<SignatureShare<CurrentNetwork>>)> {
+        // Run the first round.
+        let nonces = signers.iter().map(|signer| SigningNonces::new(signer, rng)).collect::<Vec<_>>();
+        let commitments = nonces.iter().map(SigningNonces::commitments).collect::<Vec<_>>();
+        // Run the second round.
+        let shares = signers
+            .iter()
+            .zip_eq(nonces)
+            .map(|(signer, nonces)| signer.sign(threshold_key, nonces, &commitments, message))
+            .collect::<Result<Vec<_>>>()?;
+        Ok((commitments, shares))
+    }
+
+    #[test]
+    fn test_sign_with_dealer() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for (threshold, num_participants) in [(1, 1), (2, 3), (3, 5)] {
+            let (private_key, threshold_key, key_shares) =
+                test_helpers::sample_threshold_key(threshold, num_participants, &mut rng);
+            let address = Address::try_from(&private_key)?;
+
+            for num_fields in 0..5 {
+                let message = (0..num_fields).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+
+                // Sign with the first `threshold` participants.
+                let signers = &key_shares[..usize::from(threshold)];
+                let (commitments, shares) = sign(&threshold_key, signers, &message, &mut rng)?;
+                let signature = threshold_key.aggregate(&commitments, &message, &shares)?;
+                assert!(signature.verify(&address, &message));
+                assert_eq!(address, signature.to_address());
+
+                // Sign with all participants.
+                let (commitments, shares) = sign(&threshold_key, &key_shares, &message, &mut rng)?;
+                let signature = threshold_key.aggregate(&commitments, &message, &shares)?;
+                assert!(signature.verify(&address, &message));
+
+                // Ensure the signature does not verify for another message.
+                let other = (0..num_fields + 1).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+                assert!(!signature.verify(&address, &other));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_with_dkg() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let outputs = test_helpers::run_dkg(3, 5, &mut rng)?;
+        let threshold_key = outputs[0].0.clone();
+        let key_shares = outputs.iter().map(|(_, key_share)| *key_share).collect::<Vec<_>>();
+
+        let message = (0..4).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+        // Sign with participants 2, 4, and 5.
+        let signers = [key_shares[1], key_shares[3], key_shares[4]];
+        let (commitments, shares) = sign(&threshold_key, &signers, &message, &mut rng)?;
+        let signature = threshold_key.aggregate(&commitments, &message, &shares)?;
+        assert!(signature.verify(&threshold_key.to_address(), &message));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_invalid() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        let (_, threshold_key, key_shares) = test_helpers::sample_threshold_key(2, 3, &mut rng);
+        let message = (0..4).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+
+        // Ensure too few signers are rejected.
+        assert!(sign(&threshold_key, &key_shares[..1], &message, &mut rng).is_err());
+        // Ensure duplicate or unordered signers are rejected.
+        assert!(sign(&threshold_key, &[key_shares[0], key_shares[0]], &message, &mut rng).is_err());
+        assert!(sign(&threshold_key, &[key_shares[1], key_shares[0]], &message, &mut rng).is_err());
+
+        // Ensure an invalid signature share identifies its signer.
+        let (commitments, mut shares) = sign(&threshold_key, &key_shares[..2], &message, &mut rng)?;
+        shares[1].response += Scalar::one();
+        let error = threshold_key.aggregate(&commitments, &message, &shares).unwrap_err();
+        assert!(error.to_string().contains("Participant 2"));
+
+        // Ensure signature shares for a different message are rejected.
+        let (commitments, shares) = sign(&threshold_key, &key_shares[..2], &message, &mut rng)?;
+        assert!(threshold_key.aggregate(&commitments, &message[1..], &shares).is_err());
+
+        // Ensure a key share of another threshold account is rejected.
+        let (_, other_key, _) = test_helpers::sample_threshold_key(2, 3, &mut rng);
+        assert!(sign(&other_key, &key_shares[..2], &message, &mut rng).is_err());
+        Ok(())
+    }
+}
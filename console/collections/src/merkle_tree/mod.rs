@@ -574,6 +574,16 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         path.verify(&self.leaf_hasher, &self.path_hasher, root, leaf)
     }
 
+    /// Returns the leaf hasher of the tree.
+    pub const fn leaf_hasher(&self) -> &LH {
+        &self.leaf_hasher
+    }
+
+    /// Returns the path hasher of the tree.
+    pub const fn path_hasher(&self) -> &PH {
+        &self.path_hasher
+    }
+
     /// Returns the Merkle root of the tree.
     pub const fn root(&self) -> &PH::Hash {
         &self.root
@@ -100,6 +100,8 @@ pub trait Network:
     const DEPLOYMENT_FEE_MULTIPLIER: u64 = 1_000; // 1 millicredit per byte
     /// The maximum number of microcredits that can be spent as a fee.
     const MAX_FEE: u64 = 1_000_000_000_000_000;
+    /// The block height from which the mapping entries written in finalize are priced by their storage.
+    const STORAGE_PRICING_HEIGHT: u32 = 500_000;
 
     /// The anchor time per block in seconds, which must be greater than the round time per block.
     const ANCHOR_TIME: u16 = 25;
@@ -141,6 +143,8 @@ pub trait Network:
     const MAX_COMMANDS: usize = u16::MAX as usize;
     /// The maximum number of write commands in finalize.
     const MAX_WRITES: u16 = 10;
+    /// The maximum number of events emitted by a transaction.
+    const MAX_EVENTS: u16 = 32;
 
     /// The maximum number of inputs per transition.
     const MAX_INPUTS: usize = 16;
@@ -15,6 +15,7 @@
 mod bytes;
 mod parse;
 mod serialize;
+mod size_in_bits;
 
 use snarkvm_console_network::prelude::*;
 
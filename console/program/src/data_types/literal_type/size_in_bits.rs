@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_types::{Address, Boolean, Field, Group, Scalar, I128, I16, I32, I64, I8, U128, U16, U32, U64, U8};
+
+impl LiteralType {
+    /// Returns the number of bits of a literal of this type.
+    /// For `string`, this is the number of bits of the longest possible string literal.
+    pub fn size_in_bits<N: Network>(&self) -> u16 {
+        let size = match self {
+            Self::Address => Address::<N>::size_in_bits(),
+            Self::Boolean => Boolean::<N>::size_in_bits(),
+            Self::Field => Field::<N>::size_in_bits(),
+            Self::Group => Group::<N>::size_in_bits(),
+            Self::I8 => I8::<N>::size_in_bits(),
+            Self::I16 => I16::<N>::size_in_bits(),
+            Self::I32 => I32::<N>::size_in_bits(),
+            Self::I64 => I64::<N>::size_in_bits(),
+            Self::I128 => I128::<N>::size_in_bits(),
+            Self::U8 => U8::<N>::size_in_bits(),
+            Self::U16 => U16::<N>::size_in_bits(),
+            Self::U32 => U32::<N>::size_in_bits(),
+            Self::U64 => U64::<N>::size_in_bits(),
+            Self::U128 => U128::<N>::size_in_bits(),
+            Self::Scalar => Scalar::<N>::size_in_bits(),
+            Self::String => match (N::MAX_STRING_BYTES as usize).checked_mul(8) {
+                Some(size) => size,
+                None => N::halt("String exceeds usize::MAX bits."),
+            },
+        };
+        u16::try_from(size).or_halt_with::<N>("Literal exceeds u16::MAX bits.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Literal;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_size_in_bits() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            for variant in 0..=15u8 {
+                // Sample a literal of the given type.
+                let literal_type = LiteralType::from_u8(variant).unwrap();
+                let literal = Literal::<CurrentNetwork>::sample(literal_type, &mut rng);
+                // Ensure the size of the literal type bounds the size of the literal.
+                match literal_type {
+                    LiteralType::String => {
+                        assert!(literal.size_in_bits() <= literal_type.size_in_bits::<CurrentNetwork>())
+                    }
+                    _ => assert_eq!(literal.size_in_bits(), literal_type.size_in_bits::<CurrentNetwork>()),
+                }
+            }
+        }
+    }
+}
@@ -35,6 +35,11 @@ pub type BlockTree<N> = BHPMerkleTree<N, BLOCKS_DEPTH>;
 /// The Merkle path for the state tree blocks.
 pub type BlockPath<N> = MerklePath<N, BLOCKS_DEPTH>;
 
+/// The Merkle tree for the finalize operations and events in a block.
+pub type FinalizeTree<N> = BHPMerkleTree<N, FINALIZE_OPERATIONS_DEPTH>;
+/// The Merkle path for a finalize operation or event in a block.
+pub type FinalizePath<N> = MerklePath<N, FINALIZE_OPERATIONS_DEPTH>;
+
 /// The Merkle tree for the block header.
 pub type HeaderTree<N> = BHPMerkleTree<N, HEADER_DEPTH>;
 /// The Merkle path for the block header.
@@ -30,6 +30,8 @@ impl<N: Network> Block<N> {
             && self.transactions.num_rejected() == 0
             // Ensure there is the correct number of finalize operations in the genesis block.
             && self.transactions.num_finalize() == 0
+            // Ensure there are no events in the genesis block.
+            && self.transactions.events().next().is_none()
             // Ensure there is the correct number of ratification operations in the genesis block.
             && self.ratifications.is_empty()
             // Ensure the coinbase solution does not exist.
@@ -49,5 +51,7 @@ mod tests {
         // Load the genesis block.
         let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
         assert!(genesis_block.is_genesis());
+        // Ensure the finalize root is unaffected by the event leaves, as the genesis block has no events.
+        assert_eq!(genesis_block.header().finalize_root(), genesis_block.transactions().to_finalize_root().unwrap());
     }
 }
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use crate::DecryptedTransition;
+use console::account::ViewKey;
+
+impl<N: Network> Transaction<N> {
+    /// Returns all transitions in the transaction (including the fee transition), as seen with the given view key.
+    pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<Vec<DecryptedTransition<N>>> {
+        self.transitions().map(|transition| transition.decrypt(view_key)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::PrivateKey;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_decrypt() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis transaction, which mints a record to the private key.
+        let (_, transaction, private_key) = crate::test_helpers::sample_genesis_block_and_components(rng);
+
+        // Ensure the owner sees the minted record in every transition.
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let decrypted = transaction.decrypt(&view_key).unwrap();
+        assert_eq!(decrypted.len(), transaction.transitions().count());
+        assert_eq!(decrypted.iter().flat_map(DecryptedTransition::records).count(), transaction.records().count());
+
+        // Ensure another account sees none of the records.
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let decrypted = transaction.decrypt(&other_view_key).unwrap();
+        assert_eq!(decrypted.len(), transaction.transitions().count());
+        assert_eq!(decrypted.iter().flat_map(DecryptedTransition::records).count(), 0);
+    }
+}
@@ -19,8 +19,8 @@ mod string;
 use crate::{Input, Transition};
 use console::{
     network::prelude::*,
-    program::{Literal, Plaintext},
-    types::U64,
+    program::{Literal, Plaintext, Value},
+    types::{Address, Field, U64},
 };
 use synthesizer_snark::Proof;
 
@@ -46,15 +46,50 @@ impl<N: Network> Fee<N> {
         self.amount().map(|amount| amount.is_zero())
     }
 
+    /// Returns `true` if the fee is paid from the payer's `account` mapping.
+    pub fn is_fee_public(&self) -> bool {
+        self.transition.is_fee_public()
+    }
+
     /// Returns the amount (in microcredits).
     pub fn amount(&self) -> Result<U64<N>> {
+        // Determine the input index of the amount.
+        let index = match self.is_fee_public() {
+            true => 0,
+            false => 1,
+        };
         // Retrieve the amount (in microcredits) as a plaintext value.
-        match self.transition.inputs().get(1) {
+        match self.transition.inputs().get(index) {
             Some(Input::Public(_, Some(Plaintext::Literal(Literal::U64(microcredits), _)))) => Ok(*microcredits),
             _ => bail!("Failed to retrieve the fee (in microcredits) from the fee transition"),
         }
     }
 
+    /// Returns the deployment or execution ID that the fee is paying for.
+    pub fn deployment_or_execution_id(&self) -> Result<Field<N>> {
+        // Determine the input index of the deployment or execution ID.
+        let index = match self.is_fee_public() {
+            true => 1,
+            false => 2,
+        };
+        // Retrieve the deployment or execution ID as a plaintext value.
+        match self.transition.inputs().get(index) {
+            Some(Input::Public(_, Some(Plaintext::Literal(Literal::Field(id), _)))) => Ok(*id),
+            _ => bail!("Failed to retrieve the deployment or execution ID from the fee transition"),
+        }
+    }
+
+    /// Returns the payer of a public fee, or `None` if the fee is paid from a record.
+    pub fn payer(&self) -> Option<Address<N>> {
+        // A public fee reveals the payer as its first input for finalize.
+        match self.transition.finalize()?.first() {
+            Some(Value::Plaintext(Plaintext::Literal(Literal::Address(payer), _))) if self.is_fee_public() => {
+                Some(*payer)
+            }
+            _ => None,
+        }
+    }
+
     /// Returns the transition ID.
     pub fn transition_id(&self) -> &N::TransitionID {
         self.transition.id()
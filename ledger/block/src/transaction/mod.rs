@@ -22,6 +22,7 @@ mod fee;
 pub use fee::*;
 
 mod bytes;
+mod decrypt;
 mod merkle;
 mod serialize;
 mod string;
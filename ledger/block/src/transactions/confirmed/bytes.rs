@@ -43,7 +43,7 @@ impl<N: Network> FromBytes for ConfirmedTransaction<N> {
                 let finalize =
                     (0..num_finalize).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
                 // Return the confirmed transaction.
-                Self::accepted_execute(index, transaction, finalize).map_err(|e| error(e.to_string()))
+                Self::accepted_execute(index, transaction, finalize, vec![]).map_err(|e| error(e.to_string()))
             }
             2 => {
                 // Read the index.
@@ -65,7 +65,28 @@ impl<N: Network> FromBytes for ConfirmedTransaction<N> {
                 // Return the confirmed transaction.
                 Self::rejected_execute(index, transaction, rejected).map_err(|e| error(e.to_string()))
             }
-            4.. => Err(error(format!("Failed to decode confirmed transaction variant {variant}"))),
+            4 => {
+                // Read the index.
+                let index = u32::read_le(&mut reader)?;
+                // Read the transaction.
+                let transaction = Transaction::<N>::read_le(&mut reader)?;
+                // Read the number of finalize operations.
+                let num_finalize = NumFinalizeSize::read_le(&mut reader)?;
+                // Read the finalize operations.
+                let finalize =
+                    (0..num_finalize).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                // Read the number of events.
+                let num_events = NumEventsSize::read_le(&mut reader)?;
+                // Ensure the accepted execute transaction emitted events.
+                if num_events == 0 {
+                    return Err(error("An accepted execute transaction without events must use variant 1"));
+                }
+                // Read the events.
+                let events = (0..num_events).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                // Return the confirmed transaction.
+                Self::accepted_execute(index, transaction, finalize, events).map_err(|e| error(e.to_string()))
+            }
+            5.. => Err(error(format!("Failed to decode confirmed transaction variant {variant}"))),
         }
     }
 }
@@ -86,9 +107,14 @@ impl<N: Network> ToBytes for ConfirmedTransaction<N> {
                 // Write the finalize operations.
                 finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))
             }
-            Self::AcceptedExecute(index, transaction, finalize) => {
+            Self::AcceptedExecute(index, transaction, finalize, events) => {
                 // Write the variant.
-                1u8.write_le(&mut writer)?;
+                // Note: An accepted execution without events is written as variant 1, which predates events,
+                // so that its bytes are unchanged. An accepted execution with events is written as variant 4.
+                match events.is_empty() {
+                    true => 1u8.write_le(&mut writer)?,
+                    false => 4u8.write_le(&mut writer)?,
+                }
                 // Write the index.
                 index.write_le(&mut writer)?;
                 // Write the transaction.
@@ -96,7 +122,15 @@ impl<N: Network> ToBytes for ConfirmedTransaction<N> {
                 // Write the number of finalize operations.
                 NumFinalizeSize::try_from(finalize.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
                 // Write the finalize operations.
-                finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))
+                finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))?;
+                // If there are events, write them.
+                if !events.is_empty() {
+                    // Write the number of events.
+                    NumEventsSize::try_from(events.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+                    // Write the events.
+                    events.iter().try_for_each(|event| event.write_le(&mut writer))?;
+                }
+                Ok(())
             }
             Self::RejectedDeploy(index, transaction, rejected) => {
                 // Write the variant.
@@ -138,4 +172,32 @@ mod tests {
             assert!(ConfirmedTransaction::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         }
     }
+
+    #[test]
+    fn test_accepted_execute_variants() {
+        let rng = &mut TestRng::default();
+
+        // Sample an accepted execute transaction with events.
+        let expected = crate::transactions::confirmed::test_helpers::sample_accepted_execute(0, rng);
+        assert!(!expected.events().is_empty());
+        // Ensure it is written as variant 4.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(expected_bytes[0], 4);
+
+        // Construct the same accepted execute transaction without events.
+        let ConfirmedTransaction::AcceptedExecute(index, transaction, finalize, _) = expected else {
+            unreachable!("The sample is an accepted execute transaction")
+        };
+        let expected = ConfirmedTransaction::accepted_execute(index, transaction, finalize, vec![]).unwrap();
+        // Ensure it is written as variant 1, which has no events, as before.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(expected_bytes[0], 1);
+        assert_eq!(expected, ConfirmedTransaction::read_le(&expected_bytes[..]).unwrap());
+
+        // Ensure variant 4 does not accept an empty list of events.
+        let mut invalid_bytes = expected_bytes.clone();
+        invalid_bytes[0] = 4;
+        invalid_bytes.extend(0u16.to_bytes_le().unwrap());
+        assert!(ConfirmedTransaction::<CurrentNetwork>::read_le(&invalid_bytes[..]).is_err());
+    }
 }
@@ -18,17 +18,18 @@ mod string;
 
 use crate::{rejected::Rejected, Transaction};
 use console::network::prelude::*;
-use synthesizer_program::FinalizeOperation;
+use synthesizer_program::{Event, FinalizeOperation, RejectionReason};
 
 pub type NumFinalizeSize = u16;
+pub type NumEventsSize = u16;
 
 /// The confirmed transaction.
 #[derive(Clone, PartialEq, Eq)]
 pub enum ConfirmedTransaction<N: Network> {
     /// The accepted deploy transaction is composed of `(index, deploy_transaction, finalize_operations)`.
     AcceptedDeploy(u32, Transaction<N>, Vec<FinalizeOperation<N>>),
-    /// The accepted execute transaction is composed of `(index, execute_transaction, finalize_operations)`.
-    AcceptedExecute(u32, Transaction<N>, Vec<FinalizeOperation<N>>),
+    /// The accepted execute transaction is composed of `(index, execute_transaction, finalize_operations, events)`.
+    AcceptedExecute(u32, Transaction<N>, Vec<FinalizeOperation<N>>, Vec<Event<N>>),
     /// The rejected deploy transaction is composed of `(index, fee_transaction, rejected_deployment)`.
     RejectedDeploy(u32, Transaction<N>, Rejected<N>),
     /// The rejected execute transaction is composed of `(index, fee_transaction, rejected_execution)`.
@@ -42,25 +43,44 @@ impl<N: Network> ConfirmedTransaction<N> {
         transaction: Transaction<N>,
         finalize_operations: Vec<FinalizeOperation<N>>,
     ) -> Result<Self> {
-        // Retrieve the program from the deployment, and ensure the transaction is a deploy transaction.
-        let program = match &transaction {
-            Transaction::Deploy(_, _, deployment, _) => deployment.program(),
+        // Retrieve the program and fee from the deployment, and ensure the transaction is a deploy transaction.
+        let (program, fee) = match &transaction {
+            Transaction::Deploy(_, _, deployment, fee) => (deployment.program(), fee),
             Transaction::Execute(..) | Transaction::Fee(..) => {
                 bail!("Transaction '{}' is not a deploy transaction", transaction.id())
             }
         };
+        // Determine the number of finalize operations from the mappings.
+        // Note: A public fee appends its own finalize operations after the mappings are initialized.
+        let num_mapping_operations = match fee.is_fee_public() {
+            true => program.mappings().len().min(finalize_operations.len()),
+            false => finalize_operations.len(),
+        };
         // Ensure the number of program mappings matches the number of finalize operations.
-        if program.mappings().len() != finalize_operations.len() {
+        if program.mappings().len() != num_mapping_operations {
             bail!(
                 "The number of program mappings ({}) does not match the number of finalize operations ({})",
                 program.mappings().len(),
-                finalize_operations.len()
+                num_mapping_operations
             )
         }
         // Ensure the finalize operations contain the correct types.
-        for operation in finalize_operations.iter() {
-            // Ensure the finalize operation is an initialize mapping.
-            if !matches!(operation, FinalizeOperation::InitializeMapping(..)) {
+        for (index, operation) in finalize_operations.iter().enumerate() {
+            // Ensure the finalize operation is an initialize mapping, or a key-value operation
+            // from the constructor or the public fee.
+            let is_valid = match index < num_mapping_operations {
+                true => matches!(operation, FinalizeOperation::InitializeMapping(..)),
+                false => matches!(
+                    operation,
+                    FinalizeOperation::InsertKeyValue(..)
+                        | FinalizeOperation::UpdateKeyValue(..)
+                        | FinalizeOperation::RemoveKeyValue(..)
+                        | FinalizeOperation::InsertSizedKeyValue(..)
+                        | FinalizeOperation::UpdateSizedKeyValue(..)
+                        | FinalizeOperation::RemoveSizedKeyValue(..)
+                ),
+            };
+            if !is_valid {
                 bail!("Transaction '{}' (deploy) contains an invalid finalize operation type", transaction.id())
             }
         }
@@ -73,24 +93,43 @@ impl<N: Network> ConfirmedTransaction<N> {
         index: u32,
         transaction: Transaction<N>,
         finalize_operations: Vec<FinalizeOperation<N>>,
+        events: Vec<Event<N>>,
     ) -> Result<Self> {
+        // Ensure the number of finalize operations is within bounds.
+        // Note: This ensures the number of finalize operations can be written as a `NumFinalizeSize`.
+        if finalize_operations.len() > NumFinalizeSize::MAX as usize {
+            bail!("Transaction '{}' (execute) contains too many finalize operations", transaction.id())
+        }
         // Ensure the finalize operations contain the correct types.
         for operation in finalize_operations.iter() {
-            // Ensure the finalize operation is an insert or update key-value operation.
+            // Ensure the finalize operation is an insert, update, or remove key-value operation.
             match operation {
-                FinalizeOperation::InsertKeyValue(..) | FinalizeOperation::UpdateKeyValue(..) => (),
-                FinalizeOperation::InitializeMapping(..)
-                | FinalizeOperation::RemoveMapping(..)
-                | FinalizeOperation::RemoveKeyValue(..) => {
+                FinalizeOperation::InsertKeyValue(..)
+                | FinalizeOperation::UpdateKeyValue(..)
+                | FinalizeOperation::RemoveKeyValue(..)
+                | FinalizeOperation::InsertSizedKeyValue(..)
+                | FinalizeOperation::UpdateSizedKeyValue(..)
+                | FinalizeOperation::RemoveSizedKeyValue(..) => (),
+                FinalizeOperation::InitializeMapping(..) | FinalizeOperation::RemoveMapping(..) => {
                     bail!("Transaction '{}' (execute) contains an invalid finalize operation type", transaction.id())
                 }
             }
         }
         // Ensure the transaction is an execute transaction.
-        match transaction.is_execute() {
-            true => Ok(Self::AcceptedExecute(index, transaction, finalize_operations)),
-            false => bail!("Transaction '{}' is not an execute transaction", transaction.id()),
+        let Transaction::Execute(_, execution, _) = &transaction else {
+            bail!("Transaction '{}' is not an execute transaction", transaction.id())
+        };
+        // Ensure the number of events is within bounds.
+        if events.len() > N::MAX_EVENTS as usize {
+            bail!("Transaction '{}' (execute) contains too many events", transaction.id())
         }
+        // Ensure each event was emitted by a program in the execution.
+        for event in events.iter() {
+            if !execution.transitions().any(|transition| transition.program_id() == event.program_id()) {
+                bail!("Transaction '{}' (execute) contains an event from '{}'", transaction.id(), event.program_id())
+            }
+        }
+        Ok(Self::AcceptedExecute(index, transaction, finalize_operations, events))
     }
 
     /// Returns a new instance of a rejected deploy transaction.
@@ -146,7 +185,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub fn transaction(&self) -> &Transaction<N> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => transaction,
-            Self::AcceptedExecute(_, transaction, _) => transaction,
+            Self::AcceptedExecute(_, transaction, ..) => transaction,
             Self::RejectedDeploy(_, transaction, _) => transaction,
             Self::RejectedExecute(_, transaction, _) => transaction,
         }
@@ -156,7 +195,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub fn into_transaction(self) -> Transaction<N> {
         match self {
             Self::AcceptedDeploy(_, transaction, _) => transaction,
-            Self::AcceptedExecute(_, transaction, _) => transaction,
+            Self::AcceptedExecute(_, transaction, ..) => transaction,
             Self::RejectedDeploy(_, transaction, _) => transaction,
             Self::RejectedExecute(_, transaction, _) => transaction,
         }
@@ -165,7 +204,7 @@ impl<N: Network> ConfirmedTransaction<N> {
     /// Returns the number of finalize operations.
     pub fn num_finalize(&self) -> usize {
         match self {
-            Self::AcceptedDeploy(_, _, finalize) | Self::AcceptedExecute(_, _, finalize) => finalize.len(),
+            Self::AcceptedDeploy(_, _, finalize) | Self::AcceptedExecute(_, _, finalize, _) => finalize.len(),
             Self::RejectedDeploy(..) | Self::RejectedExecute(..) => 0,
         }
     }
@@ -174,10 +213,27 @@ impl<N: Network> ConfirmedTransaction<N> {
     pub fn finalize_operations(&self) -> Option<&Vec<FinalizeOperation<N>>> {
         match self {
             Self::AcceptedDeploy(_, _, finalize) => Some(finalize),
-            Self::AcceptedExecute(_, _, finalize) => Some(finalize),
+            Self::AcceptedExecute(_, _, finalize, _) => Some(finalize),
             Self::RejectedDeploy(..) | Self::RejectedExecute(..) => None,
         }
     }
+
+    /// Returns the events emitted by the confirmed transaction.
+    pub fn events(&self) -> &[Event<N>] {
+        match self {
+            Self::AcceptedExecute(_, _, _, events) => events,
+            Self::AcceptedDeploy(..) | Self::RejectedDeploy(..) | Self::RejectedExecute(..) => &[],
+        }
+    }
+
+    /// Returns the reason the execution was rejected, if the confirmed transaction is a rejected execution
+    /// whose `finalize` scope failed on a command.
+    pub fn rejection_reason(&self) -> Option<&RejectionReason<N>> {
+        match self {
+            Self::RejectedExecute(_, _, rejected) => rejected.reason(),
+            Self::AcceptedDeploy(..) | Self::AcceptedExecute(..) | Self::RejectedDeploy(..) => None,
+        }
+    }
 }
 
 impl<N: Network> Deref for ConfirmedTransaction<N> {
@@ -192,7 +248,10 @@ impl<N: Network> Deref for ConfirmedTransaction<N> {
 #[cfg(test)]
 pub mod test_helpers {
     use super::*;
-    use console::network::Testnet3;
+    use console::{
+        network::Testnet3,
+        program::{Identifier, Plaintext},
+    };
 
     type CurrentNetwork = Testnet3;
 
@@ -209,8 +268,12 @@ pub mod test_helpers {
     pub(crate) fn sample_accepted_execute(index: u32, rng: &mut TestRng) -> ConfirmedTransaction<CurrentNetwork> {
         // Sample an execute transaction.
         let tx = crate::transaction::test_helpers::sample_execution_transaction_with_fee(rng);
+        // Sample an event from the executed program.
+        let program_id = *tx.transitions().next().unwrap().program_id();
+        let name = Identifier::from_str("sample").unwrap();
+        let event = Event::new(program_id, name, vec![Plaintext::from_str("1u64").unwrap()]).unwrap();
         // Return the confirmed transaction.
-        ConfirmedTransaction::accepted_execute(index, tx, vec![]).unwrap()
+        ConfirmedTransaction::accepted_execute(index, tx, vec![], vec![event]).unwrap()
     }
 
     /// Samples a rejected deploy transaction at the given index.
@@ -28,13 +28,18 @@ impl<N: Network> Serialize for ConfirmedTransaction<N> {
                     object.serialize_field("finalize", finalize_operations)?;
                     object.end()
                 }
-                Self::AcceptedExecute(index, transaction, finalize_operations) => {
-                    let mut object = serializer.serialize_struct("ConfirmedTransaction", 5)?;
+                Self::AcceptedExecute(index, transaction, finalize_operations, events) => {
+                    let num_fields = 5 + !events.is_empty() as usize;
+                    let mut object = serializer.serialize_struct("ConfirmedTransaction", num_fields)?;
                     object.serialize_field("status", "accepted")?;
                     object.serialize_field("type", "execute")?;
                     object.serialize_field("index", index)?;
                     object.serialize_field("transaction", transaction)?;
                     object.serialize_field("finalize", finalize_operations)?;
+                    // Note: The events are omitted if there are none, as in the format that predates events.
+                    if !events.is_empty() {
+                        object.serialize_field("events", events)?;
+                    }
                     object.end()
                 }
                 Self::RejectedDeploy(index, transaction, rejected_deployment) => {
@@ -89,8 +94,13 @@ impl<'de, N: Network> Deserialize<'de> for ConfirmedTransaction<N> {
                     (Some("accepted"), Some("execute")) => {
                         // Parse the finalize operations.
                         let finalize: Vec<_> = DeserializeExt::take_from_value::<D>(&mut object, "finalize")?;
+                        // Parse the events, if any.
+                        let events: Vec<_> = match object.get("events") {
+                            Some(_) => DeserializeExt::take_from_value::<D>(&mut object, "events")?,
+                            None => vec![],
+                        };
                         // Return the accepted execute transaction.
-                        Self::accepted_execute(index, transaction, finalize).map_err(de::Error::custom)
+                        Self::accepted_execute(index, transaction, finalize, events).map_err(de::Error::custom)
                     }
                     (Some("rejected"), Some("deploy")) => {
                         // Parse the rejected deployment.
@@ -16,13 +16,35 @@ use super::*;
 
 impl<N: Network> Transactions<N> {
     /// Returns the finalize root of the transactions.
+    ///
+    /// Note: This is a consensus rule. The finalize root commits to the finalize operations,
+    /// followed by the IDs of the emitted events, so a block with events has a different finalize root
+    /// than it would have had before events were introduced. The root of a block without events is unchanged.
     pub fn to_finalize_root(&self) -> Result<Field<N>> {
-        // Prepare the leaves.
-        let leaves = self.finalize_operations().map(|op| op.to_bits_le());
+        Ok(*self.to_finalize_tree()?.root())
+    }
+
+    /// Returns the Merkle path for the given event, which may be verified against the finalize root.
+    pub fn to_event_path(&self, event: &Event<N>) -> Result<FinalizePath<N>> {
+        match self.events().position(|candidate| candidate == event) {
+            // Note: The event leaves are appended after the finalize operation leaves.
+            Some(index) => {
+                self.to_finalize_tree()?.prove(self.finalize_operations().count() + index, &event.to_id()?.to_bits_le())
+            }
+            None => bail!("The event '{}' is not in the block transactions", event.name()),
+        }
+    }
+
+    /// The Merkle tree of finalize operations and event IDs for the block.
+    pub fn to_finalize_tree(&self) -> Result<FinalizeTree<N>> {
+        // Prepare the leaves, starting with the finalize operations.
+        let mut leaves = self.finalize_operations().map(|op| op.to_bits_le()).collect::<Vec<_>>();
+        // Append the event IDs to the leaves.
+        for event in self.events() {
+            leaves.push(event.to_id()?.to_bits_le());
+        }
         // Compute the finalize tree.
-        let tree = N::merkle_tree_bhp::<FINALIZE_OPERATIONS_DEPTH>(&leaves.collect::<Vec<_>>())?;
-        // Return the finalize root.
-        Ok(*tree.root())
+        N::merkle_tree_bhp::<FINALIZE_OPERATIONS_DEPTH>(&leaves)
     }
 }
 
@@ -70,6 +92,30 @@ mod tests {
 
     type CurrentNetwork = Testnet3;
 
+    #[test]
+    fn test_event_path() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transactions, which include an event.
+        let transactions: Transactions<CurrentNetwork> = [
+            crate::transactions::confirmed::test_helpers::sample_accepted_deploy(0, rng),
+            crate::transactions::confirmed::test_helpers::sample_accepted_execute(1, rng),
+        ]
+        .into_iter()
+        .collect();
+
+        // Compute the finalize root.
+        let finalize_root = transactions.to_finalize_root().unwrap();
+        // Ensure each event is verifiable against the finalize root.
+        let events = transactions.events().cloned().collect::<Vec<_>>();
+        assert_eq!(events.len(), 1);
+        for event in &events {
+            let path = transactions.to_event_path(event).unwrap();
+            let leaf = event.to_id().unwrap().to_bits_le();
+            assert!(CurrentNetwork::verify_merkle_path_bhp(&path, &finalize_root, &leaf));
+        }
+    }
+
     #[test]
     fn test_transactions_depth() {
         // Ensure the log2 relationship between depth and the maximum number of transactions.
@@ -28,6 +28,8 @@ use console::{
     network::prelude::*,
     program::{
         Ciphertext,
+        FinalizePath,
+        FinalizeTree,
         ProgramOwner,
         Record,
         TransactionsPath,
@@ -37,7 +39,7 @@ use console::{
     },
     types::{Field, Group, U64},
 };
-use synthesizer_program::FinalizeOperation;
+use synthesizer_program::{Event, FinalizeOperation};
 
 use indexmap::IndexMap;
 
@@ -251,6 +253,11 @@ impl<N: Network> Transactions<N> {
     pub fn finalize_operations(&self) -> impl '_ + Iterator<Item = &FinalizeOperation<N>> {
         self.iter().flat_map(|tx| tx.finalize_operations()).flatten()
     }
+
+    /// Returns an iterator over the events, for all transactions.
+    pub fn events(&self) -> impl '_ + Iterator<Item = &Event<N>> {
+        self.iter().flat_map(|tx| tx.events())
+    }
 }
 
 impl<N: Network> IntoIterator for Transactions<N> {
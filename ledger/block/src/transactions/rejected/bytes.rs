@@ -31,9 +31,17 @@ impl<N: Network> FromBytes for Rejected<N> {
                 // Read the execution.
                 let execution = Execution::read_le(&mut reader)?;
                 // Return the rejected execution.
-                Ok(Self::new_execution(execution))
+                Ok(Self::new_execution(execution, None))
             }
-            2.. => Err(error(format!("Failed to decode rejected transaction variant {variant}"))),
+            2 => {
+                // Read the execution.
+                let execution = Execution::read_le(&mut reader)?;
+                // Read the reason.
+                let reason = RejectionReason::read_le(&mut reader)?;
+                // Return the rejected execution.
+                Ok(Self::new_execution(execution, Some(reason)))
+            }
+            3.. => Err(error(format!("Failed to decode rejected transaction variant {variant}"))),
         }
     }
 }
@@ -50,12 +58,20 @@ impl<N: Network> ToBytes for Rejected<N> {
                 // Write the deployment.
                 deployment.write_le(&mut writer)
             }
-            Self::Execution(execution) => {
+            Self::Execution(execution, None) => {
                 // Write the variant.
                 1u8.write_le(&mut writer)?;
                 // Write the execution.
                 execution.write_le(&mut writer)
             }
+            Self::Execution(execution, Some(reason)) => {
+                // Write the variant.
+                2u8.write_le(&mut writer)?;
+                // Write the execution.
+                execution.write_le(&mut writer)?;
+                // Write the reason.
+                reason.write_le(&mut writer)
+            }
         }
     }
 }
@@ -19,12 +19,14 @@ mod string;
 use super::*;
 
 use crate::{Deployment, Execution};
+use synthesizer_program::RejectionReason;
 
 /// A wrapper around the rejected deployment or execution.
+/// A rejected execution records the reason its `finalize` scope was rejected, if a command failed.
 #[derive(Clone, PartialEq, Eq)]
 pub enum Rejected<N: Network> {
     Deployment(ProgramOwner<N>, Box<Deployment<N>>),
-    Execution(Execution<N>),
+    Execution(Execution<N>, Option<RejectionReason<N>>),
 }
 
 impl<N: Network> Rejected<N> {
@@ -33,9 +35,9 @@ impl<N: Network> Rejected<N> {
         Self::Deployment(program_owner, Box::new(deployment))
     }
 
-    /// Initializes a rejected execution.
-    pub fn new_execution(execution: Execution<N>) -> Self {
-        Self::Execution(execution)
+    /// Initializes a rejected execution, with the reason for the rejection (if any).
+    pub fn new_execution(execution: Execution<N>, reason: Option<RejectionReason<N>>) -> Self {
+        Self::Execution(execution, reason)
     }
 
     /// Returns true if the rejected transaction is a deployment.
@@ -52,7 +54,7 @@ impl<N: Network> Rejected<N> {
     pub fn program_owner(&self) -> Option<&ProgramOwner<N>> {
         match self {
             Self::Deployment(program_owner, _) => Some(program_owner),
-            Self::Execution(_) => None,
+            Self::Execution(..) => None,
         }
     }
 
@@ -60,7 +62,7 @@ impl<N: Network> Rejected<N> {
     pub fn deployment(&self) -> Option<&Deployment<N>> {
         match self {
             Self::Deployment(_, deployment) => Some(deployment),
-            Self::Execution(_) => None,
+            Self::Execution(..) => None,
         }
     }
 
@@ -68,7 +70,15 @@ impl<N: Network> Rejected<N> {
     pub fn execution(&self) -> Option<&Execution<N>> {
         match self {
             Self::Deployment(_, _) => None,
-            Self::Execution(execution) => Some(execution),
+            Self::Execution(execution, _) => Some(execution),
+        }
+    }
+
+    /// Returns the reason the execution was rejected, if a command in its `finalize` scope failed.
+    pub fn reason(&self) -> Option<&RejectionReason<N>> {
+        match self {
+            Self::Deployment(_, _) => None,
+            Self::Execution(_, reason) => reason.as_ref(),
         }
     }
 
@@ -76,7 +86,7 @@ impl<N: Network> Rejected<N> {
     pub fn to_id(&self) -> Result<Field<N>> {
         match self {
             Self::Deployment(_, deployment) => deployment.to_deployment_id(),
-            Self::Execution(execution) => execution.to_execution_id(),
+            Self::Execution(execution, _) => execution.to_execution_id(),
         }
     }
 }
@@ -84,7 +94,11 @@ impl<N: Network> Rejected<N> {
 #[cfg(test)]
 pub mod test_helpers {
     use super::*;
-    use console::{account::PrivateKey, network::Testnet3};
+    use console::{
+        account::PrivateKey,
+        network::Testnet3,
+        program::{Identifier, ProgramID},
+    };
 
     type CurrentNetwork = Testnet3;
 
@@ -114,13 +128,38 @@ pub mod test_helpers {
         };
 
         // Return the rejected execution.
-        Rejected::new_execution(execution)
+        Rejected::new_execution(execution, None)
+    }
+
+    /// Samples a rejected execution, with the reason for the rejection.
+    pub(crate) fn sample_rejected_execution_with_reason(rng: &mut TestRng) -> Rejected<CurrentNetwork> {
+        // Sample an execute transaction.
+        let execution = match crate::transaction::test_helpers::sample_execution_transaction_with_fee(rng) {
+            Transaction::Execute(_, execution, _) => execution,
+            _ => unreachable!(),
+        };
+        // Construct the reason for the rejection.
+        let reason = RejectionReason::new(
+            ProgramID::from_str("token.aleo").unwrap(),
+            Identifier::from_str("transfer").unwrap(),
+            1,
+            "assert.eq",
+            Some("balance is too low"),
+        )
+        .unwrap();
+
+        // Return the rejected execution.
+        Rejected::new_execution(execution, Some(reason))
     }
 
     /// Sample a list of randomly rejected transactions.
     pub(crate) fn sample_rejected_transactions() -> Vec<Rejected<CurrentNetwork>> {
         let rng = &mut TestRng::default();
 
-        vec![sample_rejected_deployment(rng), sample_rejected_execution(rng)]
+        vec![
+            sample_rejected_deployment(rng),
+            sample_rejected_execution(rng),
+            sample_rejected_execution_with_reason(rng),
+        ]
     }
 }
@@ -26,10 +26,13 @@ impl<N: Network> Serialize for Rejected<N> {
                     object.serialize_field("deployment", deployment)?;
                     object.end()
                 }
-                Self::Execution(execution) => {
-                    let mut object = serializer.serialize_struct("Rejected", 2)?;
+                Self::Execution(execution, reason) => {
+                    let mut object = serializer.serialize_struct("Rejected", 2 + reason.is_some() as usize)?;
                     object.serialize_field("type", "execution")?;
                     object.serialize_field("execution", execution)?;
+                    if let Some(reason) = reason {
+                        object.serialize_field("reason", reason)?;
+                    }
                     object.end()
                 }
             },
@@ -64,8 +67,13 @@ impl<'de, N: Network> Deserialize<'de> for Rejected<N> {
                     Some("execution") => {
                         // Parse the execution.
                         let execution: Execution<N> = DeserializeExt::take_from_value::<D>(&mut object, "execution")?;
+                        // Parse the reason, if it exists.
+                        let reason = serde_json::from_value(
+                            object.get_mut("reason").unwrap_or(&mut serde_json::Value::Null).take(),
+                        )
+                        .map_err(de::Error::custom)?;
                         // Return the rejected execution.
-                        Ok(Self::new_execution(execution))
+                        Ok(Self::new_execution(execution, reason))
                     }
                     _ => Err(de::Error::custom("Invalid rejected transaction type")),
                 }
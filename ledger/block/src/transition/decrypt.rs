@@ -0,0 +1,285 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::{account::ViewKey, program::Plaintext, types::U16};
+
+/// A transition input, as seen with a view key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecryptedInput<N: Network> {
+    /// The input hash and plaintext of a constant input.
+    Constant(Field<N>, Plaintext<N>),
+    /// The input hash and plaintext of a public input.
+    Public(Field<N>, Plaintext<N>),
+    /// The input hash and decrypted plaintext of a private input, which is visible to the caller.
+    Private(Field<N>, Plaintext<N>),
+    /// The serial number and tag of a record input.
+    Record(Field<N>, Field<N>),
+    /// The input hash of an external record input.
+    ExternalRecord(Field<N>),
+    /// The input ID of an input that is not visible with the view key.
+    Hidden(Field<N>),
+}
+
+/// A transition output, as seen with a view key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecryptedOutput<N: Network> {
+    /// The output hash and plaintext of a constant output.
+    Constant(Field<N>, Plaintext<N>),
+    /// The output hash and plaintext of a public output.
+    Public(Field<N>, Plaintext<N>),
+    /// The output hash and decrypted plaintext of a private output, which is visible to the caller.
+    Private(Field<N>, Plaintext<N>),
+    /// The commitment and decrypted record of a record output, which is visible to the record owner.
+    Record(Field<N>, Record<N, Plaintext<N>>),
+    /// The output hash of an external record output.
+    ExternalRecord(Field<N>),
+    /// The output ID of an output that is not visible with the view key.
+    Hidden(Field<N>),
+}
+
+/// A transition, as seen with a view key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecryptedTransition<N: Network> {
+    /// The transition ID.
+    id: N::TransitionID,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// Whether the transition view key is known, as it is to the caller of the transition.
+    is_caller: bool,
+    /// The transition inputs.
+    inputs: Vec<DecryptedInput<N>>,
+    /// The transition outputs.
+    outputs: Vec<DecryptedOutput<N>>,
+}
+
+impl<N: Network> DecryptedTransition<N> {
+    /// Returns the transition ID.
+    pub const fn id(&self) -> &N::TransitionID {
+        &self.id
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns `true` if the transition view key is known, as it is to the caller of the transition.
+    pub const fn is_caller(&self) -> bool {
+        self.is_caller
+    }
+
+    /// Returns the transition inputs.
+    pub fn inputs(&self) -> &[DecryptedInput<N>] {
+        &self.inputs
+    }
+
+    /// Returns the transition outputs.
+    pub fn outputs(&self) -> &[DecryptedOutput<N>] {
+        &self.outputs
+    }
+
+    /// Returns an iterator over the records owned by the view key, as `(commitment, record)`.
+    pub fn records(&self) -> impl '_ + Iterator<Item = (&Field<N>, &Record<N, Plaintext<N>>)> {
+        self.outputs.iter().filter_map(|output| match output {
+            DecryptedOutput::Record(commitment, record) => Some((commitment, record)),
+            _ => None,
+        })
+    }
+}
+
+impl<N: Network> Transition<N> {
+    /// Returns the transition as seen with the given view key.
+    ///
+    /// Constant and public values are always visible, and records are visible to their owner.
+    /// If the view key belongs to the caller, the transition view key `tvk` is recovered as `view_key * tpk`,
+    /// which also makes the private inputs and outputs visible.
+    pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<DecryptedTransition<N>> {
+        // Compute the candidate transition view key `tvk` as `view_key * tpk`.
+        let tvk = (self.tpk * **view_key).to_x_coordinate();
+        // Check if the view key belongs to the caller, as `tcm == Hash(tvk)`.
+        let tvk = match N::hash_psd2(&[tvk])? == self.tcm {
+            true => Some(tvk),
+            false => None,
+        };
+        // Decrypt the transition, revealing the private values to the caller, and the records owned by the view key.
+        self.decrypt_with(
+            tvk.is_some(),
+            |index, ciphertext| match tvk {
+                Some(tvk) => Ok(Some(ciphertext.decrypt_symmetric(self.private_value_key(tvk, index)?)?)),
+                None => Ok(None),
+            },
+            |_, record| match record.is_owner(view_key) {
+                true => Ok(Some(record.decrypt(view_key)?)),
+                false => Ok(None),
+            },
+        )
+    }
+
+    /// Returns the symmetric key of the private input or output at the given index,
+    /// as `Hash(function ID || tvk || index)`, where outputs are indexed after the inputs.
+    pub(super) fn private_value_key(&self, tvk: Field<N>, index: usize) -> Result<Field<N>> {
+        N::hash_psd4(&[self.function_id()?, tvk, Field::from_u16(u16::try_from(index)?)])
+    }
+
+    /// Returns the function ID of the transition, as `Hash(network_id, program_id, function_name)`.
+    pub fn function_id(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(
+            &(U16::<N>::new(N::ID), self.program_id.name(), self.program_id.network(), self.function_name).to_bits_le(),
+        )
+    }
+
+    /// Returns the transition as seen by a viewer, where `is_caller` is set if the viewer is the caller,
+    /// `decrypt_private` returns the private value at the given index (if it is visible),
+    /// and `decrypt_record` returns the record at the given output index (if it is visible).
+    pub(super) fn decrypt_with(
+        &self,
+        is_caller: bool,
+        decrypt_private: impl Fn(usize, &Ciphertext<N>) -> Result<Option<Plaintext<N>>>,
+        decrypt_record: impl Fn(usize, &Record<N, Ciphertext<N>>) -> Result<Option<Record<N, Plaintext<N>>>>,
+    ) -> Result<DecryptedTransition<N>> {
+        // Decrypt the inputs.
+        let inputs = self
+            .inputs
+            .iter()
+            .enumerate()
+            .map(|(index, input)| {
+                Ok(match input {
+                    Input::Constant(hash, Some(plaintext)) => DecryptedInput::Constant(*hash, plaintext.clone()),
+                    Input::Public(hash, Some(plaintext)) => DecryptedInput::Public(*hash, plaintext.clone()),
+                    Input::Private(hash, Some(ciphertext)) => match decrypt_private(index, ciphertext)? {
+                        Some(plaintext) => DecryptedInput::Private(*hash, plaintext),
+                        None => DecryptedInput::Hidden(*hash),
+                    },
+                    Input::Record(serial_number, tag) => DecryptedInput::Record(*serial_number, *tag),
+                    Input::ExternalRecord(hash) => DecryptedInput::ExternalRecord(*hash),
+                    input => DecryptedInput::Hidden(*input.id()),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Decrypt the outputs.
+        let outputs = self
+            .outputs
+            .iter()
+            .enumerate()
+            .map(|(index, output)| {
+                Ok(match output {
+                    Output::Constant(hash, Some(plaintext)) => DecryptedOutput::Constant(*hash, plaintext.clone()),
+                    Output::Public(hash, Some(plaintext)) => DecryptedOutput::Public(*hash, plaintext.clone()),
+                    Output::Private(hash, Some(ciphertext)) => {
+                        match decrypt_private(self.inputs.len() + index, ciphertext)? {
+                            Some(plaintext) => DecryptedOutput::Private(*hash, plaintext),
+                            None => DecryptedOutput::Hidden(*hash),
+                        }
+                    }
+                    Output::Record(commitment, _, Some(record)) => match decrypt_record(index, record)? {
+                        Some(record) => DecryptedOutput::Record(*commitment, record),
+                        None => DecryptedOutput::Hidden(*commitment),
+                    },
+                    Output::ExternalRecord(hash) => DecryptedOutput::ExternalRecord(*hash),
+                    output => DecryptedOutput::Hidden(*output.id()),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(DecryptedTransition {
+            id: self.id,
+            program_id: self.program_id,
+            function_name: self.function_name,
+            is_caller,
+            inputs,
+            outputs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::{Address, PrivateKey};
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_decrypt_mint() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis transaction, which mints a record to the private key.
+        let (_, transaction, private_key) = crate::test_helpers::sample_genesis_block_and_components(rng);
+        let transition = transaction.transitions().next().unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+
+        // Ensure the caller sees the public inputs and the minted record.
+        let decrypted = transition.decrypt(&view_key).unwrap();
+        assert!(decrypted.is_caller());
+        assert_eq!(decrypted.id(), transition.id());
+        assert_eq!(decrypted.inputs().len(), transition.inputs().len());
+        assert!(decrypted.inputs().iter().all(|input| matches!(input, DecryptedInput::Public(..))));
+        assert_eq!(decrypted.records().count(), 1);
+
+        // Ensure another account sees the public inputs, but not the minted record.
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let decrypted = transition.decrypt(&other_view_key).unwrap();
+        assert!(!decrypted.is_caller());
+        assert!(decrypted.inputs().iter().all(|input| matches!(input, DecryptedInput::Public(..))));
+        assert_eq!(decrypted.records().count(), 0);
+        assert!(decrypted.outputs().iter().all(|output| matches!(output, DecryptedOutput::Hidden(..))));
+    }
+
+    #[test]
+    fn test_decrypt_transfer_private() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private transfer.
+        let (transition, private_key, recipient_private_key) =
+            crate::transition::disclosure::test_helpers::sample_transfer_private(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let recipient_view_key = ViewKey::try_from(&recipient_private_key).unwrap();
+        let recipient = Address::try_from(&recipient_private_key).unwrap();
+
+        // Ensure the sender sees the private inputs and the change record.
+        let decrypted = transition.decrypt(&view_key).unwrap();
+        assert!(decrypted.is_caller());
+        assert!(matches!(decrypted.inputs()[0], DecryptedInput::Record(..)));
+        match (&decrypted.inputs()[1], &decrypted.inputs()[2]) {
+            (DecryptedInput::Private(_, address), DecryptedInput::Private(_, amount)) => {
+                assert_eq!(address.to_string(), recipient.to_string());
+                assert_eq!(amount.to_string(), "1000000u64");
+            }
+            _ => panic!("Expected the caller to see the private inputs"),
+        }
+        assert_eq!(decrypted.records().count(), 1);
+        assert!(matches!(decrypted.outputs()[0], DecryptedOutput::Hidden(..)));
+        assert!(matches!(decrypted.outputs()[1], DecryptedOutput::Record(..)));
+
+        // Ensure the recipient only sees its own record.
+        let decrypted = transition.decrypt(&recipient_view_key).unwrap();
+        assert!(!decrypted.is_caller());
+        assert!(matches!(decrypted.inputs()[0], DecryptedInput::Record(..)));
+        assert!(matches!(decrypted.inputs()[1], DecryptedInput::Hidden(..)));
+        assert!(matches!(decrypted.inputs()[2], DecryptedInput::Hidden(..)));
+        assert!(matches!(decrypted.outputs()[0], DecryptedOutput::Record(..)));
+        assert!(matches!(decrypted.outputs()[1], DecryptedOutput::Hidden(..)));
+        assert_eq!(decrypted.records().next().unwrap().1.owner().to_string(), format!("{recipient}.private"));
+    }
+}
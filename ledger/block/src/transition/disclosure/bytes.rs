@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> FromBytes for TransitionDisclosure<N> {
+    /// Reads the transition disclosure from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid transition disclosure version"));
+        }
+        // Read the transition ID.
+        let transition_id = N::TransitionID::read_le(&mut reader)?;
+        // Read the number of values.
+        let num_values = u16::read_le(&mut reader)?;
+        // Read the values.
+        let values = (0..num_values)
+            .map(|_| Ok((u16::read_le(&mut reader)?, Field::read_le(&mut reader)?)))
+            .collect::<IoResult<Vec<_>>>()?;
+        // Read the number of records.
+        let num_records = u16::read_le(&mut reader)?;
+        // Read the records.
+        let records = (0..num_records).map(|_| RecordDisclosure::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the proof variant.
+        let proof_variant = u8::read_le(&mut reader)?;
+        // Read the proof.
+        let proof = match proof_variant {
+            0 => None,
+            1 => Some(Proof::read_le(&mut reader)?),
+            _ => return Err(error(format!("Invalid proof variant '{proof_variant}'"))),
+        };
+        // Return the transition disclosure.
+        Ok(Self::from(transition_id, values, records, proof))
+    }
+}
+
+impl<N: Network> ToBytes for TransitionDisclosure<N> {
+    /// Writes the transition disclosure to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        0u8.write_le(&mut writer)?;
+        // Write the transition ID.
+        self.transition_id.write_le(&mut writer)?;
+        // Write the number of values.
+        u16::try_from(self.values.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the values.
+        for (index, key) in &self.values {
+            index.write_le(&mut writer)?;
+            key.write_le(&mut writer)?;
+        }
+        // Write the number of records.
+        u16::try_from(self.records.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the records.
+        self.records.write_le(&mut writer)?;
+        // Write the proof.
+        match self.proof {
+            None => 0u8.write_le(&mut writer)?,
+            Some(ref proof) => {
+                1u8.write_le(&mut writer)?;
+                proof.write_le(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for RecordDisclosure<N> {
+    /// Reads the record disclosure from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the output index.
+        let index = u16::read_le(&mut reader)?;
+        // Read the record key.
+        let record_key = Group::read_le(&mut reader)?;
+        // Read the challenge.
+        let challenge = Scalar::read_le(&mut reader)?;
+        // Read the response.
+        let response = Scalar::read_le(&mut reader)?;
+        // Return the record disclosure.
+        Ok(Self::from(index, record_key, challenge, response))
+    }
+}
+
+impl<N: Network> ToBytes for RecordDisclosure<N> {
+    /// Writes the record disclosure to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the output index.
+        self.index.write_le(&mut writer)?;
+        // Write the record key.
+        self.record_key.write_le(&mut writer)?;
+        // Write the challenge.
+        self.challenge.write_le(&mut writer)?;
+        // Write the response.
+        self.response.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the transition disclosure.
+        let expected = crate::transition::disclosure::test_helpers::sample_disclosure(rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, TransitionDisclosure::read_le(&expected_bytes[..])?);
+        assert!(TransitionDisclosure::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,540 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use super::*;
+
+use console::{
+    account::{Address, ViewKey},
+    types::Scalar,
+};
+use synthesizer_snark::{Proof, VerifyingKey};
+
+static RECORD_DISCLOSURE_DOMAIN: &str = "AleoRecordDisclosureProof0";
+
+/// A disclosure of the private data in a single transition, which can be handed to an auditor in place of a view key.
+///
+/// The disclosure contains the symmetric key of each private input and output, which reveals those values,
+/// and the record keys for the chosen record outputs, which reveal those records only.
+/// The value keys come with a proof that each key is `Hash(function ID || tvk || index)` for the `tvk` that
+/// opens the transition commitment `tcm`, so a value key cannot be substituted without invalidating the proof.
+///
+/// Note: The transition view key `tvk` is deliberately **not** disclosed. The encryption randomizer of every
+/// record output is `HashToScalar(tvk || index)`, so the `tvk` would reveal all of the output records,
+/// including those owned by other parties. Each private value key is `Hash(function ID || tvk || index)`,
+/// which does not reveal the `tvk`, or the keys of the other values.
+#[derive(Clone, PartialEq, Eq)]
+pub struct TransitionDisclosure<N: Network> {
+    /// The transition ID.
+    transition_id: N::TransitionID,
+    /// The index and symmetric key of each private input and output, where outputs are indexed after the inputs.
+    values: Vec<(u16, Field<N>)>,
+    /// The disclosed records.
+    records: Vec<RecordDisclosure<N>>,
+    /// The proof that each value key is derived from the transition view key, if there are values.
+    proof: Option<Proof<N>>,
+}
+
+/// A disclosure of a single record output, with a proof that the record key belongs to the record owner.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct RecordDisclosure<N: Network> {
+    /// The output index of the record.
+    index: u16,
+    /// The record key, as `view_key * nonce`, whose x-coordinate is the record view key.
+    record_key: Group<N>,
+    /// The challenge of the proof that `log_G(owner) == log_nonce(record_key)`.
+    challenge: Scalar<N>,
+    /// The response of the proof that `log_G(owner) == log_nonce(record_key)`.
+    response: Scalar<N>,
+}
+
+impl<N: Network> TransitionDisclosure<N> {
+    /// The name of the circuit that proves the value keys are derived from the transition view key.
+    pub const CIRCUIT_NAME: &'static str = "disclosure";
+
+    /// Initializes a new transition disclosure from its components.
+    pub const fn from(
+        transition_id: N::TransitionID,
+        values: Vec<(u16, Field<N>)>,
+        records: Vec<RecordDisclosure<N>>,
+        proof: Option<Proof<N>>,
+    ) -> Self {
+        Self { transition_id, values, records, proof }
+    }
+
+    /// Returns the transition ID.
+    pub const fn transition_id(&self) -> &N::TransitionID {
+        &self.transition_id
+    }
+
+    /// Returns the index and symmetric key of each disclosed private input and output.
+    pub fn values(&self) -> &[(u16, Field<N>)] {
+        &self.values
+    }
+
+    /// Returns the disclosed records.
+    pub fn records(&self) -> &[RecordDisclosure<N>] {
+        &self.records
+    }
+
+    /// Returns the proof that each value key is derived from the transition view key, if there are values.
+    pub const fn proof(&self) -> Option<&Proof<N>> {
+        self.proof.as_ref()
+    }
+
+    /// Returns the verifier inputs for the proof of each value key, as `(1, tcm, function ID, index, key)`.
+    pub fn to_verifier_inputs(&self, transition: &Transition<N>) -> Result<Vec<Vec<N::Field>>> {
+        // Compute the function ID.
+        let function_id = transition.function_id()?;
+        // Construct the verifier inputs for each value.
+        Ok(self
+            .values
+            .iter()
+            .map(|(index, key)| {
+                vec![N::Field::one(), **transition.tcm(), *function_id, *Field::<N>::from_u16(*index), **key]
+            })
+            .collect())
+    }
+
+    /// Ensures the disclosure belongs to the given transition.
+    ///
+    /// This checks that each value key decrypts its private value and is derived from the transition view key,
+    /// with the given disclosure verifying key, and that each record key belongs to the owner of its record.
+    pub fn verify(&self, transition: &Transition<N>, verifying_key: &VerifyingKey<N>) -> Result<()> {
+        // Ensure the transition ID matches.
+        ensure!(self.transition_id == *transition.id(), "The disclosure is for transition '{}'", self.transition_id);
+
+        // Ensure the value indices are unique.
+        ensure!(
+            !has_duplicates(self.values.iter().map(|(index, _)| index)),
+            "The disclosure contains duplicate values"
+        );
+        // Ensure each value key decrypts its private value.
+        for (index, key) in &self.values {
+            private_value(transition, *index as usize)?
+                .decrypt_symmetric(*key)
+                .map_err(|_| anyhow!("The key for value {index} does not decrypt it"))?;
+        }
+        // Ensure the value keys are derived from the transition view key.
+        match (&self.proof, self.values.is_empty()) {
+            (Some(proof), false) => {
+                let inputs = vec![(verifying_key.clone(), self.to_verifier_inputs(transition)?)];
+                ensure!(
+                    VerifyingKey::verify_batch(Self::CIRCUIT_NAME, inputs, proof),
+                    "The value keys are not derived from the transition view key"
+                );
+            }
+            (None, true) => (),
+            (None, false) => bail!("The disclosure is missing the proof for its value keys"),
+            (Some(_), true) => bail!("The disclosure contains a proof without value keys"),
+        }
+
+        // Ensure the record indices are unique.
+        ensure!(
+            !has_duplicates(self.records.iter().map(RecordDisclosure::index)),
+            "The disclosure contains duplicate records"
+        );
+        // Ensure each record key belongs to the owner of its record.
+        for record in &self.records {
+            let (commitment, ciphertext) = record_output(transition, record.index as usize)?;
+            record.verify(&self.transition_id, commitment, ciphertext)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the given transition as seen with this disclosure.
+    ///
+    /// Only the disclosed private values are decrypted, with their keys,
+    /// and only the disclosed records are decrypted, with their record view keys.
+    pub fn decrypt(
+        &self,
+        transition: &Transition<N>,
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<DecryptedTransition<N>> {
+        // Ensure the disclosure belongs to the transition.
+        self.verify(transition, verifying_key)?;
+        // Decrypt the transition, revealing only the disclosed values and records.
+        transition.decrypt_with(
+            false,
+            |index, ciphertext| match self.values.iter().find(|(value_index, _)| *value_index as usize == index) {
+                Some((_, key)) => Ok(Some(ciphertext.decrypt_symmetric(*key)?)),
+                None => Ok(None),
+            },
+            |index, ciphertext| match self.records.iter().find(|record| record.index as usize == index) {
+                Some(record) => Ok(Some(ciphertext.decrypt_symmetric_unchecked(&record.record_view_key())?)),
+                None => Ok(None),
+            },
+        )
+    }
+}
+
+impl<N: Network> RecordDisclosure<N> {
+    /// Initializes a new record disclosure from its components.
+    pub const fn from(index: u16, record_key: Group<N>, challenge: Scalar<N>, response: Scalar<N>) -> Self {
+        Self { index, record_key, challenge, response }
+    }
+
+    /// Returns the output index of the record.
+    pub const fn index(&self) -> u16 {
+        self.index
+    }
+
+    /// Returns the record key, as `view_key * nonce`.
+    pub const fn record_key(&self) -> &Group<N> {
+        &self.record_key
+    }
+
+    /// Returns the record view key.
+    pub fn record_view_key(&self) -> Field<N> {
+        self.record_key.to_x_coordinate()
+    }
+
+    /// Returns the challenge and response of the proof.
+    pub const fn proof(&self) -> (&Scalar<N>, &Scalar<N>) {
+        (&self.challenge, &self.response)
+    }
+
+    /// Ensures the record key belongs to the owner of the given record.
+    fn verify(
+        &self,
+        transition_id: &N::TransitionID,
+        commitment: &Field<N>,
+        ciphertext: &Record<N, Ciphertext<N>>,
+    ) -> Result<()> {
+        // Decrypt the record, to recover the owner.
+        let record = ciphertext.decrypt_symmetric_unchecked(&self.record_view_key())?;
+        let owner = **record.owner();
+        let nonce = *ciphertext.nonce();
+
+        // Recompute `g_r` as `response * G + challenge * owner`.
+        let g_r = N::g_scalar_multiply(&self.response) + (*owner * self.challenge);
+        // Recompute `n_r` as `response * nonce + challenge * record_key`.
+        let n_r = (nonce * self.response) + (self.record_key * self.challenge);
+        // Recompute the challenge.
+        let challenge =
+            record_disclosure_challenge(transition_id, commitment, &owner, &nonce, &self.record_key, g_r, n_r)?;
+        // Ensure the challenge matches.
+        ensure!(challenge == self.challenge, "The record key for output {} does not belong to its owner", self.index);
+        Ok(())
+    }
+}
+
+impl<N: Network> Transition<N> {
+    /// Returns a disclosure of this transition, revealing the private inputs and outputs,
+    /// and the records at the given output indices, without revealing the transition view key.
+    ///
+    /// The view key must belong to the caller of the transition and to the owner of each disclosed record.
+    /// If there are private values, `prove` is called with the transition view key and the value keys,
+    /// and returns the proof that each value key is derived from the transition view key.
+    pub fn disclose<R: Rng + CryptoRng>(
+        &self,
+        view_key: &ViewKey<N>,
+        record_indices: &[usize],
+        prove: impl FnOnce(Field<N>, &[(u16, Field<N>)], &mut R) -> Result<Proof<N>>,
+        rng: &mut R,
+    ) -> Result<TransitionDisclosure<N>> {
+        // Compute the transition view key `tvk` as `view_key * tpk`.
+        let tvk = (self.tpk * **view_key).to_x_coordinate();
+        // Ensure the view key belongs to the caller, as `tcm == Hash(tvk)`.
+        ensure!(N::hash_psd2(&[tvk])? == self.tcm, "The view key does not belong to the caller of '{}'", self.id);
+        // Ensure the record indices are unique.
+        ensure!(!has_duplicates(record_indices.iter()), "The record indices must be unique");
+
+        // Compute the key of each private input and output.
+        let private_indices = self.inputs.iter().map(|input| matches!(input, Input::Private(..)));
+        let private_indices =
+            private_indices.chain(self.outputs.iter().map(|output| matches!(output, Output::Private(..))));
+        let values = private_indices
+            .enumerate()
+            .filter(|(_, is_private)| *is_private)
+            .map(|(index, _)| Ok((u16::try_from(index)?, self.private_value_key(tvk, index)?)))
+            .collect::<Result<Vec<_>>>()?;
+        // Prove that each value key is derived from the transition view key.
+        let proof = match values.is_empty() {
+            true => None,
+            false => Some(prove(tvk, &values, rng)?),
+        };
+
+        // Retrieve the address.
+        let address = view_key.to_address();
+        // Disclose each record.
+        let records = record_indices
+            .iter()
+            .map(|index| {
+                let (commitment, ciphertext) = record_output(self, *index)?;
+                // Ensure the view key belongs to the record owner.
+                ensure!(ciphertext.is_owner(view_key), "The view key does not own the record at output {index}");
+
+                // Compute the record key as `view_key * nonce`.
+                let nonce = *ciphertext.nonce();
+                let record_key = nonce * **view_key;
+
+                // Prove that `log_G(address) == log_nonce(record_key)`.
+                let r = Scalar::rand(rng);
+                let g_r = N::g_scalar_multiply(&r);
+                let n_r = nonce * r;
+                let challenge =
+                    record_disclosure_challenge(&self.id, commitment, &address, &nonce, &record_key, g_r, n_r)?;
+                let response = r - challenge * **view_key;
+
+                Ok(RecordDisclosure::from(u16::try_from(*index)?, record_key, challenge, response))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(TransitionDisclosure::from(self.id, values, records, proof))
+    }
+}
+
+/// Returns the ciphertext of the private input or output at the given index,
+/// where the outputs are indexed after the inputs.
+fn private_value<N: Network>(transition: &Transition<N>, index: usize) -> Result<&Ciphertext<N>> {
+    let value = match index.checked_sub(transition.inputs().len()) {
+        None => match transition.inputs().get(index) {
+            Some(Input::Private(_, Some(ciphertext))) => Some(ciphertext),
+            _ => None,
+        },
+        Some(output_index) => match transition.outputs().get(output_index) {
+            Some(Output::Private(_, Some(ciphertext))) => Some(ciphertext),
+            _ => None,
+        },
+    };
+    value.ok_or_else(|| anyhow!("Value {index} of transition '{}' is not a private value", transition.id()))
+}
+
+/// Returns the commitment and record ciphertext of the record output at the given index.
+fn record_output<N: Network>(
+    transition: &Transition<N>,
+    index: usize,
+) -> Result<(&Field<N>, &Record<N, Ciphertext<N>>)> {
+    match transition.outputs().get(index) {
+        Some(Output::Record(commitment, _, Some(record))) => Ok((commitment, record)),
+        Some(_) => bail!("Output {index} of transition '{}' is not a record", transition.id()),
+        None => bail!("Output {index} does not exist in transition '{}'", transition.id()),
+    }
+}
+
+/// Returns the challenge of the proof that `log_G(owner) == log_nonce(record_key)`.
+fn record_disclosure_challenge<N: Network>(
+    transition_id: &N::TransitionID,
+    commitment: &Field<N>,
+    owner: &Address<N>,
+    nonce: &Group<N>,
+    record_key: &Group<N>,
+    g_r: Group<N>,
+    n_r: Group<N>,
+) -> Result<Scalar<N>> {
+    // Construct the preimage as `(domain, transition ID, commitment, owner, nonce, record_key, g_r, n_r)`.
+    let mut preimage = vec![Field::new_domain_separator(RECORD_DISCLOSURE_DOMAIN), **transition_id, *commitment];
+    preimage.extend([**owner, *nonce, *record_key, g_r, n_r].map(|point| point.to_x_coordinate()));
+    N::hash_to_scalar_psd8(&preimage)
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::account::PrivateKey;
+    use synthesizer_process::{DisclosureAssignment, Process};
+    use synthesizer_snark::ProvingKey;
+
+    type CurrentNetwork = console::network::Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    /// Returns a `credits.aleo/transfer_private` transition, and the sender and recipient private keys.
+    /// Output 0 is the recipient's record, and output 1 is the sender's change record.
+    pub(crate) fn sample_transfer_private(
+        rng: &mut TestRng,
+    ) -> (Transition<CurrentNetwork>, PrivateKey<CurrentNetwork>, PrivateKey<CurrentNetwork>) {
+        // Sample the genesis transaction, which mints a record to the private key.
+        let (_, transaction, private_key) = crate::test_helpers::sample_genesis_block_and_components(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let record = transaction.records().next().unwrap().1.decrypt(&view_key).unwrap();
+
+        // Sample the recipient.
+        let recipient_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let recipient = Address::try_from(&recipient_private_key).unwrap();
+
+        // Authorize and execute a private transfer.
+        let process = Process::load().unwrap();
+        let inputs = [record.to_string(), recipient.to_string(), "1_000_000_u64".to_string()];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key, "credits.aleo", "transfer_private", inputs.iter(), rng)
+            .unwrap();
+        let (_, trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+        // Note: This is a testing-only hack to adhere to Rust's dependency cycle rules.
+        let transition = Transition::from_str(&trace.transitions()[0].to_string()).unwrap();
+
+        (transition, private_key, recipient_private_key)
+    }
+
+    /// Returns a disclosure of the given transition, revealing the records at the given output indices.
+    pub(crate) fn disclose(
+        transition: &Transition<CurrentNetwork>,
+        view_key: &ViewKey<CurrentNetwork>,
+        record_indices: &[usize],
+        rng: &mut TestRng,
+    ) -> Result<TransitionDisclosure<CurrentNetwork>> {
+        // Note: This is a testing-only hack to adhere to Rust's dependency cycle rules, in place of `Process::disclose`.
+        let (proving_key, _) = Process::load()?.disclosure_keys::<CurrentAleo>()?;
+        let prove = |tvk, values: &[(u16, Field<CurrentNetwork>)], rng: &mut TestRng| {
+            let function_id = transition.function_id()?;
+            let assignments = values
+                .iter()
+                .map(|(index, key)| {
+                    DisclosureAssignment::new(tvk, *transition.tcm(), function_id, *index, *key)
+                        .to_circuit_assignment::<CurrentAleo>()
+                })
+                .collect::<Result<Vec<_>>>()?;
+            ProvingKey::prove_batch(
+                TransitionDisclosure::<CurrentNetwork>::CIRCUIT_NAME,
+                &[(proving_key, assignments)],
+                rng,
+            )
+        };
+        transition.disclose(view_key, record_indices, prove, rng)
+    }
+
+    /// Returns the verifying key of the disclosure circuit.
+    pub(crate) fn sample_disclosure_verifying_key() -> VerifyingKey<CurrentNetwork> {
+        Process::load().unwrap().disclosure_keys::<CurrentAleo>().unwrap().1
+    }
+
+    /// Returns a disclosure of the sender's change record in a `credits.aleo/transfer_private` transition.
+    pub(crate) fn sample_disclosure(rng: &mut TestRng) -> TransitionDisclosure<CurrentNetwork> {
+        let (transition, private_key, _) = sample_transfer_private(rng);
+        disclose(&transition, &ViewKey::try_from(&private_key).unwrap(), &[1], rng).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{test_helpers::disclose, *};
+
+    #[test]
+    fn test_disclose() {
+        let rng = &mut TestRng::default();
+
+        let (transition, private_key, recipient_private_key) = test_helpers::sample_transfer_private(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let verifying_key = test_helpers::sample_disclosure_verifying_key();
+
+        // Disclose the sender's change record.
+        let disclosure = disclose(&transition, &view_key, &[1], rng).unwrap();
+        assert_eq!(disclosure.transition_id(), transition.id());
+        assert_eq!(disclosure.records().len(), 1);
+        disclosure.verify(&transition, &verifying_key).unwrap();
+
+        // Ensure the disclosure does not contain the transition view key.
+        let tvk = (*transition.tpk() * *view_key).to_x_coordinate();
+        assert!(disclosure.values().iter().all(|(_, key)| *key != tvk));
+        assert_eq!(disclosure.values().iter().map(|(index, _)| *index).collect::<Vec<_>>(), vec![1, 2]);
+
+        // Ensure the auditor sees the private inputs and the disclosed record, as the sender does.
+        let decrypted = disclosure.decrypt(&transition, &verifying_key).unwrap();
+        let expected = transition.decrypt(&view_key).unwrap();
+        assert!(!decrypted.is_caller());
+        assert_eq!(decrypted.inputs(), expected.inputs());
+        assert_eq!(decrypted.outputs(), expected.outputs());
+        assert!(matches!(decrypted.inputs()[1], DecryptedInput::Private(..)));
+        assert!(matches!(decrypted.inputs()[2], DecryptedInput::Private(..)));
+        assert!(matches!(decrypted.outputs()[0], DecryptedOutput::Hidden(..)));
+        assert!(matches!(decrypted.outputs()[1], DecryptedOutput::Record(..)));
+
+        // Ensure a disclosure without records reveals the private inputs, but not the change record.
+        let disclosure = disclose(&transition, &view_key, &[], rng).unwrap();
+        let decrypted = disclosure.decrypt(&transition, &verifying_key).unwrap();
+        assert!(matches!(decrypted.inputs()[1], DecryptedInput::Private(..)));
+        assert_eq!(decrypted.records().count(), 0);
+
+        // Ensure the sender cannot disclose the recipient's record, or a missing output.
+        assert!(disclose(&transition, &view_key, &[0], rng).is_err());
+        assert!(disclose(&transition, &view_key, &[2], rng).is_err());
+        assert!(disclose(&transition, &view_key, &[1, 1], rng).is_err());
+        // Ensure the recipient cannot disclose the transition, as it is not the caller.
+        let recipient_view_key = ViewKey::try_from(&recipient_private_key).unwrap();
+        assert!(disclose(&transition, &recipient_view_key, &[0], rng).is_err());
+    }
+
+    #[test]
+    fn test_disclosure_verify_fails() {
+        let rng = &mut TestRng::default();
+
+        let (transition, private_key, recipient_private_key) = test_helpers::sample_transfer_private(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let verifying_key = test_helpers::sample_disclosure_verifying_key();
+        let disclosure = disclose(&transition, &view_key, &[1], rng).unwrap();
+        let (transition_id, values, record) =
+            (*disclosure.transition_id(), disclosure.values().to_vec(), disclosure.records()[0]);
+        let proof = disclosure.proof().cloned();
+
+        // Ensure a different value key is rejected.
+        let candidate =
+            TransitionDisclosure::from(transition_id, vec![(1, Uniform::rand(rng))], vec![record], proof.clone());
+        assert!(candidate.verify(&transition, &verifying_key).is_err());
+        assert!(candidate.decrypt(&transition, &verifying_key).is_err());
+
+        // Ensure a disclosure without the proof of its value keys is rejected.
+        let candidate = TransitionDisclosure::from(transition_id, values.clone(), vec![record], None);
+        assert!(candidate.verify(&transition, &verifying_key).is_err());
+
+        // Ensure a proof for different value keys is rejected.
+        let candidate = TransitionDisclosure::from(transition_id, vec![values[0]], vec![record], proof.clone());
+        assert!(candidate.verify(&transition, &verifying_key).is_err());
+
+        // Ensure a value key for an output that is not a private value is rejected.
+        let candidate = TransitionDisclosure::from(transition_id, vec![(0, values[0].1)], vec![record], proof.clone());
+        assert!(candidate.verify(&transition, &verifying_key).is_err());
+
+        // Ensure duplicate values are rejected.
+        let candidate =
+            TransitionDisclosure::from(transition_id, vec![values[0], values[0]], vec![record], proof.clone());
+        assert!(candidate.verify(&transition, &verifying_key).is_err());
+
+        // Ensure a different transition ID is rejected.
+        let candidate = TransitionDisclosure::from(rng.gen(), values.clone(), vec![record], proof.clone());
+        assert!(candidate.verify(&transition, &verifying_key).is_err());
+
+        // Ensure a record disclosure for a different output is rejected.
+        let (challenge, response) = record.proof();
+        let candidate_record = RecordDisclosure::from(0, *record.record_key(), *challenge, *response);
+        let candidate =
+            TransitionDisclosure::from(transition_id, values.clone(), vec![candidate_record], proof.clone());
+        assert!(candidate.verify(&transition, &verifying_key).is_err());
+
+        // Ensure a record key for the wrong owner is rejected, even with a valid-looking proof.
+        let recipient_view_key = ViewKey::try_from(&recipient_private_key).unwrap();
+        let Some(Output::Record(_, _, Some(ciphertext))) = transition.outputs().get(1) else { unreachable!() };
+        let record_key = *ciphertext.nonce() * *recipient_view_key;
+        let candidate_record = RecordDisclosure::from(1, record_key, *challenge, *response);
+        let candidate =
+            TransitionDisclosure::from(transition_id, values.clone(), vec![candidate_record], proof.clone());
+        assert!(candidate.verify(&transition, &verifying_key).is_err());
+
+        // Ensure a tampered proof is rejected.
+        let candidate_record = RecordDisclosure::from(1, *record.record_key(), *challenge, Uniform::rand(rng));
+        let candidate =
+            TransitionDisclosure::from(transition_id, values.clone(), vec![candidate_record], proof.clone());
+        assert!(candidate.verify(&transition, &verifying_key).is_err());
+
+        // Ensure duplicate records are rejected.
+        let candidate = TransitionDisclosure::from(transition_id, values.clone(), vec![record, record], proof.clone());
+        assert!(candidate.verify(&transition, &verifying_key).is_err());
+
+        // Ensure the disclosure does not verify against another transition.
+        let (other_transition, _, _) = test_helpers::sample_transfer_private(rng);
+        assert!(disclosure.verify(&other_transition, &verifying_key).is_err());
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> Serialize for TransitionDisclosure<N> {
+    /// Serializes the transition disclosure into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut disclosure = serializer.serialize_struct("TransitionDisclosure", 4)?;
+                disclosure.serialize_field("transition_id", &self.transition_id)?;
+                disclosure.serialize_field("values", &self.values)?;
+                disclosure.serialize_field("records", &self.records)?;
+                if let Some(proof) = &self.proof {
+                    disclosure.serialize_field("proof", proof)?;
+                }
+                disclosure.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for TransitionDisclosure<N> {
+    /// Deserializes the transition disclosure from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the transition disclosure from a string into a value.
+                let mut disclosure = serde_json::Value::deserialize(deserializer)?;
+                // Retrieve the transition ID.
+                let transition_id = DeserializeExt::take_from_value::<D>(&mut disclosure, "transition_id")?;
+                // Retrieve the values.
+                let values = DeserializeExt::take_from_value::<D>(&mut disclosure, "values")?;
+                // Retrieve the records.
+                let records = DeserializeExt::take_from_value::<D>(&mut disclosure, "records")?;
+                // Retrieve the proof.
+                let proof =
+                    serde_json::from_value(disclosure.get_mut("proof").unwrap_or(&mut serde_json::Value::Null).take())
+                        .map_err(de::Error::custom)?;
+                // Recover the transition disclosure.
+                Ok(Self::from(transition_id, values, records, proof))
+            }
+            false => {
+                FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "transition disclosure")
+            }
+        }
+    }
+}
+
+impl<N: Network> Serialize for RecordDisclosure<N> {
+    /// Serializes the record disclosure into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut disclosure = serializer.serialize_struct("RecordDisclosure", 4)?;
+                disclosure.serialize_field("index", &self.index)?;
+                disclosure.serialize_field("record_key", &self.record_key)?;
+                disclosure.serialize_field("challenge", &self.challenge)?;
+                disclosure.serialize_field("response", &self.response)?;
+                disclosure.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for RecordDisclosure<N> {
+    /// Deserializes the record disclosure from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the record disclosure from a string into a value.
+                let mut disclosure = serde_json::Value::deserialize(deserializer)?;
+                // Recover the record disclosure.
+                Ok(Self::from(
+                    // Retrieve the output index.
+                    DeserializeExt::take_from_value::<D>(&mut disclosure, "index")?,
+                    // Retrieve the record key.
+                    DeserializeExt::take_from_value::<D>(&mut disclosure, "record_key")?,
+                    // Retrieve the challenge.
+                    DeserializeExt::take_from_value::<D>(&mut disclosure, "challenge")?,
+                    // Retrieve the response.
+                    DeserializeExt::take_from_value::<D>(&mut disclosure, "response")?,
+                ))
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "record disclosure"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the transition disclosure.
+        let expected = crate::transition::disclosure::test_helpers::sample_disclosure(rng);
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        // Deserialize
+        assert_eq!(expected, TransitionDisclosure::from_str(expected_string)?);
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the transition disclosure.
+        let expected = crate::transition::disclosure::test_helpers::sample_disclosure(rng);
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, TransitionDisclosure::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
+}
@@ -18,6 +18,12 @@ pub use input::Input;
 pub mod output;
 pub use output::Output;
 
+mod decrypt;
+pub use decrypt::*;
+
+mod disclosure;
+pub use disclosure::*;
+
 mod bytes;
 mod merkle;
 mod serialize;
@@ -307,9 +313,15 @@ impl<N: Network> Transition<N> {
             && self.finalize.is_none()
     }
 
-    /// Returns `true` if this is a `fee` transition.
+    /// Returns `true` if this is a `fee` or `fee_public` transition.
     #[inline]
     pub fn is_fee(&self) -> bool {
+        self.is_fee_private() || self.is_fee_public()
+    }
+
+    /// Returns `true` if this is a `fee` transition, which pays from a record.
+    #[inline]
+    pub fn is_fee_private(&self) -> bool {
         self.program_id.to_string() == "credits.aleo"
             && self.function_name.to_string() == "fee"
             && self.inputs.len() == 3
@@ -317,6 +329,16 @@ impl<N: Network> Transition<N> {
             && self.finalize.is_none()
     }
 
+    /// Returns `true` if this is a `fee_public` transition, which pays from the `account` mapping.
+    #[inline]
+    pub fn is_fee_public(&self) -> bool {
+        self.program_id.to_string() == "credits.aleo"
+            && self.function_name.to_string() == "fee_public"
+            && self.inputs.len() == 2
+            && self.outputs.is_empty()
+            && self.finalize.as_ref().map_or(false, |finalize| finalize.len() == 2)
+    }
+
     /// Returns `true` if this is a `split` transition.
     #[inline]
     pub fn is_split(&self) -> bool {
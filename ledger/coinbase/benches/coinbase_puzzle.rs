@@ -21,10 +21,11 @@ use console::{
     account::*,
     network::{Network, Testnet3},
 };
-use snarkvm_ledger_coinbase::{CoinbasePuzzle, EpochChallenge, PuzzleConfig};
+use snarkvm_ledger_coinbase::{CoinbasePuzzle, EpochChallenge, PuzzleConfig, SearchCancellation};
 
-use criterion::Criterion;
+use criterion::{Criterion, Throughput};
 use rand::{self, thread_rng, CryptoRng, RngCore};
+use std::time::Duration;
 
 type CoinbasePuzzleInst = CoinbasePuzzle<Testnet3>;
 
@@ -141,10 +142,49 @@ fn coinbase_puzzle_verify(c: &mut Criterion) {
     }
 }
 
+#[cfg(feature = "setup")]
+fn coinbase_puzzle_search(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let universal_srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    for degree in [(1 << 13) - 1] {
+        let config = PuzzleConfig { degree };
+        let puzzle = CoinbasePuzzleInst::trim(&universal_srs, config).unwrap();
+        let (epoch_challenge, address, nonce) = sample_inputs(degree, rng);
+
+        let mut group = c.benchmark_group(format!("CoinbasePuzzle::Search 2^{}", ((degree + 1) as f64).log2()));
+        // Report the throughput in nonces attempted per second.
+        group.throughput(Throughput::Elements(1));
+
+        for num_threads in [1, 4, 16] {
+            group.bench_function(format!("{num_threads} threads"), |b| {
+                b.iter_custom(|iters| {
+                    // Search with an unreachable target, and cancel the search after a fixed period.
+                    let cancellation = SearchCancellation::new();
+                    let result = std::thread::scope(|scope| {
+                        let handle = scope.spawn(|| {
+                            puzzle.search(&epoch_challenge, address, u64::MAX, nonce, num_threads, &cancellation)
+                        });
+                        std::thread::sleep(Duration::from_secs(1));
+                        cancellation.cancel();
+                        handle.join().unwrap().unwrap()
+                    });
+                    // Scale the average time per attempt to the requested number of iterations.
+                    result.elapsed.div_f64(result.num_attempts.max(1) as f64).mul_f64(iters as f64)
+                })
+            });
+        }
+        group.finish();
+    }
+}
+
 criterion_group! {
     name = coinbase_puzzle;
     config = Criterion::default().sample_size(10);
-    targets = coinbase_puzzle_trim, coinbase_puzzle_prove, coinbase_puzzle_accumulate, coinbase_puzzle_verify,
+    targets = coinbase_puzzle_trim, coinbase_puzzle_prove, coinbase_puzzle_accumulate, coinbase_puzzle_verify, coinbase_puzzle_search,
 }
 
 criterion_main!(coinbase_puzzle);
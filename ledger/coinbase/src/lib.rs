@@ -22,6 +22,9 @@ pub use helpers::*;
 mod hash;
 use hash::*;
 
+mod search;
+pub use search::*;
+
 #[cfg(test)]
 mod tests;
 
@@ -122,19 +125,8 @@ impl<N: Network> CoinbasePuzzle<N> {
             Self::Verifier(_) => bail!("Cannot prove the coinbase puzzle with a verifier"),
         };
 
-        let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
-
-        let product_evaluations = {
-            let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(&polynomial, &pk.fft_precomputation);
-            let product_evaluations = pk.product_domain.mul_polynomials_in_evaluation_domain(
-                polynomial_evaluations,
-                &epoch_challenge.epoch_polynomial_evaluations().evaluations,
-            );
-            product_evaluations
-        };
-        let (commitment, _rand) = KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, None)?;
-
-        let partial_solution = PartialSolution::new(address, nonce, commitment);
+        let (polynomial, product_evaluations, partial_solution) =
+            Self::prover_commitment(pk, epoch_challenge, address, nonce)?;
 
         // Check that the minimum target is met.
         if let Some(minimum_target) = minimum_proof_target {
@@ -145,21 +137,7 @@ impl<N: Network> CoinbasePuzzle<N> {
             );
         }
 
-        let point = hash_commitment(&commitment)?;
-        let product_eval_at_point = polynomial.evaluate(point) * epoch_challenge.epoch_polynomial().evaluate(point);
-
-        let proof = KZG10::open_lagrange(
-            &pk.lagrange_basis(),
-            pk.product_domain_elements(),
-            &product_evaluations,
-            point,
-            product_eval_at_point,
-        )?;
-        ensure!(!proof.is_hiding(), "The prover solution must contain a non-hiding proof");
-
-        debug_assert!(KZG10::check(&pk.verifying_key, &commitment, point, product_eval_at_point, &proof)?);
-
-        Ok(ProverSolution::new(partial_solution, proof))
+        Self::prover_opening(pk, epoch_challenge, &polynomial, product_evaluations, partial_solution)
     }
 
     /// Returns a coinbase solution for the given epoch challenge and prover solutions.
@@ -370,6 +348,61 @@ impl<N: Network> CoinbasePuzzle<N> {
 }
 
 impl<N: Network> CoinbasePuzzle<N> {
+    /// Returns the prover polynomial, the product evaluations, and the partial solution for the given nonce.
+    pub(crate) fn prover_commitment(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+    ) -> Result<(
+        DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+        Vec<<N::PairingCurve as PairingEngine>::Fr>,
+        PartialSolution<N>,
+    )> {
+        let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
+
+        let product_evaluations = {
+            let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(&polynomial, &pk.fft_precomputation);
+            let product_evaluations = pk.product_domain.mul_polynomials_in_evaluation_domain(
+                polynomial_evaluations,
+                &epoch_challenge.epoch_polynomial_evaluations().evaluations,
+            );
+            product_evaluations
+        };
+        let (commitment, _rand) = KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, None)?;
+
+        let partial_solution = PartialSolution::new(address, nonce, commitment);
+
+        Ok((polynomial, product_evaluations, partial_solution))
+    }
+
+    /// Returns the prover solution for the given partial solution, by opening its commitment.
+    pub(crate) fn prover_opening(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        polynomial: &DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+        product_evaluations: Vec<<N::PairingCurve as PairingEngine>::Fr>,
+        partial_solution: PartialSolution<N>,
+    ) -> Result<ProverSolution<N>> {
+        let commitment = *partial_solution.commitment();
+
+        let point = hash_commitment(&commitment)?;
+        let product_eval_at_point = polynomial.evaluate(point) * epoch_challenge.epoch_polynomial().evaluate(point);
+
+        let proof = KZG10::open_lagrange(
+            &pk.lagrange_basis(),
+            pk.product_domain_elements(),
+            &product_evaluations,
+            point,
+            product_eval_at_point,
+        )?;
+        ensure!(!proof.is_hiding(), "The prover solution must contain a non-hiding proof");
+
+        debug_assert!(KZG10::check(&pk.verifying_key, &commitment, point, product_eval_at_point, &proof)?);
+
+        Ok(ProverSolution::new(partial_solution, proof))
+    }
+
     /// Checks that the degree for the epoch and prover polynomial is within bounds,
     /// and returns the evaluation domain for the product polynomial.
     pub(crate) fn product_domain(degree: u32) -> Result<EvaluationDomain<N::Field>> {
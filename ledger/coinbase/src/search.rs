@@ -0,0 +1,141 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{CoinbasePuzzle, EpochChallenge, ProverSolution};
+use console::{
+    account::Address,
+    prelude::{anyhow, ensure, Network, Result},
+};
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// A handle to cancel a running solution search.
+#[derive(Clone, Debug, Default)]
+pub struct SearchCancellation {
+    /// Whether the search is cancelled.
+    cancelled: Arc<AtomicBool>,
+}
+
+impl SearchCancellation {
+    /// Initializes a new cancellation handle.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the search. The search returns once each thread finishes its current attempt.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns `true` if the search is cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// The outcome of a solution search.
+#[derive(Clone)]
+pub struct SearchResult<N: Network> {
+    /// The prover solution that meets the proof target, if one was found before the search was cancelled.
+    pub solution: Option<ProverSolution<N>>,
+    /// The number of nonces attempted.
+    pub num_attempts: u64,
+    /// The time spent searching.
+    pub elapsed: Duration,
+}
+
+impl<N: Network> SearchResult<N> {
+    /// Returns the number of nonces attempted per second.
+    pub fn hashes_per_second(&self) -> f64 {
+        match self.elapsed.as_secs_f64() {
+            seconds if seconds > 0.0 => self.num_attempts as f64 / seconds,
+            _ => 0.0,
+        }
+    }
+}
+
+impl<N: Network> CoinbasePuzzle<N> {
+    /// Searches for a prover solution that meets the given proof target, using `num_threads` threads.
+    ///
+    /// Thread `i` attempts the nonces `starting_nonce + i`, `starting_nonce + i + num_threads`, and so on.
+    /// The proof is only computed for a nonce whose commitment meets the proof target. The search stops
+    /// when a solution is found, when it is cancelled, or when an attempt fails.
+    pub fn search(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        proof_target: u64,
+        starting_nonce: u64,
+        num_threads: usize,
+        cancellation: &SearchCancellation,
+    ) -> Result<SearchResult<N>> {
+        ensure!(num_threads > 0, "The solution search requires at least one thread");
+        // Retrieve the coinbase proving key.
+        let pk = self.coinbase_proving_key()?;
+
+        let timer = Instant::now();
+        let num_attempts = AtomicU64::new(0);
+        let done = AtomicBool::new(false);
+        let outcome = Mutex::new(None::<Result<ProverSolution<N>>>);
+
+        std::thread::scope(|scope| {
+            for thread_index in 0..num_threads {
+                let (num_attempts, done, outcome) = (&num_attempts, &done, &outcome);
+                scope.spawn(move || {
+                    let mut nonce = starting_nonce.wrapping_add(thread_index as u64);
+                    while !done.load(Ordering::SeqCst) && !cancellation.is_cancelled() {
+                        let attempt = Self::prover_commitment(pk, epoch_challenge, address, nonce).and_then(
+                            |(polynomial, product_evaluations, partial_solution)| {
+                                // Only open the commitment if the proof target is met.
+                                match partial_solution.to_target()? >= proof_target {
+                                    true => Self::prover_opening(
+                                        pk,
+                                        epoch_challenge,
+                                        &polynomial,
+                                        product_evaluations,
+                                        partial_solution,
+                                    )
+                                    .map(Some),
+                                    false => Ok(None),
+                                }
+                            },
+                        );
+                        num_attempts.fetch_add(1, Ordering::SeqCst);
+
+                        // Record the first solution or error, and stop the other threads.
+                        if let Some(attempt) = attempt.transpose() {
+                            if !done.swap(true, Ordering::SeqCst) {
+                                *outcome.lock().unwrap_or_else(|error| error.into_inner()) = Some(attempt);
+                            }
+                            break;
+                        }
+                        nonce = nonce.wrapping_add(num_threads as u64);
+                    }
+                });
+            }
+        });
+
+        let solution =
+            outcome.into_inner().map_err(|_| anyhow!("The solution search lock was poisoned"))?.transpose()?;
+
+        Ok(SearchResult { solution, num_attempts: num_attempts.into_inner(), elapsed: timer.elapsed() })
+    }
+}
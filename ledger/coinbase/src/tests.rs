@@ -106,3 +106,62 @@ fn test_edge_case_for_degree() {
     let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &[prover_solution]).unwrap();
     assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
 }
+
+#[test]
+fn test_search() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 7) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+    let starting_nonce = u64::rand(&mut rng);
+
+    // Compute the highest proof target among the first few nonces, so the search is guaranteed to terminate.
+    let proof_target = (0..8)
+        .map(|i| puzzle.prove(&epoch_challenge, address, starting_nonce + i, None).unwrap().to_target().unwrap())
+        .max()
+        .unwrap();
+
+    for num_threads in [1, 4] {
+        let result = puzzle
+            .search(&epoch_challenge, address, proof_target, starting_nonce, num_threads, &SearchCancellation::new())
+            .unwrap();
+        let solution = result.solution.unwrap();
+        assert!(solution.to_target().unwrap() >= proof_target);
+        assert!(result.num_attempts > 0);
+        assert!(result.hashes_per_second() >= 0.0);
+
+        // Ensure the solution is valid.
+        let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &[solution]).unwrap();
+        assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+    }
+
+    // Ensure a cancelled search returns without a solution.
+    let cancellation = SearchCancellation::new();
+    cancellation.cancel();
+    let result = puzzle.search(&epoch_challenge, address, u64::MAX, starting_nonce, 4, &cancellation).unwrap();
+    assert!(result.solution.is_none());
+    assert_eq!(result.num_attempts, 0);
+
+    // Ensure a running search stops once it is cancelled.
+    let cancellation = SearchCancellation::new();
+    let result = std::thread::scope(|scope| {
+        let handle =
+            scope.spawn(|| puzzle.search(&epoch_challenge, address, u64::MAX, starting_nonce, 2, &cancellation));
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        cancellation.cancel();
+        handle.join().unwrap().unwrap()
+    });
+    assert!(result.solution.is_none());
+
+    // Ensure a verifier cannot search.
+    let verifier = CoinbasePuzzle::<Testnet3>::Verifier(Arc::new(puzzle.coinbase_verifying_key().clone()));
+    assert!(verifier.search(&epoch_challenge, address, 0, starting_nonce, 1, &SearchCancellation::new()).is_err());
+}
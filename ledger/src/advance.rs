@@ -15,6 +15,107 @@
 use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns a block template chosen from the given candidate transactions.
+    ///
+    /// Valid candidates are ordered by fee per byte, with ties broken by the higher fee and then by
+    /// transaction ID, and included greedily while they stay within the given limits and do not
+    /// conflict with an included transaction. The chosen transactions are meant to be passed to
+    /// `prepare_advance_to_next_block`.
+    pub fn prepare_block_template(
+        &self,
+        candidate_transactions: Vec<Transaction<N>>,
+        limits: &BlockLimits,
+    ) -> BlockTemplate<N> {
+        // Check each candidate, and compute its fee, size in bytes, and cost.
+        let checked = cfg_into_iter!(candidate_transactions)
+            .map(|transaction| {
+                let result = self.check_transaction_basic(&transaction, None).and_then(|_| self.weigh(&transaction));
+                (transaction, result)
+            })
+            .collect::<Vec<_>>();
+
+        // Separate the valid candidates from the invalid ones.
+        let mut excluded = Vec::new();
+        let mut candidates = Vec::with_capacity(checked.len());
+        for (transaction, result) in checked {
+            match result {
+                Ok((fee, size_in_bytes, cost)) => candidates.push((transaction, fee, size_in_bytes, cost)),
+                Err(error) => excluded.push((transaction.id(), ExclusionReason::Invalid(error.to_string()))),
+            }
+        }
+
+        // Order the candidates by fee per byte, in descending order.
+        candidates.sort_by(|(a, a_fee, a_size, _), (b, b_fee, b_size, _)| {
+            (*b_fee as u128 * *a_size as u128)
+                .cmp(&(*a_fee as u128 * *b_size as u128))
+                .then(b_fee.cmp(a_fee))
+                .then((*a.id()).cmp(&*b.id()))
+        });
+
+        // Select the candidates that fit within the limits.
+        let mut tracker = ConflictTracker::new();
+        let mut transactions = Vec::new();
+        let mut total_size_in_bytes = 0u64;
+        let mut total_cost = 0u64;
+        let mut public_fees = IndexMap::<Address<N>, u64>::new();
+        for (transaction, fee, size_in_bytes, cost) in candidates {
+            // Retrieve the payer of the public fee, if the fee is public.
+            let payer = transaction.fee_transition().and_then(|fee| fee.payer());
+            let reason = if transactions.len() >= limits.max_transactions {
+                ExclusionReason::TransactionLimit
+            } else if total_size_in_bytes.saturating_add(size_in_bytes) > limits.max_size_in_bytes {
+                ExclusionReason::SizeLimit
+            } else if total_cost.saturating_add(cost) > limits.max_cost {
+                ExclusionReason::CostLimit
+            } else if let Some(reason) = payer.and_then(|payer| self.check_public_fee(&payer, fee, &public_fees)) {
+                reason
+            } else {
+                // Ensure the transaction does not conflict with an included transaction.
+                match tracker.insert(&transaction) {
+                    Ok(()) => {
+                        total_size_in_bytes += size_in_bytes;
+                        total_cost += cost;
+                        if let Some(payer) = payer {
+                            *public_fees.entry(payer).or_default() += fee;
+                        }
+                        transactions.push(transaction);
+                        continue;
+                    }
+                    Err(conflict) => ExclusionReason::Conflict(conflict),
+                }
+            };
+            excluded.push((transaction.id(), reason));
+        }
+
+        BlockTemplate { transactions, excluded }
+    }
+
+    /// Returns the reason to exclude a public fee, if the public balance of the payer cannot cover
+    /// the fee on top of the public fees of the payer that are already included.
+    fn check_public_fee(
+        &self,
+        payer: &Address<N>,
+        fee: u64,
+        public_fees: &IndexMap<Address<N>, u64>,
+    ) -> Option<ExclusionReason<N>> {
+        let pending = public_fees.get(payer).copied().unwrap_or_default();
+        match self.get_public_balance(payer) {
+            Ok(balance) if balance >= pending.saturating_add(fee) => None,
+            Ok(_) => Some(ExclusionReason::InsufficientPublicBalance),
+            Err(error) => Some(ExclusionReason::Invalid(error.to_string())),
+        }
+    }
+
+    /// Returns the fee, size in bytes, and cost of the given transaction.
+    /// Note: The fee and cost are the ones the transaction is checked against in block validation.
+    fn weigh(&self, transaction: &Transaction<N>) -> Result<(u64, u64, u64)> {
+        // Retrieve the fee and the minimum cost.
+        let (fee, cost) = self.transaction_fee_and_cost(transaction)?;
+        // Compute the size in bytes.
+        let size_in_bytes = u64::try_from(transaction.to_bytes_le()?.len())?;
+        Ok((fee, size_in_bytes, cost))
+    }
+
     /// Returns a candidate for the next block in the ledger.
     pub fn prepare_advance_to_next_block<R: Rng + CryptoRng>(
         &self,
@@ -59,10 +160,14 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Compute the next cumulative weight.
         let next_cumulative_weight = latest_cumulative_weight.saturating_add(cumulative_proof_target);
 
+        // Checkpoint the timestamp for the next block.
+        let next_timestamp = OffsetDateTime::now_utc().unix_timestamp();
+
         // Construct the finalize state.
         let state = FinalizeGlobalState::new::<N>(
             next_round,
             next_height,
+            next_timestamp,
             next_cumulative_weight,
             cumulative_proof_target,
             latest_block.hash(),
@@ -73,9 +178,6 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Compute the next total supply in microcredits.
         let next_total_supply_in_microcredits = update_total_supply(latest_total_supply, &transactions)?;
 
-        // Checkpoint the timestamp for the next block.
-        let next_timestamp = OffsetDateTime::now_utc().unix_timestamp();
-
         // TODO (raychu86): Pay the provers.
         let (proving_rewards, staking_rewards) = match candidate_solutions {
             Some(prover_solutions) => {
@@ -175,6 +277,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Drop the write lock on the current block.
         drop(current_block);
 
+        // Evict the transactions in the block from the verified transactions cache.
+        let mut verified_transactions = self.verified_transactions.write();
+        block.transaction_ids().for_each(|transaction_id| verified_transactions.remove(transaction_id));
+        drop(verified_transactions);
+
         // If the block is the start of a new epoch, or the epoch challenge has not been set, update the current epoch challenge.
         if block.height() % N::NUM_BLOCKS_PER_EPOCH == 0 || self.current_epoch_challenge.read().is_none() {
             // Update the current epoch challenge.
@@ -15,6 +15,22 @@
 use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the fee and the minimum cost of the given transaction, in microcredits.
+    pub(crate) fn transaction_fee_and_cost(&self, transaction: &Transaction<N>) -> Result<(u64, u64)> {
+        // Retrieve the transaction fee.
+        let fee = *transaction.fee()?;
+        // Retrieve the minimum cost of the transaction.
+        let (cost, _) = match transaction {
+            // Compute the deployment cost.
+            Transaction::Deploy(_, _, deployment, _) => synthesizer::deployment_cost(deployment)?,
+            // Compute the execution cost.
+            Transaction::Execute(_, execution, _) => synthesizer::execution_cost(self.vm(), execution)?,
+            // TODO (howardwu): Plug in the Rejected struct, to compute the cost.
+            Transaction::Fee(_, _) => (0, (0, 0)),
+        };
+        Ok((fee, cost))
+    }
+
     /// Checks the given transaction is well-formed and unique.
     pub fn check_transaction_basic(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
         let transaction_id = transaction.id();
@@ -57,27 +73,36 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         };
 
         if !can_skip_fee {
-            // Retrieve the transaction fee.
-            let fee = *transaction.fee()?;
-            // Retrieve the minimum cost of the transaction.
-            let (cost, _) = match transaction {
-                // Compute the deployment cost.
-                Transaction::Deploy(_, _, deployment, _) => synthesizer::deployment_cost(deployment)?,
-                // Compute the execution cost.
-                Transaction::Execute(_, execution, _) => synthesizer::execution_cost(self.vm(), execution)?,
-                // TODO (howardwu): Plug in the Rejected struct, to compute the cost.
-                Transaction::Fee(_, _) => (0, (0, 0)),
-            };
+            // Retrieve the transaction fee and the minimum cost of the transaction.
+            let (fee, cost) = self.transaction_fee_and_cost(transaction)?;
             // Ensure the transaction has a sufficient fee.
             if cost > fee {
                 bail!("Transaction '{transaction_id}' has an insufficient fee - expected at least {cost} microcredits")
             }
+            // If the fee is public, ensure the payer has a sufficient balance in the `account` mapping.
+            if let Some(payer) = transaction.fee_transition().and_then(|fee| fee.payer()) {
+                // Ensure the public balance of the payer covers the fee.
+                if self.get_public_balance(&payer)? < fee {
+                    bail!("Transaction '{transaction_id}' has a public fee that exceeds the balance of '{payer}'")
+                }
+            }
         }
 
         /* Proof */
 
-        // Ensure the transaction is valid.
-        self.vm().check_transaction(transaction, rejected_id)?;
+        // If the transaction was already verified, only ensure the state roots it is anchored to still exist.
+        if self.verified_transactions.read().contains(transaction, rejected_id) {
+            for state_root in anchored_state_roots(transaction) {
+                if !self.contains_state_root(&state_root)? {
+                    bail!("Transaction '{transaction_id}' is anchored to an unknown state root '{state_root}'")
+                }
+            }
+        } else {
+            // Ensure the transaction is valid.
+            self.vm().check_transaction(transaction, rejected_id)?;
+            // Cache the verified transaction.
+            self.verified_transactions.write().insert(transaction.clone(), rejected_id);
+        }
 
         /* Program */
 
@@ -412,10 +437,27 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
                 .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
         })?;
 
+        // Ensure the public fees of each payer do not exceed the public balance of the payer.
+        let mut public_fees = IndexMap::<Address<N>, u64>::new();
+        for transaction in block.transactions().iter() {
+            if let Some(fee) = transaction.fee_transition() {
+                if let Some(payer) = fee.payer() {
+                    let total = public_fees.entry(payer).or_default();
+                    *total = total.checked_add(*fee.amount()?).ok_or(anyhow!("Public fee overflow for '{payer}'"))?;
+                }
+            }
+        }
+        for (payer, total) in public_fees {
+            if self.get_public_balance(&payer)? < total {
+                bail!("The public fees of '{payer}' in the block exceed the public balance of the payer")
+            }
+        }
+
         // Construct the finalize state.
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
@@ -427,7 +469,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             .iter()
             .map(|tx| match tx {
                 // Pass through the accepted deployment and execution transactions.
-                ConfirmedTransaction::AcceptedDeploy(_, tx, _) | ConfirmedTransaction::AcceptedExecute(_, tx, _) => {
+                ConfirmedTransaction::AcceptedDeploy(_, tx, _) | ConfirmedTransaction::AcceptedExecute(_, tx, ..) => {
                     Ok(tx.clone())
                 }
                 // Reconstruct the unconfirmed deployment transaction.
@@ -513,4 +555,17 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         Ok(())
     }
+
+    /// Returns the confirmed public balance of the given address, in the `credits.aleo/account` mapping.
+    pub fn get_public_balance(&self, address: &Address<N>) -> Result<u64> {
+        match self.vm().finalize_store().get_value_confirmed(
+            &ProgramID::from_str("credits.aleo")?,
+            &Identifier::from_str("account")?,
+            &Plaintext::from(Literal::Address(*address)),
+        )? {
+            Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => Ok(*balance),
+            Some(..) => bail!("Invalid public balance for '{address}'"),
+            None => Ok(0),
+        }
+    }
 }
@@ -164,6 +164,18 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
     }
 
+    /// Returns the events with the given program ID and event name, in the order they were emitted.
+    pub fn get_events(&self, program_id: ProgramID<N>, name: Identifier<N>) -> Result<Vec<Event<N>>> {
+        self.vm.block_store().get_events(&program_id, &name)
+    }
+
+    /// Returns the finalize diffs for the given confirmed transaction ID, in the order they were applied.
+    /// Note: The finalize diffs are only recorded when the `history` feature is enabled.
+    #[cfg(feature = "history")]
+    pub fn get_finalize_diffs(&self, transaction_id: N::TransactionID) -> Result<Vec<FinalizeDiff<N>>> {
+        self.vm.get_finalize_diffs(&transaction_id)
+    }
+
     /// Returns the block coinbase solution for the given block height.
     pub fn get_coinbase(&self, height: u32) -> Result<Option<CoinbaseSolution<N>>> {
         // If the height is 0, return the genesis block coinbase.
@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::ProgramID,
+    types::{Field, Group},
+};
+use ledger_block::Transaction;
+
+use indexmap::IndexMap;
+
+/// An element of a transaction that must be unique across a block.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConflictKind<N: Network> {
+    /// The transaction ID.
+    TransactionID(N::TransactionID),
+    /// A transition ID.
+    TransitionID(N::TransitionID),
+    /// An input ID.
+    InputID(Field<N>),
+    /// A serial number.
+    SerialNumber(Field<N>),
+    /// A tag.
+    Tag(Field<N>),
+    /// An output ID.
+    OutputID(Field<N>),
+    /// A commitment.
+    Commitment(Field<N>),
+    /// A nonce.
+    Nonce(Group<N>),
+    /// A transition public key.
+    TransitionPublicKey(Group<N>),
+    /// A transition commitment.
+    TransitionCommitment(Field<N>),
+    /// A deployed program ID.
+    Deployment(ProgramID<N>),
+}
+
+impl<N: Network> Display for ConflictKind<N> {
+    /// Prints the conflicting element.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::TransactionID(id) => write!(f, "transaction ID '{id}'"),
+            Self::TransitionID(id) => write!(f, "transition ID '{id}'"),
+            Self::InputID(id) => write!(f, "input ID '{id}'"),
+            Self::SerialNumber(serial_number) => write!(f, "serial number '{serial_number}'"),
+            Self::Tag(tag) => write!(f, "tag '{tag}'"),
+            Self::OutputID(id) => write!(f, "output ID '{id}'"),
+            Self::Commitment(commitment) => write!(f, "commitment '{commitment}'"),
+            Self::Nonce(nonce) => write!(f, "nonce '{nonce}'"),
+            Self::TransitionPublicKey(tpk) => write!(f, "transition public key '{tpk}'"),
+            Self::TransitionCommitment(tcm) => write!(f, "transition commitment '{tcm}'"),
+            Self::Deployment(program_id) => write!(f, "deployment of '{program_id}'"),
+        }
+    }
+}
+
+/// A conflict between a pending transaction and an earlier pending transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Conflict<N: Network> {
+    /// The ID of the conflicting transaction.
+    pub transaction_id: N::TransactionID,
+    /// The ID of the earlier transaction it conflicts with.
+    pub existing_id: N::TransactionID,
+    /// The element both transactions share.
+    pub kind: ConflictKind<N>,
+}
+
+impl<N: Network> Display for Conflict<N> {
+    /// Prints the conflict.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Transaction '{}' conflicts with '{}' on its {}", self.transaction_id, self.existing_id, self.kind)
+    }
+}
+
+/// Returns the elements of the given transaction that must be unique across a block.
+pub fn conflict_kinds<N: Network>(transaction: &Transaction<N>) -> Vec<ConflictKind<N>> {
+    let mut kinds = vec![ConflictKind::TransactionID(transaction.id())];
+    kinds.extend(transaction.transition_ids().map(|id| ConflictKind::TransitionID(*id)));
+    kinds.extend(transaction.input_ids().map(|id| ConflictKind::InputID(*id)));
+    kinds.extend(transaction.serial_numbers().map(|serial_number| ConflictKind::SerialNumber(*serial_number)));
+    kinds.extend(transaction.tags().map(|tag| ConflictKind::Tag(*tag)));
+    kinds.extend(transaction.output_ids().map(|id| ConflictKind::OutputID(*id)));
+    kinds.extend(transaction.commitments().map(|commitment| ConflictKind::Commitment(*commitment)));
+    kinds.extend(transaction.nonces().map(|nonce| ConflictKind::Nonce(*nonce)));
+    kinds.extend(transaction.transition_public_keys().map(|tpk| ConflictKind::TransitionPublicKey(*tpk)));
+    kinds.extend(transaction.transition_commitments().map(|tcm| ConflictKind::TransitionCommitment(*tcm)));
+    if let Some(deployment) = transaction.deployment() {
+        kinds.push(ConflictKind::Deployment(*deployment.program_id()));
+    }
+    kinds
+}
+
+/// A set of pending transactions that are free of conflicts with each other.
+#[derive(Clone)]
+pub struct ConflictTracker<N: Network> {
+    /// The transaction that claimed each element.
+    claimed: IndexMap<ConflictKind<N>, N::TransactionID>,
+}
+
+impl<N: Network> ConflictTracker<N> {
+    /// Initializes a new, empty tracker.
+    pub fn new() -> Self {
+        Self { claimed: IndexMap::new() }
+    }
+
+    /// Returns the first conflict between the given transaction and the tracked transactions, if any.
+    pub fn find(&self, transaction: &Transaction<N>) -> Option<Conflict<N>> {
+        conflict_kinds(transaction).into_iter().find_map(|kind| {
+            self.claimed.get(&kind).map(|existing_id| Conflict {
+                transaction_id: transaction.id(),
+                existing_id: *existing_id,
+                kind,
+            })
+        })
+    }
+
+    /// Tracks the given transaction, returning the conflict instead if it conflicts with a tracked transaction.
+    pub fn insert(&mut self, transaction: &Transaction<N>) -> Result<(), Conflict<N>> {
+        match self.find(transaction) {
+            Some(conflict) => Err(conflict),
+            None => {
+                self.claimed.extend(conflict_kinds(transaction).into_iter().map(|kind| (kind, transaction.id())));
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<N: Network> Default for ConflictTracker<N> {
+    /// Initializes a new, empty tracker.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the conflicts among the given pending transactions.
+///
+/// Transactions are considered in the given order, so the first transaction to use an element
+/// keeps it, and every later transaction that reuses it is reported as a conflict. A conflicting
+/// transaction does not claim any of its elements, so it never causes further conflicts.
+pub fn find_conflicts<'a, N: Network>(transactions: impl IntoIterator<Item = &'a Transaction<N>>) -> Vec<Conflict<N>> {
+    let mut tracker = ConflictTracker::new();
+    transactions.into_iter().filter_map(|transaction| tracker.insert(transaction).err()).collect()
+}
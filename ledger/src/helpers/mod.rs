@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod conflicts;
+pub use conflicts::*;
+
 mod rewards;
 pub use rewards::*;
 
@@ -20,3 +23,9 @@ pub use supply::*;
 
 mod target;
 pub use target::*;
+
+mod template;
+pub use template::*;
+
+mod verified;
+pub use verified::*;
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Conflict;
+use console::network::prelude::*;
+use ledger_block::{Transaction, Transactions};
+
+/// The limits a block template must stay within.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BlockLimits {
+    /// The maximum number of transactions.
+    pub max_transactions: usize,
+    /// The maximum cumulative size of the transactions, in bytes.
+    pub max_size_in_bytes: u64,
+    /// The maximum cumulative cost of the transactions, in microcredits.
+    pub max_cost: u64,
+}
+
+impl BlockLimits {
+    /// The default maximum cumulative size of the transactions, in bytes.
+    pub const MAX_SIZE_IN_BYTES: u64 = 16 * 1024 * 1024;
+    /// The default maximum cumulative cost of the transactions, in microcredits.
+    pub const MAX_COST: u64 = 1_000_000_000;
+
+    /// Returns the default limits for the given network.
+    pub fn new<N: Network>() -> Self {
+        Self {
+            max_transactions: Transactions::<N>::MAX_TRANSACTIONS,
+            max_size_in_bytes: Self::MAX_SIZE_IN_BYTES,
+            max_cost: Self::MAX_COST,
+        }
+    }
+}
+
+/// The reason a candidate transaction was excluded from a block template.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExclusionReason<N: Network> {
+    /// The transaction failed the basic checks.
+    Invalid(String),
+    /// The transaction conflicts with a transaction that pays a higher fee per byte.
+    Conflict(Conflict<N>),
+    /// The block already contains the maximum number of transactions.
+    TransactionLimit,
+    /// The transaction does not fit within the remaining size limit.
+    SizeLimit,
+    /// The transaction does not fit within the remaining cost limit.
+    CostLimit,
+    /// The public balance of the payer cannot cover the public fee, with the public fees of the payer already included.
+    InsufficientPublicBalance,
+}
+
+impl<N: Network> Display for ExclusionReason<N> {
+    /// Prints the exclusion reason.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Invalid(error) => write!(f, "Invalid transaction: {error}"),
+            Self::Conflict(conflict) => write!(f, "{conflict}"),
+            Self::TransactionLimit => write!(f, "The block transaction limit is reached"),
+            Self::SizeLimit => write!(f, "The transaction exceeds the remaining block size"),
+            Self::CostLimit => write!(f, "The transaction exceeds the remaining block cost"),
+            Self::InsufficientPublicBalance => write!(f, "The public fee exceeds the remaining balance of the payer"),
+        }
+    }
+}
+
+/// The transactions chosen for the next block, and the reasons the remaining candidates were excluded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockTemplate<N: Network> {
+    /// The chosen transactions, ordered by fee per byte.
+    pub transactions: Vec<Transaction<N>>,
+    /// The excluded transaction IDs, in the order they were considered, with the reason for each.
+    pub excluded: Vec<(N::TransactionID, ExclusionReason<N>)>,
+}
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, types::Field};
+use ledger_block::Transaction;
+
+use indexmap::IndexMap;
+
+/// A bounded cache of transactions whose proofs have already been verified.
+///
+/// Each entry records the exact transaction and the rejected ID it was verified with. A lookup
+/// only succeeds if both match, as the transaction ID does not commit to the proofs.
+#[derive(Clone)]
+pub struct VerifiedTransactions<N: Network> {
+    /// The verified transactions, in insertion order.
+    transactions: IndexMap<N::TransactionID, (Transaction<N>, Option<Field<N>>)>,
+}
+
+impl<N: Network> VerifiedTransactions<N> {
+    /// The maximum number of transactions held in the cache.
+    pub const MAX_TRANSACTIONS: usize = 4096;
+
+    /// Initializes a new, empty cache.
+    pub fn new() -> Self {
+        Self { transactions: IndexMap::new() }
+    }
+
+    /// Returns `true` if the given transaction was verified with the given rejected ID.
+    pub fn contains(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> bool {
+        match self.transactions.get(&transaction.id()) {
+            Some((candidate, candidate_rejected_id)) => {
+                candidate == transaction && *candidate_rejected_id == rejected_id
+            }
+            None => false,
+        }
+    }
+
+    /// Inserts the given verified transaction, evicting the oldest entry if the cache is full.
+    pub fn insert(&mut self, transaction: Transaction<N>, rejected_id: Option<Field<N>>) {
+        // If the cache is full, evict the oldest entry.
+        if self.transactions.len() >= Self::MAX_TRANSACTIONS && !self.transactions.contains_key(&transaction.id()) {
+            self.transactions.shift_remove_index(0);
+        }
+        self.transactions.insert(transaction.id(), (transaction, rejected_id));
+    }
+
+    /// Removes the given transaction ID from the cache.
+    pub fn remove(&mut self, transaction_id: &N::TransactionID) {
+        self.transactions.shift_remove(transaction_id);
+    }
+
+    /// Removes all transactions from the cache.
+    pub fn clear(&mut self) {
+        self.transactions.clear();
+    }
+
+    /// Returns the number of transactions in the cache.
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+}
+
+impl<N: Network> Default for VerifiedTransactions<N> {
+    /// Initializes a new, empty cache.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the global state roots that the given transaction is anchored to.
+pub fn anchored_state_roots<N: Network>(transaction: &Transaction<N>) -> Vec<N::StateRoot> {
+    let mut state_roots = Vec::with_capacity(2);
+    if let Some(execution) = transaction.execution() {
+        state_roots.push(execution.global_state_root());
+    }
+    if let Some(fee) = transaction.fee_transition() {
+        state_roots.push(fee.global_state_root());
+    }
+    state_roots
+}
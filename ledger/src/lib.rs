@@ -60,7 +60,7 @@ use ledger_coinbase::{CoinbasePuzzle, CoinbaseSolution, EpochChallenge, ProverSo
 use ledger_query::Query;
 use ledger_store::{ConsensusStorage, ConsensusStore};
 use synthesizer::{
-    program::{FinalizeGlobalState, Program},
+    program::{Event, FinalizeGlobalState, Program},
     vm::VM,
 };
 
@@ -73,6 +73,8 @@ use rand::{prelude::IteratorRandom, rngs::OsRng};
 use std::{borrow::Cow, sync::Arc};
 use time::OffsetDateTime;
 
+#[cfg(feature = "history")]
+use synthesizer::program::FinalizeDiff;
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
@@ -106,6 +108,8 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     current_epoch_challenge: Arc<RwLock<Option<EpochChallenge<N>>>>,
     /// The current committee.
     current_committee: Arc<RwLock<IndexSet<Address<N>>>>,
+    /// The transactions whose proofs have already been verified.
+    verified_transactions: Arc<RwLock<VerifiedTransactions<N>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
@@ -164,6 +168,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             current_block: Arc::new(RwLock::new(genesis.clone())),
             current_epoch_challenge: Default::default(),
             current_committee: Default::default(),
+            verified_transactions: Default::default(),
         };
 
         // Add the genesis validator to the committee.
@@ -17,14 +17,17 @@ use crate::{
     RecordsFilter,
 };
 use console::{
-    account::PrivateKey,
+    account::{Address, PrivateKey},
     network::prelude::*,
-    program::{Entry, Identifier, Literal, Plaintext, Value},
+    program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
 };
 use ledger_block::{ConfirmedTransaction, Rejected, Transaction};
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
 use synthesizer::{program::Program, vm::VM};
 
+#[cfg(feature = "history")]
+use synthesizer::program::FinalizeDiff;
+
 #[test]
 fn test_load() {
     let rng = &mut TestRng::default();
@@ -258,7 +261,7 @@ finalize failed_assert:
     if let Transaction::Execute(_, execution, fee) = failed_assert_transaction {
         let fee_transaction = Transaction::from_fee(fee.unwrap()).unwrap();
         let expected_confirmed_transaction =
-            ConfirmedTransaction::RejectedExecute(0, fee_transaction, Rejected::new_execution(execution));
+            ConfirmedTransaction::RejectedExecute(0, fee_transaction, Rejected::new_execution(execution, None));
 
         assert_eq!(confirmed_transaction, &expected_confirmed_transaction);
     }
@@ -269,3 +272,350 @@ finalize failed_assert:
     // Add the block with the rejected transaction to the ledger.
     ledger.advance_to_next_block(&next_block).unwrap();
 }
+
+#[test]
+fn test_finalize_events() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Deploy a test program to the ledger.
+    let program_id = "test_finalize_events.aleo";
+    // Note: The `flood` function emits one more event than a transaction may emit.
+    let flood = (0..=CurrentNetwork::MAX_EVENTS).map(|i| format!("\n    emit flooded {i}u64;")).collect::<String>();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+
+function ping:
+    input r0 as u64.public;
+    finalize self.caller r0;
+
+finalize ping:
+    input r0 as address.public;
+    input r1 as u64.public;
+    emit pinged r0 r1;
+    emit pinged r0 1u64;
+
+function flood:
+    finalize;
+
+finalize flood:{flood}"
+    ))
+    .unwrap();
+
+    // A helper function to find records.
+    let find_records = || {
+        let microcredits = Identifier::from_str("microcredits").unwrap();
+        ledger
+            .find_records(&view_key, RecordsFilter::SlowUnspent(private_key))
+            .unwrap()
+            .filter(|(_, record)| match record.data().get(&microcredits) {
+                Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => !amount.is_zero(),
+                _ => false,
+            })
+            .collect::<indexmap::IndexMap<_, _>>()
+    };
+
+    // Fetch the unspent records.
+    let records = find_records();
+    let record_1 = records[0].clone();
+    let record_2 = records[1].clone();
+
+    // Deploy the program.
+    let deployment_transaction = ledger.vm().deploy(&private_key, &program, (record_1, 0), None, rng).unwrap();
+    let deployment_block =
+        ledger.prepare_advance_to_next_block(&private_key, vec![deployment_transaction], None, rng).unwrap();
+    ledger.check_next_block(&deployment_block).unwrap();
+    ledger.advance_to_next_block(&deployment_block).unwrap();
+
+    // Execute the function, which emits two events.
+    let transaction = ledger
+        .vm()
+        .execute(
+            &private_key,
+            (program_id, "ping"),
+            [Value::<CurrentNetwork>::from_str("5u64").unwrap()].into_iter(),
+            Some((record_2, 0)),
+            None,
+            rng,
+        )
+        .unwrap();
+    let next_block = ledger.prepare_advance_to_next_block(&private_key, vec![transaction], None, rng).unwrap();
+
+    // Check that the block contains the emitted events, and that they are committed to in the finalize root.
+    let confirmed_transaction = next_block.transactions().iter().next().unwrap();
+    assert!(confirmed_transaction.is_accepted());
+    assert_eq!(confirmed_transaction.events().len(), 2);
+    for event in confirmed_transaction.events() {
+        let path = next_block.transactions().to_event_path(event).unwrap();
+        let leaf = event.to_id().unwrap().to_bits_le();
+        assert!(CurrentNetwork::verify_merkle_path_bhp(&path, &next_block.finalize_root(), &leaf));
+    }
+
+    // Add the block to the ledger.
+    ledger.check_next_block(&next_block).unwrap();
+    ledger.advance_to_next_block(&next_block).unwrap();
+
+    // Query the events by program ID and event name.
+    let address = Address::try_from(&private_key).unwrap();
+    let events =
+        ledger.get_events(ProgramID::from_str(program_id).unwrap(), Identifier::from_str("pinged").unwrap()).unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].values(), &[Plaintext::from(Literal::Address(address)), Plaintext::from_str("5u64").unwrap()]);
+    assert_eq!(events[1].values(), &[Plaintext::from(Literal::Address(address)), Plaintext::from_str("1u64").unwrap()]);
+
+    // Ensure an unknown event name returns no events.
+    let unknown = Identifier::from_str("unknown").unwrap();
+    assert!(ledger.get_events(ProgramID::from_str(program_id).unwrap(), unknown).unwrap().is_empty());
+
+    // Execute the function that emits too many events.
+    let records = find_records();
+    let transaction = ledger
+        .vm()
+        .execute(
+            &private_key,
+            (program_id, "flood"),
+            Vec::<Value<_>>::new().into_iter(),
+            Some((records[0].clone(), 0)),
+            None,
+            rng,
+        )
+        .unwrap();
+    let next_block = ledger.prepare_advance_to_next_block(&private_key, vec![transaction], None, rng).unwrap();
+
+    // Ensure the transaction is rejected, instead of aborting the block.
+    assert_eq!(next_block.transactions().len(), 1);
+    assert!(next_block.transactions().iter().next().unwrap().is_rejected());
+    ledger.check_next_block(&next_block).unwrap();
+    ledger.advance_to_next_block(&next_block).unwrap();
+    let flooded = Identifier::from_str("flooded").unwrap();
+    assert!(ledger.get_events(ProgramID::from_str(program_id).unwrap(), flooded).unwrap().is_empty());
+}
+
+#[test]
+#[cfg(feature = "history")]
+fn test_finalize_diffs() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // Deploy a test program to the ledger.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("test_finalize_diffs.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+
+mapping counter:
+    key owner as address.public;
+    value count as u64.public;
+
+function bump:
+    input r0 as u64.public;
+    finalize self.caller r0;
+
+finalize bump:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use counter[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into counter[r0];
+    add r3 1u64 into r4;
+    set r4 into counter[r0];
+
+function clear:
+    input r0 as boolean.public;
+    finalize self.caller;
+
+finalize clear:
+    input r0 as address.public;
+    remove counter[r0];"
+    ))
+    .unwrap();
+
+    // A helper function to find records.
+    let find_records = || {
+        let microcredits = Identifier::from_str("microcredits").unwrap();
+        ledger
+            .find_records(&view_key, RecordsFilter::SlowUnspent(private_key))
+            .unwrap()
+            .filter(|(_, record)| match record.data().get(&microcredits) {
+                Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => !amount.is_zero(),
+                _ => false,
+            })
+            .collect::<indexmap::IndexMap<_, _>>()
+    };
+    // A helper function to execute a function of the test program.
+    let execute = |function_name: &str, input: &str, fee_record, rng: &mut TestRng| {
+        ledger
+            .vm()
+            .execute(
+                &private_key,
+                (program_id, function_name),
+                [Value::<CurrentNetwork>::from_str(input).unwrap()].into_iter(),
+                Some((fee_record, 0)),
+                None,
+                rng,
+            )
+            .unwrap()
+    };
+    // A helper function to retrieve the finalize diffs of the test program, as `(old value, new value)` strings.
+    let get_diffs = |transaction_id| {
+        let key = Plaintext::from(Literal::Address(address));
+        ledger
+            .get_finalize_diffs(transaction_id)
+            .unwrap()
+            .into_iter()
+            .filter(|diff| diff.program_id() == &program_id)
+            .map(|diff| {
+                assert_eq!(diff.mapping_name(), &Identifier::from_str("counter").unwrap());
+                assert_eq!(diff.key(), &key);
+                (diff.old_value().map(ToString::to_string), diff.new_value().map(ToString::to_string))
+            })
+            .collect::<Vec<_>>()
+    };
+    let some = |value: &str| Some(value.to_string());
+
+    // Deploy the program.
+    let records = find_records();
+    let deployment_transaction =
+        ledger.vm().deploy(&private_key, &program, (records[0].clone(), 0), None, rng).unwrap();
+    let deployment_id = deployment_transaction.id();
+    let block = ledger.prepare_advance_to_next_block(&private_key, vec![deployment_transaction], None, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Execute the function, which sets the entry twice.
+    let transaction = execute("bump", "5u64", records[1].clone(), rng);
+    let transaction_id = transaction.id();
+    let block = ledger.prepare_advance_to_next_block(&private_key, vec![transaction], None, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Initializing the mapping does not change any entries.
+    assert!(get_diffs(deployment_id).is_empty());
+    // The first execution inserts the entry, and then updates it.
+    assert_eq!(get_diffs(transaction_id), vec![(None, some("5u64")), (some("5u64"), some("6u64"))]);
+
+    // Update and then remove the entry in the same block.
+    let records = find_records();
+    let bump_transaction = execute("bump", "1u64", records[0].clone(), rng);
+    let clear_transaction = execute("clear", "true", records[1].clone(), rng);
+    let (bump_id, clear_id) = (bump_transaction.id(), clear_transaction.id());
+    let transactions = vec![bump_transaction, clear_transaction];
+    let block = ledger.prepare_advance_to_next_block(&private_key, transactions, None, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the old values are resolved across transactions in the same block.
+    assert_eq!(get_diffs(bump_id), vec![(some("6u64"), some("7u64")), (some("7u64"), some("8u64"))]);
+    assert_eq!(get_diffs(clear_id), vec![(some("8u64"), None)]);
+
+    // Ensure the finalize diffs round-trip through JSON.
+    let diffs = ledger.get_finalize_diffs(bump_id).unwrap();
+    let candidate: Vec<FinalizeDiff<CurrentNetwork>> =
+        serde_json::from_str(&serde_json::to_string(&diffs).unwrap()).unwrap();
+    assert_eq!(diffs, candidate);
+
+    // Ensure an unknown transaction ID has no finalize diffs.
+    assert!(ledger.get_finalize_diffs(rng.gen()).is_err());
+}
+
+#[test]
+fn test_verified_transactions_cache() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Create a transfer transaction.
+    let transaction = ledger.create_transfer(&private_key, address, 100, 0, None).unwrap();
+    assert!(!ledger.verified_transactions.read().contains(&transaction, None));
+
+    // Check the transaction, and ensure it is cached.
+    ledger.check_transaction_basic(&transaction, None).unwrap();
+    assert!(ledger.verified_transactions.read().contains(&transaction, None));
+    // Ensure the cached entry does not apply to a different rejected ID.
+    assert!(!ledger.verified_transactions.read().contains(&transaction, Some(Uniform::rand(rng))));
+    // Ensure the transaction is still valid when checked from the cache.
+    ledger.check_transaction_basic(&transaction, None).unwrap();
+
+    // Construct the next block, and ensure it is valid.
+    let block = ledger.prepare_advance_to_next_block(&private_key, vec![transaction.clone()], None, rng).unwrap();
+    ledger.check_next_block(&block).unwrap();
+    // Advance to the next block.
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the transaction is evicted from the cache, and is no longer valid.
+    assert!(!ledger.verified_transactions.read().contains(&transaction, None));
+    assert!(ledger.check_transaction_basic(&transaction, None).is_err());
+}
+
+#[test]
+fn test_find_conflicts() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Create two transfer transactions that spend the same records.
+    let transaction_1 = ledger.create_transfer(&private_key, address, 100, 0, None).unwrap();
+    let transaction_2 = ledger.create_transfer(&private_key, address, 200, 0, None).unwrap();
+    assert!(transaction_1.serial_numbers().any(|serial_number| transaction_2.contains_serial_number(serial_number)));
+
+    // Ensure each transaction is conflict-free on its own.
+    assert!(crate::find_conflicts([&transaction_1]).is_empty());
+    assert!(crate::find_conflicts([&transaction_2]).is_empty());
+
+    // Ensure the later transaction conflicts with the earlier one.
+    let conflicts = crate::find_conflicts([&transaction_1, &transaction_2, &transaction_1]);
+    assert_eq!(conflicts.len(), 2);
+    assert_eq!(conflicts[0].transaction_id, transaction_2.id());
+    assert_eq!(conflicts[0].existing_id, transaction_1.id());
+    assert!(matches!(conflicts[0].kind, crate::ConflictKind::InputID(_) | crate::ConflictKind::SerialNumber(_)));
+    // Ensure a repeated transaction conflicts on its transaction ID.
+    assert_eq!(conflicts[1].transaction_id, transaction_1.id());
+    assert_eq!(conflicts[1].kind, crate::ConflictKind::TransactionID(transaction_1.id()));
+}
+
+#[test]
+fn test_prepare_block_template() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Create two transfer transactions that spend the same records, with different priority fees.
+    let low_fee_transaction = ledger.create_transfer(&private_key, address, 100, 0, None).unwrap();
+    let high_fee_transaction = ledger.create_transfer(&private_key, address, 100, 1_000, None).unwrap();
+
+    // Ensure the transaction with the higher fee is chosen, and the other is excluded as a conflict.
+    let limits = crate::BlockLimits::new::<CurrentNetwork>();
+    let template =
+        ledger.prepare_block_template(vec![low_fee_transaction.clone(), high_fee_transaction.clone()], &limits);
+    assert_eq!(template.transactions, vec![high_fee_transaction.clone()]);
+    assert_eq!(template.excluded.len(), 1);
+    assert_eq!(template.excluded[0].0, low_fee_transaction.id());
+    match &template.excluded[0].1 {
+        crate::ExclusionReason::Conflict(conflict) => assert_eq!(conflict.existing_id, high_fee_transaction.id()),
+        reason => panic!("Unexpected exclusion reason: {reason}"),
+    }
+
+    // Ensure the limits are enforced.
+    let limits = crate::BlockLimits { max_transactions: 0, ..limits };
+    let template = ledger.prepare_block_template(vec![high_fee_transaction.clone()], &limits);
+    assert!(template.transactions.is_empty());
+    assert_eq!(template.excluded, vec![(high_fee_transaction.id(), crate::ExclusionReason::TransactionLimit)]);
+    let limits = crate::BlockLimits { max_cost: 0, ..crate::BlockLimits::new::<CurrentNetwork>() };
+    let template = ledger.prepare_block_template(vec![high_fee_transaction.clone()], &limits);
+    assert!(template.transactions.is_empty());
+    assert_eq!(template.excluded, vec![(high_fee_transaction.id(), crate::ExclusionReason::CostLimit)]);
+
+    // Ensure the chosen transactions form a valid block.
+    let limits = crate::BlockLimits::new::<CurrentNetwork>();
+    let template = ledger.prepare_block_template(vec![low_fee_transaction, high_fee_transaction], &limits);
+    let block = ledger.prepare_advance_to_next_block(&private_key, template.transactions, None, rng).unwrap();
+    ledger.check_next_block(&block).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(block.transactions().len(), 1);
+}
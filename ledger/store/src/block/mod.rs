@@ -16,7 +16,7 @@ use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{Map, MapRead, MerkleNodeKey, PersistentMerkleTree},
     TransactionStorage,
     TransactionStore,
     TransitionStorage,
@@ -24,15 +24,26 @@ use crate::{
 };
 use console::{
     account::Signature,
+    algorithms::{BHP1024, BHP512},
     network::prelude::*,
-    program::{BlockTree, HeaderLeaf, ProgramID, StatePath},
+    program::{HeaderLeaf, Identifier, ProgramID, StatePath, BLOCKS_DEPTH},
     types::Field,
 };
-use ledger_block::{Block, ConfirmedTransaction, Header, NumFinalizeSize, Ratify, Transaction, Transactions};
+use ledger_block::{
+    Block,
+    ConfirmedTransaction,
+    Header,
+    NumEventsSize,
+    NumFinalizeSize,
+    Ratify,
+    Transaction,
+    Transactions,
+};
 use ledger_coinbase::{CoinbaseSolution, PuzzleCommitment};
-use synthesizer_program::Program;
+use synthesizer_program::{Event, Program};
 
 use anyhow::Result;
+use indexmap::IndexSet;
 use parking_lot::RwLock;
 use std::{borrow::Cow, io::Cursor, sync::Arc};
 
@@ -62,11 +73,21 @@ fn to_confirmed_tuple<N: Network>(
             // Return the confirmed tuple.
             Ok((ConfirmedTxType::AcceptedDeploy(index), tx, (num_finalize, finalize).to_bytes_le()?))
         }
-        ConfirmedTransaction::AcceptedExecute(index, tx, finalize) => {
+        ConfirmedTransaction::AcceptedExecute(index, tx, finalize, events) => {
             // Retrieve the number of finalize operations.
             let num_finalize = NumFinalizeSize::try_from(finalize.len())?;
+            // Prepare the confirmed blob.
+            let mut blob = (num_finalize, finalize).to_bytes_le()?;
+            // If there are events, append them to the blob.
+            // Note: A blob without events is unchanged from the format that predates events.
+            if !events.is_empty() {
+                // Retrieve the number of events.
+                let num_events = NumEventsSize::try_from(events.len())?;
+                // Append the events.
+                blob.extend((num_events, events).to_bytes_le()?);
+            }
             // Return the confirmed tuple.
-            Ok((ConfirmedTxType::AcceptedExecute(index), tx, (num_finalize, finalize).to_bytes_le()?))
+            Ok((ConfirmedTxType::AcceptedExecute(index), tx, blob))
         }
         ConfirmedTransaction::RejectedDeploy(index, tx, rejected) => {
             // Return the confirmed tuple.
@@ -79,6 +100,21 @@ fn to_confirmed_tuple<N: Network>(
     }
 }
 
+/// Returns the `((program ID, event name), transaction ID)` index entries for the events of the confirmed transaction.
+/// Note: A transaction that emits an event more than once has a single index entry for the event.
+fn to_event_keys<N: Network>(
+    confirmed: &ConfirmedTransaction<N>,
+) -> impl '_ + Iterator<Item = ((ProgramID<N>, Identifier<N>), N::TransactionID)> {
+    let event_keys: IndexSet<_> = confirmed.events().iter().map(|event| (*event.program_id(), *event.name())).collect();
+    event_keys.into_iter().map(move |event_key| (event_key, confirmed.id()))
+}
+
+/// The block tree, with its nodes persisted in the block tree map of the given block storage.
+/// Note: Only the block tree grows with the chain. The transactions, transaction, and transition trees are bounded
+/// by their depth, and are rebuilt from a single block or transaction when a path is requested, so they stay in memory.
+pub type BlockTreeStore<N, B> =
+    PersistentMerkleTree<N, BHP1024<N>, BHP512<N>, <B as BlockStorage<N>>::BlockTreeMap, BLOCKS_DEPTH>;
+
 fn to_confirmed_transaction<N: Network>(
     confirmed_type: ConfirmedTxType,
     transaction: Transaction<N>,
@@ -102,8 +138,18 @@ fn to_confirmed_transaction<N: Network>(
             let num_finalize = NumFinalizeSize::read_le(&mut cursor)?;
             // Read the finalize operations.
             let finalize = (0..num_finalize).map(|_| FromBytes::read_le(&mut cursor)).collect::<Result<Vec<_>, _>>()?;
+            // Read the events, if the blob contains them.
+            let events = match cursor.position() < cursor.get_ref().len() as u64 {
+                true => {
+                    // Read the number of events.
+                    let num_events = NumEventsSize::read_le(&mut cursor)?;
+                    // Read the events.
+                    (0..num_events).map(|_| FromBytes::read_le(&mut cursor)).collect::<Result<Vec<_>, _>>()?
+                }
+                false => vec![],
+            };
             // Return the confirmed transaction.
-            ConfirmedTransaction::accepted_execute(index, transaction, finalize)
+            ConfirmedTransaction::accepted_execute(index, transaction, finalize, events)
         }
         ConfirmedTxType::RejectedDeploy(index) => {
             ConfirmedTransaction::rejected_deploy(index, transaction, FromBytes::read_le(&*blob)?)
@@ -142,6 +188,12 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type CoinbasePuzzleCommitmentMap: for<'a> Map<'a, PuzzleCommitment<N>, u32>;
     /// The mapping of `block hash` to `block signature`.
     type SignatureMap: for<'a> Map<'a, N::BlockHash, Signature<N>>;
+    /// The mapping of `(level, index)` to `block tree node`.
+    type BlockTreeMap: for<'a> Map<'a, MerkleNodeKey, Field<N>>;
+    /// The mapping of `(program ID, event name, index)` to `transaction ID`.
+    type EventsMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u32), N::TransactionID>;
+    /// The mapping of `(program ID, event name)` to `number of transactions`.
+    type EventCountMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>), u32>;
 
     /// Initializes the block storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -170,6 +222,12 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn coinbase_puzzle_commitment_map(&self) -> &Self::CoinbasePuzzleCommitmentMap;
     /// Returns the signature map.
     fn signature_map(&self) -> &Self::SignatureMap;
+    /// Returns the block tree map.
+    fn block_tree_map(&self) -> &Self::BlockTreeMap;
+    /// Returns the events map.
+    fn events_map(&self) -> &Self::EventsMap;
+    /// Returns the event count map.
+    fn event_count_map(&self) -> &Self::EventCountMap;
 
     /// Returns the transition store.
     fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
@@ -195,6 +253,9 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.coinbase_solution_map().start_atomic();
         self.coinbase_puzzle_commitment_map().start_atomic();
         self.signature_map().start_atomic();
+        self.block_tree_map().start_atomic();
+        self.events_map().start_atomic();
+        self.event_count_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -211,6 +272,9 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.coinbase_solution_map().is_atomic_in_progress()
             || self.coinbase_puzzle_commitment_map().is_atomic_in_progress()
             || self.signature_map().is_atomic_in_progress()
+            || self.block_tree_map().is_atomic_in_progress()
+            || self.events_map().is_atomic_in_progress()
+            || self.event_count_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -227,6 +291,9 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.coinbase_solution_map().atomic_checkpoint();
         self.coinbase_puzzle_commitment_map().atomic_checkpoint();
         self.signature_map().atomic_checkpoint();
+        self.block_tree_map().atomic_checkpoint();
+        self.events_map().atomic_checkpoint();
+        self.event_count_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -243,6 +310,9 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.coinbase_solution_map().clear_latest_checkpoint();
         self.coinbase_puzzle_commitment_map().clear_latest_checkpoint();
         self.signature_map().clear_latest_checkpoint();
+        self.block_tree_map().clear_latest_checkpoint();
+        self.events_map().clear_latest_checkpoint();
+        self.event_count_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -259,6 +329,9 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.coinbase_solution_map().atomic_rewind();
         self.coinbase_puzzle_commitment_map().atomic_rewind();
         self.signature_map().atomic_rewind();
+        self.block_tree_map().atomic_rewind();
+        self.events_map().atomic_rewind();
+        self.event_count_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -275,6 +348,9 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.coinbase_solution_map().abort_atomic();
         self.coinbase_puzzle_commitment_map().abort_atomic();
         self.signature_map().abort_atomic();
+        self.block_tree_map().abort_atomic();
+        self.events_map().abort_atomic();
+        self.event_count_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -290,7 +366,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.ratifications_map().finish_atomic()?;
         self.coinbase_solution_map().finish_atomic()?;
         self.coinbase_puzzle_commitment_map().finish_atomic()?;
-        self.signature_map().finish_atomic()
+        self.signature_map().finish_atomic()?;
+        self.block_tree_map().finish_atomic()?;
+        self.events_map().finish_atomic()?;
+        self.event_count_map().finish_atomic()
     }
 
     /// Stores the given `(state root, block)` pair into storage.
@@ -302,6 +381,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             .cloned()
             .map(|confirmed| to_confirmed_tuple(confirmed))
             .collect::<Result<Vec<_>, anyhow::Error>>()?;
+        // Prepare the emitted events, as `((program ID, event name), transaction ID)` pairs.
+        let events = block.transactions().iter().flat_map(to_event_keys).collect::<Vec<_>>();
 
         atomic_batch_scope!(self, {
             // Store the (block height, state root) pair.
@@ -327,6 +408,16 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.transaction_store().insert(&transaction)?;
             }
 
+            // Store the transaction IDs for each emitted event, at the next index for the event.
+            for ((program_id, name), transaction_id) in events {
+                let count = match self.event_count_map().get_speculative(&(program_id, name))? {
+                    Some(count) => cow_to_copied!(count),
+                    None => 0,
+                };
+                self.events_map().insert((program_id, name, count), transaction_id)?;
+                self.event_count_map().insert((program_id, name), count.saturating_add(1))?;
+            }
+
             // Store the block ratifications.
             self.ratifications_map().insert(block.hash(), block.ratifications().clone())?;
 
@@ -364,6 +455,13 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             Some(transaction_ids) => transaction_ids,
             None => bail!("Failed to remove block: missing transactions for block '{block_height}' ('{block_hash}')"),
         };
+        // Retrieve the emitted events, as `((program ID, event name), transaction ID)` pairs.
+        let mut events = Vec::new();
+        for transaction_id in transaction_ids.iter() {
+            if let Some(confirmed) = self.get_confirmed_transaction(*transaction_id)? {
+                events.extend(to_event_keys(&confirmed));
+            }
+        }
         // Retrieve the coinbase solution.
         let coinbase = match self.coinbase_solution_map().get_confirmed(block_hash)? {
             Some(coinbase_solution) => cow_to_cloned!(coinbase_solution),
@@ -396,6 +494,25 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.transaction_store().remove(transaction_id)?;
             }
 
+            // Remove the transaction IDs for each emitted event, in the reverse order they were stored.
+            // Note: The removed block is the latest block, so its events are the last entries for each event.
+            for ((program_id, name), transaction_id) in events.into_iter().rev() {
+                let index = match self.event_count_map().get_speculative(&(program_id, name))? {
+                    Some(count) => cow_to_copied!(count).saturating_sub(1),
+                    None => bail!("Failed to remove block: missing event count for '{program_id}/{name}'"),
+                };
+                // Ensure the last entry for the event is the transaction.
+                match self.events_map().get_speculative(&(program_id, name, index))? {
+                    Some(id) if cow_to_copied!(id) == transaction_id => (),
+                    _ => bail!("Failed to remove block: mismatching '{program_id}/{name}' event '{transaction_id}'"),
+                }
+                self.events_map().remove(&(program_id, name, index))?;
+                match index {
+                    0 => self.event_count_map().remove(&(program_id, name))?,
+                    _ => self.event_count_map().insert((program_id, name), index)?,
+                }
+            }
+
             // Remove the block ratifications.
             self.ratifications_map().remove(block_hash)?;
 
@@ -450,7 +567,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     }
 
     /// Returns a state path for the given `commitment`.
-    fn get_state_path_for_commitment(&self, commitment: &Field<N>, block_tree: &BlockTree<N>) -> Result<StatePath<N>> {
+    fn get_state_path_for_commitment(
+        &self,
+        commitment: &Field<N>,
+        block_tree: &BlockTreeStore<N, Self>,
+    ) -> Result<StatePath<N>> {
         // Ensure the commitment exists.
         if !self.transition_store().contains_commitment(commitment)? {
             bail!("Commitment '{commitment}' does not exist");
@@ -597,6 +718,42 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         to_confirmed_transaction(confirmed_type, transaction, blob).map(Some)
     }
 
+    /// Returns the IDs of the transactions that emitted the given event, in the order they were stored.
+    fn find_event_transaction_ids(
+        &self,
+        program_id: &ProgramID<N>,
+        name: &Identifier<N>,
+    ) -> Result<Vec<N::TransactionID>> {
+        // Retrieve the number of transactions that emitted the event.
+        let count = match self.event_count_map().get_confirmed(&(*program_id, *name))? {
+            Some(count) => cow_to_copied!(count),
+            None => return Ok(Vec::new()),
+        };
+        // Retrieve the transaction IDs, in the order they were stored.
+        (0..count)
+            .map(|index| match self.events_map().get_confirmed(&(*program_id, *name, index))? {
+                Some(transaction_id) => Ok(cow_to_copied!(transaction_id)),
+                None => bail!("Missing the transaction ID at index {index} for event '{program_id}/{name}'"),
+            })
+            .collect()
+    }
+
+    /// Returns the events with the given `program ID` and `event name`, in the order they were stored.
+    fn get_events(&self, program_id: &ProgramID<N>, name: &Identifier<N>) -> Result<Vec<Event<N>>> {
+        let mut events = Vec::new();
+        for transaction_id in self.find_event_transaction_ids(program_id, name)? {
+            // Retrieve the confirmed transaction.
+            let confirmed = match self.get_confirmed_transaction(transaction_id)? {
+                Some(confirmed) => confirmed,
+                None => bail!("Missing confirmed transaction '{transaction_id}' for event '{program_id}/{name}'"),
+            };
+            // Retrieve the matching events.
+            let is_match = |event: &&Event<N>| event.program_id() == program_id && event.name() == name;
+            events.extend(confirmed.events().iter().filter(is_match).cloned());
+        }
+        Ok(events)
+    }
+
     /// Returns the block ratifications for the given `block hash`.
     fn get_block_ratifications(&self, block_hash: &N::BlockHash) -> Result<Option<Vec<Ratify<N>>>> {
         match self.ratifications_map().get_confirmed(block_hash)? {
@@ -676,7 +833,7 @@ pub struct BlockStore<N: Network, B: BlockStorage<N>> {
     /// The block storage.
     storage: B,
     /// The block tree.
-    tree: Arc<RwLock<BlockTree<N>>>,
+    tree: Arc<RwLock<BlockTreeStore<N, B>>>,
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
@@ -685,22 +842,29 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         // Initialize the block storage.
         let storage = B::open(dev)?;
 
-        // Compute the block tree.
+        // Load the block tree.
         let tree = {
-            // Prepare an iterator over the block heights.
-            let heights = storage.id_map().keys_confirmed();
-            // Prepare the leaves of the block tree.
-            let hashes = match heights.max() {
-                Some(height) => cfg_into_iter!(0..=cow_to_copied!(height))
-                    .map(|height| match storage.get_block_hash(height)? {
-                        Some(hash) => Ok(hash.to_bits_le()),
-                        None => bail!("Missing block hash for block {height}"),
-                    })
-                    .collect::<Result<Vec<Vec<bool>>>>()?,
-                None => vec![],
-            };
-            // Construct the block tree.
-            Arc::new(RwLock::new(N::merkle_tree_bhp(&hashes)?))
+            // Retrieve the hashers for the block tree.
+            let hashers = N::merkle_tree_bhp::<BLOCKS_DEPTH>(&[])?;
+            // Open the block tree from storage.
+            let mut tree = BlockTreeStore::<N, B>::open(
+                hashers.leaf_hasher(),
+                hashers.path_hasher(),
+                storage.block_tree_map().clone(),
+            )?;
+            // Prepare the leaves for any blocks that are missing from the block tree.
+            // Note: This is only the case for storage that was populated before the block tree was persisted.
+            let mut hashes = vec![];
+            let mut height = u32::try_from(tree.number_of_leaves())?;
+            while let Some(hash) = storage.get_block_hash(height)? {
+                hashes.push(hash.to_bits_le());
+                height = height.checked_add(1).ok_or_else(|| anyhow!("Block height overflow"))?;
+            }
+            // Append the missing leaves to the block tree.
+            if !hashes.is_empty() {
+                tree.append(&hashes)?;
+            }
+            Arc::new(RwLock::new(tree))
         };
 
         // Return the block store.
@@ -717,8 +881,12 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         if block.height() != u32::try_from(updated_tree.number_of_leaves())? - 1 {
             bail!("Attempted to insert a block at the incorrect height into storage")
         }
-        // Insert the (state root, block height) pair.
-        self.storage.insert((*updated_tree.root()).into(), block)?;
+        let updated_tree = atomic_batch_scope!(self, {
+            // Insert the (state root, block height) pair.
+            self.storage.insert((*updated_tree.root()).into(), block)?;
+            // Persist the updated block tree.
+            updated_tree.commit()
+        })?;
         // Update the block tree.
         *tree = updated_tree;
         // Return success.
@@ -734,16 +902,14 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         let mut tree = self.tree.write();
 
         // Determine the block heights to remove.
-        let heights = match self.storage.id_map().keys_confirmed().max() {
-            Some(height) => {
-                // Determine the end block height to remove.
-                let end_height = cow_to_copied!(height);
+        let heights = match u32::try_from(tree.number_of_leaves())?.checked_sub(1) {
+            Some(end_height) => {
                 // Determine the start block height to remove.
                 let start_height = end_height
                     .checked_sub(n - 1)
                     .ok_or_else(|| anyhow!("Failed to remove last '{n}' blocks: block height underflow"))?;
-                // Ensure the block height matches the number of leaves in the Merkle tree.
-                ensure!(end_height == u32::try_from(tree.number_of_leaves())? - 1, "Block height mismatch");
+                // Ensure the block height matches the latest block in storage.
+                ensure!(self.storage.id_map().contains_key_confirmed(&end_height)?, "Block height mismatch");
                 // Output the block heights.
                 start_height..=end_height
             }
@@ -761,12 +927,13 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         // Prepare an updated Merkle tree removing the last 'n' block hashes.
         let updated_tree = tree.prepare_remove_last_n(usize::try_from(n)?)?;
 
-        atomic_batch_scope!(self, {
+        let updated_tree = atomic_batch_scope!(self, {
             // Remove the blocks, in descending order.
             for block_hash in hashes.iter().rev() {
                 self.storage.remove(block_hash)?;
             }
-            Ok(())
+            // Persist the updated block tree.
+            updated_tree.commit()
         })?;
 
         // Update the block tree.
@@ -844,9 +1011,23 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     ) -> Result<Option<u32>> {
         self.storage.find_block_height_from_puzzle_commitment(puzzle_commitment)
     }
+
+    /// Returns the IDs of the transactions that emitted an event with the given `program ID` and `event name`.
+    pub fn find_event_transaction_ids(
+        &self,
+        program_id: &ProgramID<N>,
+        name: &Identifier<N>,
+    ) -> Result<Vec<N::TransactionID>> {
+        self.storage.find_event_transaction_ids(program_id, name)
+    }
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
+    /// Returns the current block height.
+    pub fn current_block_height(&self) -> u32 {
+        u32::try_from(self.tree.read().number_of_leaves()).unwrap_or(u32::MAX).saturating_sub(1)
+    }
+
     /// Returns the current state root.
     pub fn current_state_root(&self) -> N::StateRoot {
         (*self.tree.read().root()).into()
@@ -919,6 +1100,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         self.storage.transaction_store().get_program(program_id)
     }
+
+    /// Returns the events with the given `program ID` and `event name`.
+    pub fn get_events(&self, program_id: &ProgramID<N>, name: &Identifier<N>) -> Result<Vec<Event<N>>> {
+        self.storage.get_events(program_id, name)
+    }
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
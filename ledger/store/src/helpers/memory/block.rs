@@ -13,13 +13,21 @@
 // limitations under the License.
 
 use crate::{
-    helpers::memory::{MemoryMap, TransactionMemory, TransitionMemory},
+    helpers::{
+        memory::{MemoryMap, TransactionMemory, TransitionMemory},
+        MerkleNodeKey,
+    },
     BlockStorage,
     ConfirmedTxType,
     TransactionStore,
     TransitionStore,
 };
-use console::{account::Signature, prelude::*};
+use console::{
+    account::Signature,
+    prelude::*,
+    program::{Identifier, ProgramID},
+    types::Field,
+};
 use ledger_block::{Header, Ratify};
 use ledger_coinbase::{CoinbaseSolution, PuzzleCommitment};
 
@@ -50,6 +58,12 @@ pub struct BlockMemory<N: Network> {
     coinbase_puzzle_commitment_map: MemoryMap<PuzzleCommitment<N>, u32>,
     /// The signature map.
     signature_map: MemoryMap<N::BlockHash, Signature<N>>,
+    /// The block tree map.
+    block_tree_map: MemoryMap<MerkleNodeKey, Field<N>>,
+    /// The events map.
+    events_map: MemoryMap<(ProgramID<N>, Identifier<N>, u32), N::TransactionID>,
+    /// The event count map.
+    event_count_map: MemoryMap<(ProgramID<N>, Identifier<N>), u32>,
 }
 
 #[rustfmt::skip]
@@ -67,6 +81,9 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type CoinbaseSolutionMap = MemoryMap<N::BlockHash, Option<CoinbaseSolution<N>>>;
     type CoinbasePuzzleCommitmentMap = MemoryMap<PuzzleCommitment<N>, u32>;
     type SignatureMap = MemoryMap<N::BlockHash, Signature<N>>;
+    type BlockTreeMap = MemoryMap<MerkleNodeKey, Field<N>>;
+    type EventsMap = MemoryMap<(ProgramID<N>, Identifier<N>, u32), N::TransactionID>;
+    type EventCountMap = MemoryMap<(ProgramID<N>, Identifier<N>), u32>;
 
     /// Initializes the block storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -88,6 +105,9 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             coinbase_solution_map: MemoryMap::default(),
             coinbase_puzzle_commitment_map: MemoryMap::default(),
             signature_map: MemoryMap::default(),
+            block_tree_map: MemoryMap::default(),
+            events_map: MemoryMap::default(),
+            event_count_map: MemoryMap::default(),
         })
     }
 
@@ -150,4 +170,19 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     fn signature_map(&self) -> &Self::SignatureMap {
         &self.signature_map
     }
+
+    /// Returns the block tree map.
+    fn block_tree_map(&self) -> &Self::BlockTreeMap {
+        &self.block_tree_map
+    }
+
+    /// Returns the events map.
+    fn events_map(&self) -> &Self::EventsMap {
+        &self.events_map
+    }
+
+    /// Returns the event count map.
+    fn event_count_map(&self) -> &Self::EventCountMap {
+        &self.event_count_map
+    }
 }
@@ -34,6 +34,12 @@ pub struct FinalizeMemory<N: Network> {
     key_map: MemoryMap<Field<N>, Plaintext<N>>,
     /// The value map.
     value_map: MemoryMap<Field<N>, Value<N>>,
+    /// The history map.
+    history_map: MemoryMap<Field<N>, (ProgramID<N>, Identifier<N>, Plaintext<N>, Value<N>)>,
+    /// The diff ID map.
+    diff_id_map: MemoryMap<N::TransactionID, Vec<(Option<Field<N>>, Option<Field<N>>)>>,
+    /// The usage map.
+    usage_map: MemoryMap<Field<N>, (u64, u64)>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -45,6 +51,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     type KeyValueIDMap = MemoryMap<Field<N>, IndexMap<Field<N>, Field<N>>>;
     type KeyMap = MemoryMap<Field<N>, Plaintext<N>>;
     type ValueMap = MemoryMap<Field<N>, Value<N>>;
+    type HistoryMap = MemoryMap<Field<N>, (ProgramID<N>, Identifier<N>, Plaintext<N>, Value<N>)>;
+    type DiffIDMap = MemoryMap<N::TransactionID, Vec<(Option<Field<N>>, Option<Field<N>>)>>;
+    type UsageMap = MemoryMap<Field<N>, (u64, u64)>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -54,6 +63,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
             key_value_id_map: MemoryMap::default(),
             key_map: MemoryMap::default(),
             value_map: MemoryMap::default(),
+            history_map: MemoryMap::default(),
+            diff_id_map: MemoryMap::default(),
+            usage_map: MemoryMap::default(),
             dev,
         })
     }
@@ -83,6 +95,21 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
         &self.value_map
     }
 
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
+    /// Returns the diff ID map.
+    fn diff_id_map(&self) -> &Self::DiffIDMap {
+        &self.diff_id_map
+    }
+
+    /// Returns the usage map.
+    fn usage_map(&self) -> &Self::UsageMap {
+        &self.usage_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
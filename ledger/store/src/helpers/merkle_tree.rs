@@ -0,0 +1,535 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    atomic_batch_scope,
+    cow_to_copied,
+    helpers::Map,
+};
+use console::{
+    collections::merkle_tree::{LeafHash, MerklePath, PathHash},
+    network::prelude::*,
+    types::{Field, U64},
+};
+
+use std::collections::BTreeMap;
+
+/// The key of a node in a persistent Merkle tree, as a `(level, index)` pair.
+/// Level `0` contains the leaf hashes, and the index increases from left to right within a level.
+pub type MerkleNodeKey = (u8, u64);
+
+/// A Merkle tree whose nodes are persisted in a map, instead of being held in memory.
+///
+/// The nodes are keyed by `(level, index)`, so that they remain valid as the tree grows.
+/// Nodes that only cover empty leaves are never stored. Only the rightmost node of each level
+/// (the frontier) and the root are cached, which allows the tree to be opened without rehashing.
+///
+/// The roots and paths of this tree are identical to those of the in-memory `MerkleTree`.
+#[derive(Clone)]
+pub struct PersistentMerkleTree<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    M: for<'a> Map<'a, MerkleNodeKey, Field<E>>,
+    const DEPTH: u8,
+> {
+    /// The leaf hasher for the Merkle tree.
+    leaf_hasher: LH,
+    /// The path hasher for the Merkle tree.
+    path_hasher: PH,
+    /// The map of `(level, index)` to node hash.
+    map: M,
+    /// The computed root of the full Merkle tree.
+    root: PH::Hash,
+    /// The rightmost node of each level, from the hashed leaves to the top of the tree.
+    frontier: Vec<PH::Hash>,
+    /// The hash of an empty subtree for each level, from the hashed leaves up to `DEPTH`.
+    empty_hashes: Vec<PH::Hash>,
+    /// The number of hashed leaves in the tree.
+    number_of_leaves: usize,
+    /// The node updates that have been prepared, but not yet written to the map.
+    pending: BTreeMap<MerkleNodeKey, Option<PH::Hash>>,
+}
+
+impl<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    M: for<'a> Map<'a, MerkleNodeKey, Field<E>>,
+    const DEPTH: u8,
+> PersistentMerkleTree<E, LH, PH, M, DEPTH>
+{
+    /// Opens the Merkle tree that is stored in the given map.
+    pub fn open(leaf_hasher: &LH, path_hasher: &PH, map: M) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+
+        // Compute the empty hash of each level.
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        empty_hashes.push(path_hasher.hash_empty()?);
+        for level in 0..DEPTH as usize {
+            empty_hashes.push(path_hasher.hash_children(&empty_hashes[level], &empty_hashes[level])?);
+        }
+
+        // Initialize an empty Merkle tree over the map.
+        let mut tree = Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            map,
+            root: empty_hashes[0],
+            frontier: vec![],
+            empty_hashes,
+            number_of_leaves: 0,
+            pending: Default::default(),
+        };
+
+        // Find the number of leaves, which are stored contiguously from index 0.
+        let number_of_leaves = tree.find_number_of_leaves()?;
+        // Load the frontier and compute the root.
+        let (frontier, root) = tree.compute_frontier_and_root(number_of_leaves, &Default::default())?;
+
+        tree.frontier = frontier;
+        tree.root = root;
+        tree.number_of_leaves = usize::try_from(number_of_leaves)?;
+        Ok(tree)
+    }
+
+    /// Returns a new Merkle tree with the given new leaves appended to it.
+    /// Note: The new nodes are not written to the map until `commit` is called.
+    pub fn prepare_append(&self, new_leaves: &[LH::Leaf]) -> Result<Self> {
+        // Compute the start and end indices of the new leaves.
+        let start = u64::try_from(self.number_of_leaves)?;
+        let end = start
+            .checked_add(u64::try_from(new_leaves.len())?)
+            .ok_or_else(|| anyhow!("Integer overflow when computing the number of leaves in the Merkle tree"))?;
+        // Compute the height of the updated Merkle tree.
+        let height = tree_height::<DEPTH>(end)?;
+
+        // Initialize the node updates, starting from any updates that have not been committed yet.
+        let mut updates = self.pending.clone();
+
+        // Compute and store each new leaf hash.
+        for (index, leaf_hash) in (start..end).zip_eq(self.leaf_hasher.hash_leaves(new_leaves)?) {
+            updates.insert((0, index), Some(leaf_hash));
+        }
+
+        // Recompute the ancestors of the new leaves, iterating from the penultimate level to the top level.
+        if start < end {
+            for level in 1..=height {
+                // Compute the indices of the ancestors on this level.
+                let indices = (start >> level)..=((end - 1) >> level);
+                // Construct the children for each ancestor on this level.
+                let tuples = indices
+                    .clone()
+                    .map(|index| {
+                        let left = self.get_node(&updates, level - 1, 2 * index)?;
+                        let right = self.get_node(&updates, level - 1, 2 * index + 1)?;
+                        Ok((left, right))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                // Compute and store the hashes for each ancestor on this level.
+                for (index, hash) in indices.zip_eq(self.path_hasher.hash_all_children(&tuples)?) {
+                    updates.insert((level, index), Some(hash));
+                }
+            }
+        }
+
+        // Compute the frontier and root of the updated Merkle tree.
+        let (frontier, root) = self.compute_frontier_and_root(end, &updates)?;
+
+        Ok(Self {
+            leaf_hasher: self.leaf_hasher.clone(),
+            path_hasher: self.path_hasher.clone(),
+            map: self.map.clone(),
+            root,
+            frontier,
+            empty_hashes: self.empty_hashes.clone(),
+            number_of_leaves: usize::try_from(end)?,
+            pending: updates,
+        })
+    }
+
+    /// Updates the Merkle tree with the given new leaves appended to it.
+    pub fn append(&mut self, new_leaves: &[LH::Leaf]) -> Result<()> {
+        // Compute and persist the updated Merkle tree with the new leaves.
+        let updated_tree = self.prepare_append(new_leaves)?.commit()?;
+        // Update the tree at the very end, so the original tree is not altered in case of failure.
+        *self = updated_tree;
+        Ok(())
+    }
+
+    /// Returns a new Merkle tree with the last 'n' leaves removed from it.
+    /// Note: The removed nodes are not deleted from the map until `commit` is called.
+    pub fn prepare_remove_last_n(&self, n: usize) -> Result<Self> {
+        ensure!(n > 0, "Cannot remove zero leaves from the Merkle tree");
+
+        // Determine the updated number of leaves, after removing the last 'n' leaves.
+        let number_of_leaves = u64::try_from(self.number_of_leaves)?;
+        let updated_number_of_leaves = number_of_leaves.checked_sub(u64::try_from(n)?).ok_or_else(|| {
+            anyhow!("Failed to remove '{n}' leaves from the Merkle tree, as it only contains {}", self.number_of_leaves)
+        })?;
+
+        // Compute the heights of the current and updated Merkle trees.
+        let height = tree_height::<DEPTH>(number_of_leaves)?;
+        let updated_height = tree_height::<DEPTH>(updated_number_of_leaves)?;
+
+        // Initialize the node updates, starting from any updates that have not been committed yet.
+        let mut updates = self.pending.clone();
+
+        // Remove every node that no longer covers a leaf, or that is above the top level of the updated tree.
+        for level in 0..=height {
+            let start = match level > updated_height {
+                true => 0,
+                false => number_of_nodes(updated_number_of_leaves, level),
+            };
+            for index in start..number_of_nodes(number_of_leaves, level) {
+                updates.insert((level, index), None);
+            }
+        }
+
+        // Recompute the rightmost node of each level, as its right subtree may have been removed.
+        if updated_number_of_leaves > 0 {
+            for level in 1..=updated_height {
+                let index = (updated_number_of_leaves - 1) >> level;
+                let left = self.get_node(&updates, level - 1, 2 * index)?;
+                let right = self.get_node(&updates, level - 1, 2 * index + 1)?;
+                updates.insert((level, index), Some(self.path_hasher.hash_children(&left, &right)?));
+            }
+        }
+
+        // Compute the frontier and root of the updated Merkle tree.
+        let (frontier, root) = self.compute_frontier_and_root(updated_number_of_leaves, &updates)?;
+
+        Ok(Self {
+            leaf_hasher: self.leaf_hasher.clone(),
+            path_hasher: self.path_hasher.clone(),
+            map: self.map.clone(),
+            root,
+            frontier,
+            empty_hashes: self.empty_hashes.clone(),
+            number_of_leaves: usize::try_from(updated_number_of_leaves)?,
+            pending: updates,
+        })
+    }
+
+    /// Updates the Merkle tree with the last 'n' leaves removed from it.
+    pub fn remove_last_n(&mut self, n: usize) -> Result<()> {
+        // Compute and persist the updated Merkle tree with the last 'n' leaves removed.
+        let updated_tree = self.prepare_remove_last_n(n)?.commit()?;
+        // Update the tree at the very end, so the original tree is not altered in case of failure.
+        *self = updated_tree;
+        Ok(())
+    }
+
+    /// Writes the pending node updates to the map, and returns the committed Merkle tree.
+    /// If an atomic batch is in progress on the map, the updates become part of it.
+    pub fn commit(mut self) -> Result<Self> {
+        atomic_batch_scope!(self.map, {
+            for (key, node) in &self.pending {
+                match node {
+                    Some(hash) => self.map.insert(*key, *hash)?,
+                    None => self.map.remove(key)?,
+                }
+            }
+            Ok(())
+        })?;
+        // Clear the pending node updates.
+        self.pending.clear();
+        Ok(self)
+    }
+
+    /// Returns the Merkle path for the given leaf index and leaf.
+    pub fn prove(&self, leaf_index: usize, leaf: &LH::Leaf) -> Result<MerklePath<E, DEPTH>> {
+        // Ensure the leaf index is valid.
+        ensure!(leaf_index < self.number_of_leaves, "The given Merkle leaf index is out of bounds");
+
+        // Compute the leaf hash.
+        let leaf_hash = self.leaf_hasher.hash_leaf(leaf)?;
+        // Ensure the leaf hash matches the one in the tree.
+        let index = u64::try_from(leaf_index)?;
+        ensure!(
+            self.get_node(&self.pending, 0, index)? == leaf_hash,
+            "The given Merkle leaf does not match the one in the Merkle tree"
+        );
+
+        // Compute the height of the Merkle tree.
+        let height = tree_height::<DEPTH>(u64::try_from(self.number_of_leaves)?)?;
+
+        // Initialize a vector for the Merkle path.
+        let mut path = Vec::with_capacity(DEPTH as usize);
+        // Iterate from the leaf hash to the top level, storing the sibling hashes along the path.
+        for level in 0..height {
+            path.push(self.get_node(&self.pending, level, (index >> level) ^ 1)?);
+        }
+        // If the Merkle path length is not equal to `DEPTH`, pad the path with the empty hash.
+        path.resize(DEPTH as usize, self.empty_hashes[0]);
+
+        // Return the Merkle path.
+        MerklePath::try_from((U64::new(index), path))
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    pub fn verify(&self, path: &MerklePath<E, DEPTH>, root: &PH::Hash, leaf: &LH::Leaf) -> bool {
+        path.verify(&self.leaf_hasher, &self.path_hasher, root, leaf)
+    }
+
+    /// Returns the Merkle root of the tree.
+    pub const fn root(&self) -> &PH::Hash {
+        &self.root
+    }
+
+    /// Returns the rightmost node of each level, from the hashed leaves to the top of the tree.
+    pub fn frontier(&self) -> &[PH::Hash] {
+        &self.frontier
+    }
+
+    /// Returns the empty hash.
+    pub fn empty_hash(&self) -> &PH::Hash {
+        &self.empty_hashes[0]
+    }
+
+    /// Returns the number of leaves in the Merkle tree.
+    pub const fn number_of_leaves(&self) -> usize {
+        self.number_of_leaves
+    }
+
+    /// Returns the node at the given level and index, from the given updates or the map.
+    /// If the node does not exist, the empty hash for the level is returned.
+    fn get_node(&self, updates: &BTreeMap<MerkleNodeKey, Option<PH::Hash>>, level: u8, index: u64) -> Result<PH::Hash> {
+        // Retrieve the node from the updates, if it exists.
+        let node = match updates.get(&(level, index)) {
+            Some(node) => *node,
+            None => self.map.get_speculative(&(level, index))?.map(|hash| cow_to_copied!(hash)),
+        };
+        // Return the node, or the empty hash of the level.
+        match node {
+            Some(hash) => Ok(hash),
+            None => match self.empty_hashes.get(level as usize) {
+                Some(empty_hash) => Ok(*empty_hash),
+                None => bail!("Merkle tree cannot exceed depth {DEPTH}: attempted to read level {level}"),
+            },
+        }
+    }
+
+    /// Returns the frontier and the root of a tree with the given number of leaves.
+    fn compute_frontier_and_root(
+        &self,
+        number_of_leaves: u64,
+        updates: &BTreeMap<MerkleNodeKey, Option<PH::Hash>>,
+    ) -> Result<(Vec<PH::Hash>, PH::Hash)> {
+        // Compute the height of the Merkle tree.
+        let height = tree_height::<DEPTH>(number_of_leaves)?;
+
+        // Retrieve the rightmost node of each level.
+        let frontier = match number_of_leaves {
+            0 => vec![],
+            _ => (0..=height)
+                .map(|level| self.get_node(updates, level, (number_of_leaves - 1) >> level))
+                .collect::<Result<Vec<_>>>()?,
+        };
+
+        // Compute the root hash, by iterating from the top level up to `DEPTH`.
+        let mut root_hash = frontier.last().copied().unwrap_or(self.empty_hashes[0]);
+        for _ in height..DEPTH {
+            // Update the root hash, by hashing the current root hash with the empty hash.
+            root_hash = self.path_hasher.hash_children(&root_hash, &self.empty_hashes[0])?;
+        }
+        Ok((frontier, root_hash))
+    }
+
+    /// Returns the number of leaves stored in the map.
+    fn find_number_of_leaves(&self) -> Result<u64> {
+        // Returns `true` if the leaf at the given index is stored.
+        let contains_leaf = |index: u64| self.map.contains_key_speculative(&(0u8, index));
+
+        // If the first leaf does not exist, the tree is empty.
+        if !contains_leaf(0)? {
+            return Ok(0);
+        }
+        // Find an upper bound on the number of leaves, by doubling the index.
+        let mut upper = 1u64;
+        while contains_leaf(upper)? {
+            upper = upper.checked_mul(2).ok_or_else(|| anyhow!("Failed to find the number of leaves in the tree"))?;
+        }
+        // Binary search for the first missing leaf, which lies in `(upper / 2, upper]`.
+        let mut lower = upper / 2;
+        while lower + 1 < upper {
+            let middle = lower + (upper - lower) / 2;
+            match contains_leaf(middle)? {
+                true => lower = middle,
+                false => upper = middle,
+            }
+        }
+        Ok(upper)
+    }
+}
+
+/// Returns the number of levels above the hashed leaves, for a tree with the given number of leaves.
+fn tree_height<const DEPTH: u8>(number_of_leaves: u64) -> Result<u8> {
+    // Compute the maximum number of leaves.
+    let max_leaves = match number_of_leaves.checked_next_power_of_two() {
+        Some(max_leaves) => max_leaves,
+        None => bail!("Integer overflow when computing the maximum number of leaves in the Merkle tree"),
+    };
+    // Compute the height of the tree (i.e. log2(max_leaves)).
+    let height = u8::try_from(max_leaves.trailing_zeros())?;
+    // Ensure the tree height is within the depth bound.
+    match height <= DEPTH {
+        true => Ok(height),
+        false => bail!("Merkle tree cannot exceed depth {DEPTH}: attempted to reach depth {height}"),
+    }
+}
+
+/// Returns the number of nodes on the given level that cover at least one of the given number of leaves.
+fn number_of_nodes(number_of_leaves: u64, level: u8) -> u64 {
+    match number_of_leaves {
+        0 => 0,
+        _ => ((number_of_leaves - 1).checked_shr(u32::from(level)).unwrap_or(0)) + 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::memory::MemoryMap;
+    use console::{
+        algorithms::{BHP1024, BHP512},
+        network::{BHPMerkleTree, Testnet3},
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    const DEPTH: u8 = 32;
+
+    type TestTree = PersistentMerkleTree<
+        CurrentNetwork,
+        BHP1024<CurrentNetwork>,
+        BHP512<CurrentNetwork>,
+        MemoryMap<MerkleNodeKey, Field<CurrentNetwork>>,
+        DEPTH,
+    >;
+
+    /// Returns a persistent Merkle tree and an in-memory Merkle tree, over the same hashers.
+    fn sample_trees(
+        map: MemoryMap<MerkleNodeKey, Field<CurrentNetwork>>,
+    ) -> (TestTree, BHPMerkleTree<CurrentNetwork, DEPTH>) {
+        let expected = CurrentNetwork::merkle_tree_bhp::<DEPTH>(&[]).unwrap();
+        let candidate = TestTree::open(expected.leaf_hasher(), expected.path_hasher(), map).unwrap();
+        (candidate, expected)
+    }
+
+    /// Samples the given number of leaves.
+    fn sample_leaves(num_leaves: usize, rng: &mut TestRng) -> Vec<Vec<bool>> {
+        (0..num_leaves).map(|_| Field::<CurrentNetwork>::rand(rng).to_bits_le()).collect()
+    }
+
+    /// Checks that the given trees have the same root, and produce the same Merkle paths for the given leaves.
+    fn check_equivalence(candidate: &TestTree, expected: &BHPMerkleTree<CurrentNetwork, DEPTH>, leaves: &[Vec<bool>]) {
+        assert_eq!(expected.number_of_leaves(), candidate.number_of_leaves());
+        assert_eq!(expected.root(), candidate.root());
+        for (index, leaf) in leaves.iter().enumerate() {
+            let path = candidate.prove(index, leaf).unwrap();
+            assert_eq!(expected.prove(index, leaf).unwrap(), path);
+            assert!(candidate.verify(&path, candidate.root(), leaf));
+        }
+    }
+
+    #[test]
+    fn test_append_and_remove() {
+        let rng = &mut TestRng::default();
+
+        let (mut candidate, mut expected) = sample_trees(MemoryMap::default());
+        check_equivalence(&candidate, &expected, &[]);
+
+        let mut leaves = vec![];
+        for num_new_leaves in [1, 1, 2, 3, 5, 8, 13] {
+            // Append the new leaves.
+            let new_leaves = sample_leaves(num_new_leaves, rng);
+            candidate.append(&new_leaves).unwrap();
+            expected.append(&new_leaves).unwrap();
+            leaves.extend(new_leaves);
+            check_equivalence(&candidate, &expected, &leaves);
+        }
+
+        for n in [1, 4, 7, 2, 1] {
+            // Remove the last 'n' leaves.
+            candidate.remove_last_n(n).unwrap();
+            expected.remove_last_n(n).unwrap();
+            leaves.truncate(leaves.len() - n);
+            check_equivalence(&candidate, &expected, &leaves);
+        }
+
+        // Remove all remaining leaves.
+        candidate.remove_last_n(leaves.len()).unwrap();
+        expected.remove_last_n(leaves.len()).unwrap();
+        check_equivalence(&candidate, &expected, &[]);
+    }
+
+    #[test]
+    fn test_reopen() {
+        let rng = &mut TestRng::default();
+
+        let map = MemoryMap::default();
+        let (mut candidate, mut expected) = sample_trees(map.clone());
+
+        for num_leaves in [1, 6, 25] {
+            let leaves = sample_leaves(num_leaves, rng);
+            candidate.append(&leaves).unwrap();
+            expected.append(&leaves).unwrap();
+
+            // Reopen the tree from the map, and ensure it is unchanged.
+            let (reopened, _) = sample_trees(map.clone());
+            assert_eq!(candidate.number_of_leaves(), reopened.number_of_leaves());
+            assert_eq!(candidate.root(), reopened.root());
+            assert_eq!(candidate.frontier(), reopened.frontier());
+            assert_eq!(expected.root(), reopened.root());
+        }
+    }
+
+    #[test]
+    fn test_prepare_does_not_write() {
+        let rng = &mut TestRng::default();
+
+        let map = MemoryMap::default();
+        let (candidate, _) = sample_trees(map.clone());
+
+        // Prepare an append, without committing it.
+        let prepared = candidate.prepare_append(&sample_leaves(3, rng)).unwrap();
+        assert_eq!(3, prepared.number_of_leaves());
+        assert_ne!(candidate.root(), prepared.root());
+
+        // Ensure the map is unchanged.
+        let (reopened, _) = sample_trees(map.clone());
+        assert_eq!(0, reopened.number_of_leaves());
+        assert_eq!(candidate.root(), reopened.root());
+
+        // Commit the append, and ensure the map is updated.
+        let committed = prepared.commit().unwrap();
+        let (reopened, _) = sample_trees(map);
+        assert_eq!(committed.root(), reopened.root());
+    }
+
+    #[test]
+    fn test_remove_too_many() {
+        let rng = &mut TestRng::default();
+
+        let (mut candidate, _) = sample_trees(MemoryMap::default());
+        candidate.append(&sample_leaves(2, rng)).unwrap();
+        assert!(candidate.remove_last_n(0).is_err());
+        assert!(candidate.remove_last_n(3).is_err());
+        assert_eq!(2, candidate.number_of_leaves());
+    }
+}
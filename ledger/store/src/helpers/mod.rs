@@ -16,6 +16,9 @@ pub mod memory;
 #[cfg(feature = "rocks")]
 pub mod rocksdb;
 
+mod merkle_tree;
+pub use merkle_tree::*;
+
 use console::network::prelude::*;
 
 use core::{borrow::Borrow, hash::Hash};
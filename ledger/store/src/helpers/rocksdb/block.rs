@@ -13,19 +13,27 @@
 // limitations under the License.
 
 use crate::{
-    helpers::rocksdb::{
-        internal::{self, DataMap, Database},
-        BlockMap,
-        MapID,
-        TransactionDB,
-        TransitionDB,
+    helpers::{
+        rocksdb::{
+            internal::{self, DataMap, Database},
+            BlockMap,
+            MapID,
+            TransactionDB,
+            TransitionDB,
+        },
+        MerkleNodeKey,
     },
     BlockStorage,
     ConfirmedTxType,
     TransactionStore,
     TransitionStore,
 };
-use console::{account::Signature, prelude::*};
+use console::{
+    account::Signature,
+    prelude::*,
+    program::{Identifier, ProgramID},
+    types::Field,
+};
 use ledger_block::{Header, Ratify};
 use ledger_coinbase::{CoinbaseSolution, PuzzleCommitment};
 
@@ -56,6 +64,12 @@ pub struct BlockDB<N: Network> {
     coinbase_puzzle_commitment_map: DataMap<PuzzleCommitment<N>, u32>,
     /// The signature map.
     signature_map: DataMap<N::BlockHash, Signature<N>>,
+    /// The block tree map.
+    block_tree_map: DataMap<MerkleNodeKey, Field<N>>,
+    /// The events map.
+    events_map: DataMap<(ProgramID<N>, Identifier<N>, u32), N::TransactionID>,
+    /// The event count map.
+    event_count_map: DataMap<(ProgramID<N>, Identifier<N>), u32>,
 }
 
 #[rustfmt::skip]
@@ -73,6 +87,9 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     type CoinbaseSolutionMap = DataMap<N::BlockHash, Option<CoinbaseSolution<N>>>;
     type CoinbasePuzzleCommitmentMap = DataMap<PuzzleCommitment<N>, u32>;
     type SignatureMap = DataMap<N::BlockHash, Signature<N>>;
+    type BlockTreeMap = DataMap<MerkleNodeKey, Field<N>>;
+    type EventsMap = DataMap<(ProgramID<N>, Identifier<N>, u32), N::TransactionID>;
+    type EventCountMap = DataMap<(ProgramID<N>, Identifier<N>), u32>;
 
     /// Initializes the block storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -94,6 +111,9 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
             coinbase_solution_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::CoinbaseSolution))?,
             coinbase_puzzle_commitment_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::CoinbasePuzzleCommitment))?,
             signature_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::Signature))?,
+            block_tree_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::Tree))?,
+            events_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::Events))?,
+            event_count_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::EventCount))?,
         })
     }
 
@@ -156,4 +176,19 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     fn signature_map(&self) -> &Self::SignatureMap {
         &self.signature_map
     }
+
+    /// Returns the block tree map.
+    fn block_tree_map(&self) -> &Self::BlockTreeMap {
+        &self.block_tree_map
+    }
+
+    /// Returns the events map.
+    fn events_map(&self) -> &Self::EventsMap {
+        &self.events_map
+    }
+
+    /// Returns the event count map.
+    fn event_count_map(&self) -> &Self::EventCountMap {
+        &self.event_count_map
+    }
 }
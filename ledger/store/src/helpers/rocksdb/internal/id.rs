@@ -65,6 +65,9 @@ pub enum BlockMap {
     CoinbaseSolution = DataID::BlockCoinbaseSolutionMap as u16,
     CoinbasePuzzleCommitment = DataID::BlockCoinbasePuzzleCommitmentMap as u16,
     Signature = DataID::BlockSignatureMap as u16,
+    Tree = DataID::BlockTreeMap as u16,
+    Events = DataID::BlockEventsMap as u16,
+    EventCount = DataID::BlockEventCountMap as u16,
 }
 
 /// The RocksDB map prefix for deployment-related entries.
@@ -169,6 +172,9 @@ pub enum ProgramMap {
     KeyValueID = DataID::KeyValueIDMap as u16,
     Key = DataID::KeyMap as u16,
     Value = DataID::ValueMap as u16,
+    History = DataID::HistoryMap as u16,
+    DiffID = DataID::DiffIDMap as u16,
+    Usage = DataID::UsageMap as u16,
 }
 
 /// The RocksDB map prefix for test-related entries.
@@ -251,6 +257,14 @@ enum DataID {
     KeyValueIDMap,
     KeyMap,
     ValueMap,
+    // Block (continued)
+    BlockTreeMap,
+    BlockEventsMap,
+    BlockEventCountMap,
+    // Program (continued)
+    HistoryMap,
+    DiffIDMap,
+    UsageMap,
 
     // Testing
     #[cfg(test)]
@@ -37,6 +37,12 @@ pub struct FinalizeDB<N: Network> {
     key_map: DataMap<Field<N>, Plaintext<N>>,
     /// The value map.
     value_map: DataMap<Field<N>, Value<N>>,
+    /// The history map.
+    history_map: DataMap<Field<N>, (ProgramID<N>, Identifier<N>, Plaintext<N>, Value<N>)>,
+    /// The diff ID map.
+    diff_id_map: DataMap<N::TransactionID, Vec<(Option<Field<N>>, Option<Field<N>>)>>,
+    /// The usage map.
+    usage_map: DataMap<Field<N>, (u64, u64)>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -48,6 +54,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     type KeyValueIDMap = DataMap<Field<N>, IndexMap<Field<N>, Field<N>>>;
     type KeyMap = DataMap<Field<N>, Plaintext<N>>;
     type ValueMap = DataMap<Field<N>, Value<N>>;
+    type HistoryMap = DataMap<Field<N>, (ProgramID<N>, Identifier<N>, Plaintext<N>, Value<N>)>;
+    type DiffIDMap = DataMap<N::TransactionID, Vec<(Option<Field<N>>, Option<Field<N>>)>>;
+    type UsageMap = DataMap<Field<N>, (u64, u64)>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -57,6 +66,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             key_value_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::KeyValueID))?,
             key_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::Key))?,
             value_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::Value))?,
+            history_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::History))?,
+            diff_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::DiffID))?,
+            usage_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::Usage))?,
             dev,
         })
     }
@@ -86,6 +98,21 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         &self.value_map
     }
 
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap {
+        &self.history_map
+    }
+
+    /// Returns the diff ID map.
+    fn diff_id_map(&self) -> &Self::DiffIDMap {
+        &self.diff_id_map
+    }
+
+    /// Returns the usage map.
+    fn usage_map(&self) -> &Self::UsageMap {
+        &self.usage_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
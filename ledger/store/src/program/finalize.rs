@@ -29,6 +29,9 @@ use anyhow::Result;
 use core::marker::PhantomData;
 use indexmap::{IndexMap, IndexSet};
 
+#[cfg(feature = "history")]
+use synthesizer_program::FinalizeDiff;
+
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
 /// We define the `mapping ID := Hash( program ID || mapping name )`,
@@ -51,6 +54,14 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     type KeyMap: for<'a> Map<'a, Field<N>, Plaintext<N>>;
     /// The mapping of `key ID` to `value`.
     type ValueMap: for<'a> Map<'a, Field<N>, Value<N>>;
+    /// The mapping of `value ID` to `(program ID, mapping name, key, value)`.
+    /// Note: This map is only written to when the `history` feature is enabled.
+    type HistoryMap: for<'a> Map<'a, Field<N>, (ProgramID<N>, Identifier<N>, Plaintext<N>, Value<N>)>;
+    /// The mapping of `transaction ID` to `[(old value ID, new value ID)]`.
+    /// Note: This map is only written to when the `history` feature is enabled.
+    type DiffIDMap: for<'a> Map<'a, N::TransactionID, Vec<(Option<Field<N>>, Option<Field<N>>)>>;
+    /// The mapping of `mapping ID` to `(number of entries, size in bytes)`.
+    type UsageMap: for<'a> Map<'a, Field<N>, (u64, u64)>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -65,6 +76,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     fn key_map(&self) -> &Self::KeyMap;
     /// Returns the value map.
     fn value_map(&self) -> &Self::ValueMap;
+    /// Returns the history map.
+    fn history_map(&self) -> &Self::HistoryMap;
+    /// Returns the diff ID map.
+    fn diff_id_map(&self) -> &Self::DiffIDMap;
+    /// Returns the usage map.
+    fn usage_map(&self) -> &Self::UsageMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16>;
@@ -76,6 +93,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().start_atomic();
         self.key_map().start_atomic();
         self.value_map().start_atomic();
+        self.history_map().start_atomic();
+        self.diff_id_map().start_atomic();
+        self.usage_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -85,6 +105,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.key_value_id_map().is_atomic_in_progress()
             || self.key_map().is_atomic_in_progress()
             || self.value_map().is_atomic_in_progress()
+            || self.history_map().is_atomic_in_progress()
+            || self.diff_id_map().is_atomic_in_progress()
+            || self.usage_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -94,6 +117,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().atomic_checkpoint();
         self.key_map().atomic_checkpoint();
         self.value_map().atomic_checkpoint();
+        self.history_map().atomic_checkpoint();
+        self.diff_id_map().atomic_checkpoint();
+        self.usage_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -103,6 +129,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().clear_latest_checkpoint();
         self.key_map().clear_latest_checkpoint();
         self.value_map().clear_latest_checkpoint();
+        self.history_map().clear_latest_checkpoint();
+        self.diff_id_map().clear_latest_checkpoint();
+        self.usage_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -112,6 +141,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().atomic_rewind();
         self.key_map().atomic_rewind();
         self.value_map().atomic_rewind();
+        self.history_map().atomic_rewind();
+        self.diff_id_map().atomic_rewind();
+        self.usage_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -121,6 +153,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().abort_atomic();
         self.key_map().abort_atomic();
         self.value_map().abort_atomic();
+        self.history_map().abort_atomic();
+        self.diff_id_map().abort_atomic();
+        self.usage_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -129,7 +164,49 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.mapping_id_map().finish_atomic()?;
         self.key_value_id_map().finish_atomic()?;
         self.key_map().finish_atomic()?;
-        self.value_map().finish_atomic()
+        self.value_map().finish_atomic()?;
+        self.history_map().finish_atomic()?;
+        self.diff_id_map().finish_atomic()?;
+        self.usage_map().finish_atomic()
+    }
+
+    /// Initializes the usage of each mapping that was stored before its usage was tracked.
+    /// Note: The usage of such a mapping is computed from its stored entries.
+    fn initialize_usage(&self) -> Result<()> {
+        // Compute the usage of each mapping that does not have one.
+        let mut usages = Vec::new();
+        for (mapping_id, key_value_ids) in self.key_value_id_map().iter_confirmed() {
+            // Skip the mapping if its usage is already tracked.
+            if self.usage_map().contains_key_confirmed(&mapping_id)? {
+                continue;
+            }
+            // Sum the number of entries and their size in bytes.
+            let mut usage = (0u64, 0u64);
+            for key_id in key_value_ids.keys() {
+                // Retrieve the key and value.
+                let key = match self.key_map().get_confirmed(key_id)? {
+                    Some(key) => cow_to_cloned!(key),
+                    None => bail!("Illegal operation: key ID '{key_id}' is missing in storage - cannot compute usage."),
+                };
+                let value = match self.value_map().get_confirmed(key_id)? {
+                    Some(value) => cow_to_cloned!(value),
+                    None => bail!("Illegal operation: value for key ID '{key_id}' is missing - cannot compute usage."),
+                };
+                usage = (usage.0.saturating_add(1), usage.1.saturating_add(size_in_bytes(&key, &value)?));
+            }
+            usages.push((cow_to_copied!(mapping_id), usage));
+        }
+
+        // Store the usage of each mapping.
+        if !usages.is_empty() {
+            atomic_batch_scope!(self, {
+                for (mapping_id, usage) in usages {
+                    self.usage_map().insert(mapping_id, usage)?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(())
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -174,6 +251,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             self.mapping_id_map().insert((*program_id, *mapping_name), mapping_id)?;
             // Initialize the key-value ID map.
             self.key_value_id_map().insert(mapping_id, IndexMap::new())?;
+            // Initialize the usage of the mapping.
+            self.usage_map().insert(mapping_id, (0, 0))?;
 
             Ok(())
         })?;
@@ -218,13 +297,22 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         // Insert the new key-value ID.
         key_value_ids.insert(key_id, value_id);
 
+        // Retrieve the usage of the mapping, and account for the new entry.
+        let (num_entries, num_bytes) = self.get_usage_speculative(&mapping_id)?;
+        let usage = (num_entries.saturating_add(1), num_bytes.saturating_add(size_in_bytes(&key, &value)?));
+
         atomic_batch_scope!(self, {
             // Update the key-value ID map with the new key-value ID.
             self.key_value_id_map().insert(mapping_id, key_value_ids)?;
+            // Record the key-value pair in the history.
+            #[cfg(feature = "history")]
+            self.history_map().insert(value_id, (*program_id, *mapping_name, key.clone(), value.clone()))?;
             // Insert the key.
             self.key_map().insert(key_id, key)?;
             // Insert the value.
             self.value_map().insert(key_id, value)?;
+            // Update the usage of the mapping.
+            self.usage_map().insert(mapping_id, usage)?;
 
             Ok(())
         })?;
@@ -261,6 +349,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
                 bail!("Illegal operation: mapping ID '{mapping_id}' is not initialized - cannot update key-value.")
             }
         };
+        // Retrieve the current value for the key ID, if the key exists.
+        let old_value = self.get_value_from_key_id_speculative(&key_id)?;
         // If the key ID does not exist, insert it in the key-value ID map.
         if !self.key_map().contains_key_speculative(&key_id)? {
             // Ensure the key ID does not already exist.
@@ -278,13 +368,28 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             None => bail!("Illegal operation: key ID '{key_id}' does not exist in storage - cannot finalize."),
         };
 
+        // Retrieve the usage of the mapping, and account for the new or overwritten entry.
+        let (num_entries, num_bytes) = self.get_usage_speculative(&mapping_id)?;
+        let usage = match &old_value {
+            Some(old_value) => {
+                let old_size = size_in_bytes(&key, old_value)?;
+                (num_entries, num_bytes.saturating_sub(old_size).saturating_add(size_in_bytes(&key, &value)?))
+            }
+            None => (num_entries.saturating_add(1), num_bytes.saturating_add(size_in_bytes(&key, &value)?)),
+        };
+
         atomic_batch_scope!(self, {
             // Update the key-value ID map with the new key-value ID.
             self.key_value_id_map().insert(mapping_id, key_value_ids)?;
+            // Record the key-value pair in the history.
+            #[cfg(feature = "history")]
+            self.history_map().insert(value_id, (*program_id, *mapping_name, key.clone(), value.clone()))?;
             // Insert the key.
             self.key_map().insert(key_id, key)?;
             // Insert the value.
             self.value_map().insert(key_id, value)?;
+            // Update the usage of the mapping.
+            self.usage_map().insert(mapping_id, usage)?;
 
             Ok(())
         })?;
@@ -293,6 +398,38 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         Ok(FinalizeOperation::UpdateKeyValue(mapping_id, index, key_id, value_id))
     }
 
+    /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage,
+    /// and returns a finalize operation that commits to the sizes of the entries in bytes.
+    /// If the `mapping name` is not initialized, an error is returned.
+    /// If the `key` does not exist, the `(key, value)` pair is initialized, and an `InsertSizedKeyValue` is returned.
+    /// If the `key` already exists, the `value` is overwritten, and an `UpdateSizedKeyValue` is returned.
+    fn update_sized_key_value(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: Plaintext<N>,
+        value: Value<N>,
+    ) -> Result<FinalizeOperation<N>> {
+        // Compute the size of the current entry, if the key exists.
+        let previous_size = match self.get_value_speculative(program_id, mapping_name, &key)? {
+            Some(previous_value) => Some(size_in_bytes(&key, &previous_value)?),
+            None => None,
+        };
+        // Compute the size of the new entry.
+        let size = size_in_bytes(&key, &value)?;
+
+        // Update the key-value pair, and add the sizes to the finalize operation.
+        match (self.update_key_value(program_id, mapping_name, key, value)?, previous_size) {
+            (FinalizeOperation::UpdateKeyValue(mapping_id, index, key_id, value_id), Some(previous_size)) => {
+                Ok(FinalizeOperation::UpdateSizedKeyValue(mapping_id, index, key_id, value_id, previous_size, size))
+            }
+            (FinalizeOperation::UpdateKeyValue(mapping_id, _, key_id, value_id), None) => {
+                Ok(FinalizeOperation::InsertSizedKeyValue(mapping_id, key_id, value_id, size))
+            }
+            (operation, _) => bail!("Illegal operation: expected an update of a key-value, found '{operation}'"),
+        }
+    }
+
     /// Removes the key-value pair for the given `program ID`, `mapping name`, and `key` from storage.
     fn remove_key_value(
         &self,
@@ -326,6 +463,15 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         // Remove the key ID.
         key_value_ids.remove(&key_id);
 
+        // Retrieve the value for the key ID.
+        let value = match self.get_value_from_key_id_speculative(&key_id)? {
+            Some(value) => value,
+            None => bail!("Illegal operation: key ID '{key_id}' does not have a value in storage - cannot remove."),
+        };
+        // Retrieve the usage of the mapping, and release the removed entry.
+        let (num_entries, num_bytes) = self.get_usage_speculative(&mapping_id)?;
+        let usage = (num_entries.saturating_sub(1), num_bytes.saturating_sub(size_in_bytes(key, &value)?));
+
         atomic_batch_scope!(self, {
             // Update the key-value ID map with the new key ID.
             self.key_value_id_map().insert(mapping_id, key_value_ids)?;
@@ -333,6 +479,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             self.key_map().remove(&key_id)?;
             // Remove the value.
             self.value_map().remove(&key_id)?;
+            // Update the usage of the mapping.
+            self.usage_map().insert(mapping_id, usage)?;
 
             Ok(())
         })?;
@@ -341,6 +489,29 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         Ok(FinalizeOperation::RemoveKeyValue(mapping_id, index))
     }
 
+    /// Removes the key-value pair for the given `program ID`, `mapping name`, and `key` from storage,
+    /// and returns a `RemoveSizedKeyValue` that commits to the size of the removed entry in bytes.
+    fn remove_sized_key_value(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<FinalizeOperation<N>> {
+        // Compute the size of the entry.
+        let size = match self.get_value_speculative(program_id, mapping_name, key)? {
+            Some(value) => size_in_bytes(key, &value)?,
+            None => bail!("Illegal operation: key '{key}' does not exist in mapping '{mapping_name}' - cannot remove."),
+        };
+
+        // Remove the key-value pair, and add the size to the finalize operation.
+        match self.remove_key_value(program_id, mapping_name, key)? {
+            FinalizeOperation::RemoveKeyValue(mapping_id, index) => {
+                Ok(FinalizeOperation::RemoveSizedKeyValue(mapping_id, index, size))
+            }
+            operation => bail!("Illegal operation: expected a removal of a key-value, found '{operation}'"),
+        }
+    }
+
     /// Removes the mapping for the given `program ID` and `mapping name` from storage,
     /// along with all associated key-value pairs in storage.
     fn remove_mapping(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<FinalizeOperation<N>> {
@@ -379,6 +550,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.key_map().remove(key_id)?;
                 self.value_map().remove(key_id)?;
             }
+            // Remove the usage of the mapping.
+            self.usage_map().remove(&mapping_id)?;
 
             Ok(())
         })?;
@@ -428,12 +601,121 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
                     self.key_map().remove(key_id)?;
                     self.value_map().remove(key_id)?;
                 }
+                // Remove the usage of the mapping.
+                self.usage_map().remove(&mapping_id)?;
             }
 
             Ok(())
         })
     }
 
+    /// Stores the finalize diff for the given `transaction ID`, by replaying its `finalize operations`
+    /// over the given key-value IDs of each mapping, which are updated in place.
+    /// Mappings missing from the key-value IDs are loaded from their confirmed state, so the same
+    /// key-value IDs must be used for all transactions in a batch, in the order they are finalized.
+    #[cfg(feature = "history")]
+    fn insert_diff(
+        &self,
+        transaction_id: N::TransactionID,
+        finalize_operations: &[FinalizeOperation<N>],
+        key_value_ids: &mut IndexMap<Field<N>, IndexMap<Field<N>, Field<N>>>,
+    ) -> Result<()> {
+        // Initialize a list of `(old value ID, new value ID)` pairs.
+        let mut diff_ids = Vec::new();
+
+        for operation in finalize_operations {
+            match *operation {
+                FinalizeOperation::InitializeMapping(mapping_id) => {
+                    // Initialize the key-value IDs for the mapping ID.
+                    key_value_ids.insert(mapping_id, IndexMap::new());
+                }
+                FinalizeOperation::InsertKeyValue(mapping_id, key_id, value_id)
+                | FinalizeOperation::UpdateKeyValue(mapping_id, _, key_id, value_id)
+                | FinalizeOperation::InsertSizedKeyValue(mapping_id, key_id, value_id, _)
+                | FinalizeOperation::UpdateSizedKeyValue(mapping_id, _, key_id, value_id, ..) => {
+                    // Update the key-value ID, and retrieve the previous value ID (if any).
+                    let old_value_id = load_key_value_ids(self, key_value_ids, mapping_id)?.insert(key_id, value_id);
+                    diff_ids.push((old_value_id, Some(value_id)));
+                }
+                FinalizeOperation::RemoveKeyValue(mapping_id, index)
+                | FinalizeOperation::RemoveSizedKeyValue(mapping_id, index, _) => {
+                    // Retrieve the key-value IDs for the mapping ID.
+                    let mapping_key_value_ids = load_key_value_ids(self, key_value_ids, mapping_id)?;
+                    // Retrieve the key ID at the given index.
+                    let key_id = match mapping_key_value_ids.get_index(usize::try_from(index)?) {
+                        Some((key_id, _)) => *key_id,
+                        None => bail!("Illegal operation: index '{index}' does not exist in mapping ID '{mapping_id}'"),
+                    };
+                    // Remove the key ID, and retrieve the removed value ID.
+                    let old_value_id = mapping_key_value_ids.remove(&key_id);
+                    diff_ids.push((old_value_id, None));
+                }
+                FinalizeOperation::RemoveMapping(mapping_id) => {
+                    // Ensure the key-value IDs for the mapping ID are loaded.
+                    load_key_value_ids(self, key_value_ids, mapping_id)?;
+                    // Remove the key-value IDs for the mapping ID, as every entry of the mapping is removed.
+                    if let Some(mapping_key_value_ids) = key_value_ids.remove(&mapping_id) {
+                        diff_ids.extend(mapping_key_value_ids.values().map(|value_id| (Some(*value_id), None)));
+                    }
+                }
+            }
+        }
+
+        // Store the diff IDs.
+        self.diff_id_map().insert(transaction_id, diff_ids)
+    }
+
+    /// Returns the finalize diff for the given `transaction ID`.
+    #[cfg(feature = "history")]
+    fn get_diff(&self, transaction_id: &N::TransactionID) -> Result<Option<Vec<FinalizeDiff<N>>>> {
+        // Retrieve the diff IDs.
+        let diff_ids = match self.diff_id_map().get_confirmed(transaction_id)? {
+            Some(diff_ids) => cow_to_cloned!(diff_ids),
+            None => return Ok(None),
+        };
+
+        // Resolve each `(old value ID, new value ID)` pair into a finalize diff.
+        diff_ids
+            .into_iter()
+            .map(|(old_value_id, new_value_id)| {
+                // Retrieve the old and new entries from the history.
+                let get_entry = |value_id: Field<N>| -> Result<_> {
+                    match self.get_history_confirmed(&value_id)? {
+                        Some(entry) => Ok(entry),
+                        None => bail!("Missing history for value ID '{value_id}' in transaction '{transaction_id}'"),
+                    }
+                };
+                let old_entry = old_value_id.map(get_entry).transpose()?;
+                let new_entry = new_value_id.map(get_entry).transpose()?;
+                match (old_entry, new_entry) {
+                    (None, Some((program_id, mapping_name, key, new_value))) => {
+                        FinalizeDiff::new(program_id, mapping_name, key, None, Some(new_value))
+                    }
+                    (Some((program_id, mapping_name, key, old_value)), None) => {
+                        FinalizeDiff::new(program_id, mapping_name, key, Some(old_value), None)
+                    }
+                    (Some((_, _, _, old_value)), Some((program_id, mapping_name, key, new_value))) => {
+                        FinalizeDiff::new(program_id, mapping_name, key, Some(old_value), Some(new_value))
+                    }
+                    (None, None) => bail!("Missing value IDs in the finalize diff for transaction '{transaction_id}'"),
+                }
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(Some)
+    }
+
+    /// Returns the confirmed `(program ID, mapping name, key, value)` for the given `value ID`.
+    #[cfg(feature = "history")]
+    fn get_history_confirmed(
+        &self,
+        value_id: &Field<N>,
+    ) -> Result<Option<(ProgramID<N>, Identifier<N>, Plaintext<N>, Value<N>)>> {
+        match self.history_map().get_confirmed(value_id)? {
+            Some(entry) => Ok(Some(cow_to_cloned!(entry))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns `true` if the given `program ID` exist.
     fn contains_program_confirmed(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.program_id_map().contains_key_confirmed(program_id)
@@ -617,6 +899,76 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             None => Ok(None),
         }
     }
+
+    /// Returns the confirmed `(number of entries, size in bytes)` for the given `program ID` and `mapping name`.
+    fn get_mapping_usage_confirmed(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<Option<(u64, u64)>> {
+        // Retrieve the mapping ID.
+        let mapping_id = match self.get_mapping_id_confirmed(program_id, mapping_name)? {
+            Some(mapping_id) => mapping_id,
+            None => return Ok(None),
+        };
+        // Retrieve the usage of the mapping.
+        match self.usage_map().get_confirmed(&mapping_id)? {
+            Some(usage) => Ok(Some(cow_to_copied!(usage))),
+            None => Ok(Some((0, 0))),
+        }
+    }
+
+    /// Returns the confirmed `(number of entries, size in bytes)` across all mappings of the given `program ID`.
+    fn get_program_usage_confirmed(&self, program_id: &ProgramID<N>) -> Result<Option<(u64, u64)>> {
+        // Retrieve the mapping names.
+        let mapping_names = match self.get_mapping_names_confirmed(program_id)? {
+            Some(mapping_names) => mapping_names,
+            None => return Ok(None),
+        };
+        // Sum the usage of each mapping.
+        let mut usage = (0u64, 0u64);
+        for mapping_name in mapping_names.iter() {
+            if let Some((num_entries, num_bytes)) = self.get_mapping_usage_confirmed(program_id, mapping_name)? {
+                usage = (usage.0.saturating_add(num_entries), usage.1.saturating_add(num_bytes));
+            }
+        }
+        Ok(Some(usage))
+    }
+
+    /// Returns the speculative `(number of entries, size in bytes)` for the given `mapping ID`.
+    fn get_usage_speculative(&self, mapping_id: &Field<N>) -> Result<(u64, u64)> {
+        match self.usage_map().get_speculative(mapping_id)? {
+            Some(usage) => Ok(cow_to_copied!(usage)),
+            None => Ok((0, 0)),
+        }
+    }
+}
+
+/// Returns the number of bytes the given `(key, value)` pair occupies in storage.
+fn size_in_bytes<N: Network>(key: &Plaintext<N>, value: &Value<N>) -> Result<u64> {
+    Ok(u64::try_from(key.to_bytes_le()?.len().saturating_add(value.to_bytes_le()?.len()))?)
+}
+
+/// Returns the key-value IDs for the given `mapping ID` from the given key-value IDs,
+/// loading them from the confirmed state of the storage if they are not present.
+#[cfg(feature = "history")]
+fn load_key_value_ids<'a, N: Network, S: FinalizeStorage<N> + ?Sized>(
+    storage: &S,
+    key_value_ids: &'a mut IndexMap<Field<N>, IndexMap<Field<N>, Field<N>>>,
+    mapping_id: Field<N>,
+) -> Result<&'a mut IndexMap<Field<N>, Field<N>>> {
+    if !key_value_ids.contains_key(&mapping_id) {
+        // Retrieve the confirmed key-value IDs for the mapping ID.
+        let mapping_key_value_ids = match storage.key_value_id_map().get_confirmed(&mapping_id)? {
+            Some(mapping_key_value_ids) => cow_to_cloned!(mapping_key_value_ids),
+            None => bail!("Illegal operation: mapping ID '{mapping_id}' is not initialized - cannot replay finalize."),
+        };
+        key_value_ids.insert(mapping_id, mapping_key_value_ids);
+    }
+    match key_value_ids.get_mut(&mapping_id) {
+        Some(mapping_key_value_ids) => Ok(mapping_key_value_ids),
+        None => bail!("Illegal operation: mapping ID '{mapping_id}' is not initialized - cannot replay finalize."),
+    }
 }
 
 /// The finalize store.
@@ -636,6 +988,8 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 
     /// Initializes a finalize store from storage.
     pub fn from(storage: P) -> Result<Self> {
+        // Initialize the usage of any mapping stored before its usage was tracked.
+        storage.initialize_usage()?;
         // Return the finalize store.
         Ok(Self { storage, _phantom: PhantomData })
     }
@@ -734,6 +1088,21 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         self.storage.update_key_value(program_id, mapping_name, key, value)
     }
 
+    /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage,
+    /// and returns a finalize operation that commits to the sizes of the entries in bytes.
+    /// If the `mapping name` is not initialized, an error is returned.
+    /// If the `key` does not exist, the `(key, value)` pair is initialized, and an `InsertSizedKeyValue` is returned.
+    /// If the `key` already exists, the `value` is overwritten, and an `UpdateSizedKeyValue` is returned.
+    fn update_sized_key_value(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: Plaintext<N>,
+        value: Value<N>,
+    ) -> Result<FinalizeOperation<N>> {
+        self.storage.update_sized_key_value(program_id, mapping_name, key, value)
+    }
+
     /// Removes the key-value pair for the given `program ID`, `mapping name`, and `key` from storage.
     fn remove_key_value(
         &self,
@@ -743,6 +1112,17 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
     ) -> Result<FinalizeOperation<N>> {
         self.storage.remove_key_value(program_id, mapping_name, key)
     }
+
+    /// Removes the key-value pair for the given `program ID`, `mapping name`, and `key` from storage,
+    /// and returns a `RemoveSizedKeyValue` that commits to the size of the removed entry in bytes.
+    fn remove_sized_key_value(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<FinalizeOperation<N>> {
+        self.storage.remove_sized_key_value(program_id, mapping_name, key)
+    }
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
@@ -771,6 +1151,18 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     pub fn remove_program(&self, program_id: &ProgramID<N>) -> Result<()> {
         self.storage.remove_program(program_id)
     }
+
+    /// Stores the finalize diff for the given `transaction ID`, by replaying its `finalize operations`
+    /// over the given key-value IDs of each mapping, which are updated in place.
+    #[cfg(feature = "history")]
+    pub fn insert_diff(
+        &self,
+        transaction_id: N::TransactionID,
+        finalize_operations: &[FinalizeOperation<N>],
+        key_value_ids: &mut IndexMap<Field<N>, IndexMap<Field<N>, Field<N>>>,
+    ) -> Result<()> {
+        self.storage.insert_diff(transaction_id, finalize_operations, key_value_ids)
+    }
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
@@ -811,6 +1203,26 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     ) -> Result<Option<Value<N>>> {
         self.storage.get_value_confirmed(program_id, mapping_name, key)
     }
+
+    /// Returns the finalize diff for the given `transaction ID`.
+    #[cfg(feature = "history")]
+    pub fn get_diff(&self, transaction_id: &N::TransactionID) -> Result<Option<Vec<FinalizeDiff<N>>>> {
+        self.storage.get_diff(transaction_id)
+    }
+
+    /// Returns the confirmed `(number of entries, size in bytes)` for the given `program ID` and `mapping name`.
+    pub fn get_mapping_usage_confirmed(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<Option<(u64, u64)>> {
+        self.storage.get_mapping_usage_confirmed(program_id, mapping_name)
+    }
+
+    /// Returns the confirmed `(number of entries, size in bytes)` across all mappings of the given `program ID`.
+    pub fn get_program_usage_confirmed(&self, program_id: &ProgramID<N>) -> Result<Option<(u64, u64)>> {
+        self.storage.get_program_usage_confirmed(program_id)
+    }
 }
 
 #[cfg(test)]
@@ -1293,4 +1705,175 @@ mod tests {
         check_initialize_insert_remove(&finalize_store, program_id, mapping_name);
         check_initialize_update_remove(&finalize_store, program_id, mapping_name);
     }
+
+    #[test]
+    #[cfg(feature = "history")]
+    fn test_insert_get_diff() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Initialize the keys and values.
+        let (key_1, key_2) = (Plaintext::from_str("1field").unwrap(), Plaintext::from_str("2field").unwrap());
+        let (value_1, value_2) = (Value::from_str("1u64").unwrap(), Value::from_str("2u64").unwrap());
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+
+        // Perform the finalize operations.
+        let finalize_operations = vec![
+            finalize_store.initialize_mapping(&program_id, &mapping_name).unwrap(),
+            finalize_store.insert_key_value(&program_id, &mapping_name, key_1.clone(), value_1.clone()).unwrap(),
+            finalize_store.update_key_value(&program_id, &mapping_name, key_1.clone(), value_2.clone()).unwrap(),
+            finalize_store.insert_key_value(&program_id, &mapping_name, key_2.clone(), value_1.clone()).unwrap(),
+            finalize_store.remove_key_value(&program_id, &mapping_name, &key_1).unwrap(),
+            finalize_store.remove_mapping(&program_id, &mapping_name).unwrap(),
+        ];
+
+        // Store the finalize diff.
+        let transaction_id = rng.gen();
+        assert!(finalize_store.get_diff(&transaction_id).unwrap().is_none());
+        finalize_store.insert_diff(transaction_id, &finalize_operations, &mut IndexMap::new()).unwrap();
+
+        // Ensure the finalize diff resolves the old and new values.
+        let diff = |key: &Plaintext<CurrentNetwork>, old_value: Option<&Value<_>>, new_value: Option<&Value<_>>| {
+            FinalizeDiff::new(program_id, mapping_name, key.clone(), old_value.cloned(), new_value.cloned()).unwrap()
+        };
+        let expected = vec![
+            diff(&key_1, None, Some(&value_1)),
+            diff(&key_1, Some(&value_1), Some(&value_2)),
+            diff(&key_2, None, Some(&value_1)),
+            diff(&key_1, Some(&value_2), None),
+            diff(&key_2, Some(&value_1), None),
+        ];
+        assert_eq!(finalize_store.get_diff(&transaction_id).unwrap(), Some(expected));
+    }
+
+    #[test]
+    #[cfg(not(feature = "history"))]
+    fn test_history_disabled() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Initialize the key and values.
+        let key = Plaintext::from_str("1field").unwrap();
+        let (value_1, value_2) = (Value::from_str("1u64").unwrap(), Value::from_str("2u64").unwrap());
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+
+        // Insert and update a key-value pair.
+        finalize_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        finalize_store.insert_key_value(&program_id, &mapping_name, key.clone(), value_1).unwrap();
+        finalize_store.update_key_value(&program_id, &mapping_name, key, value_2).unwrap();
+
+        // Ensure the previous values are not kept.
+        assert_eq!(finalize_store.storage.history_map().iter_confirmed().count(), 0);
+    }
+
+    #[test]
+    fn test_mapping_usage() {
+        // Initialize a program ID and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let (mapping_1, mapping_2) =
+            (Identifier::from_str("account").unwrap(), Identifier::from_str("supply").unwrap());
+        // Initialize the keys and values.
+        let (key_1, key_2) = (Plaintext::from_str("1field").unwrap(), Plaintext::from_str("2field").unwrap());
+        let (value_1, value_2) = (Value::from_str("1u64").unwrap(), Value::from_str("2u128").unwrap());
+        // Compute the size of each entry.
+        let size = |key: &Plaintext<CurrentNetwork>, value: &Value<CurrentNetwork>| size_in_bytes(key, value).unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+
+        // Ensure the usage of an unknown program or mapping is `None`.
+        assert_eq!(finalize_store.get_program_usage_confirmed(&program_id).unwrap(), None);
+        assert_eq!(finalize_store.get_mapping_usage_confirmed(&program_id, &mapping_1).unwrap(), None);
+
+        // Initialize the mappings.
+        finalize_store.initialize_mapping(&program_id, &mapping_1).unwrap();
+        finalize_store.initialize_mapping(&program_id, &mapping_2).unwrap();
+        assert_eq!(finalize_store.get_mapping_usage_confirmed(&program_id, &mapping_1).unwrap(), Some((0, 0)));
+        assert_eq!(finalize_store.get_program_usage_confirmed(&program_id).unwrap(), Some((0, 0)));
+
+        // Ensure `update_key_value` on a new key is still an update, and is accounted as a new entry.
+        let operation =
+            finalize_store.update_key_value(&program_id, &mapping_1, key_1.clone(), value_1.clone()).unwrap();
+        assert!(matches!(operation, FinalizeOperation::UpdateKeyValue(..)));
+        let operation =
+            finalize_store.insert_key_value(&program_id, &mapping_1, key_2.clone(), value_1.clone()).unwrap();
+        assert!(matches!(operation, FinalizeOperation::InsertKeyValue(..)));
+        let expected = (2, size(&key_1, &value_1) + size(&key_2, &value_1));
+        assert_eq!(finalize_store.get_mapping_usage_confirmed(&program_id, &mapping_1).unwrap(), Some(expected));
+
+        // Ensure `update_sized_key_value` on an existing key commits to the previous and new sizes,
+        // and only accounts for the change in size.
+        let operation =
+            finalize_store.update_sized_key_value(&program_id, &mapping_1, key_1.clone(), value_2.clone()).unwrap();
+        let (previous_size, new_size) = (size(&key_1, &value_1), size(&key_1, &value_2));
+        let sizes = match operation {
+            FinalizeOperation::UpdateSizedKeyValue(_, 0, _, _, previous_size, size) => (previous_size, size),
+            operation => panic!("Expected an update of the first entry, found '{operation}'"),
+        };
+        assert_eq!(sizes, (previous_size, new_size));
+        let expected = (2, size(&key_1, &value_2) + size(&key_2, &value_1));
+        assert_eq!(finalize_store.get_mapping_usage_confirmed(&program_id, &mapping_1).unwrap(), Some(expected));
+
+        // Ensure `update_sized_key_value` on a new key is an insertion that commits to its size.
+        let operation =
+            finalize_store.update_sized_key_value(&program_id, &mapping_2, key_1.clone(), value_1.clone()).unwrap();
+        assert!(matches!(operation, FinalizeOperation::InsertSizedKeyValue(.., size) if size == previous_size));
+
+        // Ensure the program usage sums the usage of each mapping.
+        let expected = (3, size(&key_1, &value_2) + size(&key_2, &value_1) + size(&key_1, &value_1));
+        assert_eq!(finalize_store.get_program_usage_confirmed(&program_id).unwrap(), Some(expected));
+
+        // Ensure removing a key releases its entry, and commits to its size.
+        let operation = finalize_store.remove_sized_key_value(&program_id, &mapping_1, &key_1).unwrap();
+        assert!(matches!(operation, FinalizeOperation::RemoveSizedKeyValue(_, 0, size) if size == new_size));
+        let expected = (1, size(&key_2, &value_1));
+        assert_eq!(finalize_store.get_mapping_usage_confirmed(&program_id, &mapping_1).unwrap(), Some(expected));
+
+        // Ensure removing a mapping releases all of its entries.
+        finalize_store.remove_mapping(&program_id, &mapping_1).unwrap();
+        assert_eq!(finalize_store.get_mapping_usage_confirmed(&program_id, &mapping_1).unwrap(), None);
+        let expected = (1, size(&key_1, &value_1));
+        assert_eq!(finalize_store.get_program_usage_confirmed(&program_id).unwrap(), Some(expected));
+
+        // Ensure removing the program releases all of its mappings.
+        finalize_store.remove_program(&program_id).unwrap();
+        assert_eq!(finalize_store.get_program_usage_confirmed(&program_id).unwrap(), None);
+    }
+
+    #[test]
+    fn test_initialize_usage() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Initialize the keys and values.
+        let (key_1, key_2) = (Plaintext::from_str("1field").unwrap(), Plaintext::from_str("2field").unwrap());
+        let (value_1, value_2) = (Value::from_str("1u64").unwrap(), Value::from_str("2u128").unwrap());
+
+        // Initialize a new finalize store, and store two entries.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        finalize_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        finalize_store.insert_key_value(&program_id, &mapping_name, key_1.clone(), value_1.clone()).unwrap();
+        finalize_store.insert_key_value(&program_id, &mapping_name, key_2.clone(), value_2.clone()).unwrap();
+        let expected = (2, size_in_bytes(&key_1, &value_1).unwrap() + size_in_bytes(&key_2, &value_2).unwrap());
+        assert_eq!(finalize_store.get_mapping_usage_confirmed(&program_id, &mapping_name).unwrap(), Some(expected));
+
+        // Remove the usage, as in storage written before the usage was tracked.
+        let mapping_id = finalize_store.storage.get_mapping_id_confirmed(&program_id, &mapping_name).unwrap().unwrap();
+        finalize_store.storage.usage_map().remove(&mapping_id).unwrap();
+        assert!(!finalize_store.storage.usage_map().contains_key_confirmed(&mapping_id).unwrap());
+
+        // Ensure re-opening the finalize store computes the usage from the stored entries.
+        let finalize_store = FinalizeStore::from(finalize_store.storage).unwrap();
+        assert_eq!(finalize_store.get_mapping_usage_confirmed(&program_id, &mapping_name).unwrap(), Some(expected));
+    }
 }
@@ -28,4 +28,122 @@ impl<N: Network> Process<N> {
         // Authorize the call.
         self.get_stack(program_id)?.authorize::<A, R>(private_key, function_name, inputs, rng)
     }
+
+    /// Authorizes the fee given the credits record, the fee amount (in microcredits),
+    /// and the deployment or execution ID.
+    #[inline]
+    pub fn authorize_fee<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        credits: Record<N, Plaintext<N>>,
+        fee_in_microcredits: u64,
+        deployment_or_execution_id: Field<N>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        // Prepare the fee in microcredits.
+        let fee_in_microcredits = Value::from_str(&U64::<N>::new(fee_in_microcredits).to_string())?;
+        // Prepare the deployment or execution ID.
+        let deployment_or_execution_id = Value::from(Literal::Field(deployment_or_execution_id));
+        // Construct the inputs.
+        let inputs = [Value::Record(credits), fee_in_microcredits, deployment_or_execution_id];
+        // Authorize the call to 'credits.aleo/fee'.
+        self.authorize::<A, R>(private_key, "credits.aleo", "fee", inputs.into_iter(), rng)
+    }
+
+    /// Authorizes the public fee given the fee amount (in microcredits) and the deployment or execution ID.
+    /// The fee is charged from the caller's balance in the `credits.aleo/account` mapping.
+    #[inline]
+    pub fn authorize_fee_public<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        fee_in_microcredits: u64,
+        deployment_or_execution_id: Field<N>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        // Prepare the fee in microcredits.
+        let fee_in_microcredits = Value::from_str(&U64::<N>::new(fee_in_microcredits).to_string())?;
+        // Prepare the deployment or execution ID.
+        let deployment_or_execution_id = Value::from(Literal::Field(deployment_or_execution_id));
+        // Construct the inputs.
+        let inputs = [fee_in_microcredits, deployment_or_execution_id];
+        // Authorize the call to 'credits.aleo/fee_public'.
+        self.authorize::<A, R>(private_key, "credits.aleo", "fee_public", inputs.into_iter(), rng)
+    }
+
+    /// Verifies the given authorization is well-formed, before it is executed.
+    ///
+    /// This checks that every request is signed by the same caller for this network, that every
+    /// request is valid for the input types of its function, and that the requests follow the call
+    /// graph of the first request, in the order they are executed.
+    #[inline]
+    pub fn verify_authorization(&self, authorization: &Authorization<N>) -> Result<()> {
+        // Retrieve the requests.
+        let requests = authorization.to_vec_deque();
+        // Retrieve the main request.
+        let Some(main_request) = requests.front() else { bail!("The authorization is empty") };
+
+        // Compute the expected sequence of calls.
+        let mut expected_calls = Vec::with_capacity(requests.len());
+        Self::append_calls(
+            self.get_stack(main_request.program_id())?,
+            main_request.function_name(),
+            requests.len(),
+            &mut expected_calls,
+        )?;
+        // Ensure the requests follow the call graph.
+        ensure!(
+            expected_calls.len() == requests.len(),
+            "Expected {} requests in the authorization, found {}",
+            expected_calls.len(),
+            requests.len()
+        );
+
+        for (index, (request, (program_id, function_name))) in requests.iter().zip_eq(&expected_calls).enumerate() {
+            // Ensure the request is for the expected function.
+            ensure!(
+                request.program_id() == program_id && request.function_name() == function_name,
+                "Request {index} is for '{}/{}', expected '{program_id}/{function_name}'",
+                request.program_id(),
+                request.function_name()
+            );
+            // Ensure the request is for this network.
+            ensure!(**request.network_id() == N::ID, "Request {index} is for network {}", request.network_id());
+            // Ensure the request is signed by the caller of the main request.
+            ensure!(request.caller() == main_request.caller(), "Request {index} is signed by a different caller");
+            // Ensure the request is valid.
+            let input_types = self.get_stack(program_id)?.get_function(function_name)?.input_types();
+            ensure!(request.verify(&input_types), "Request {index} ('{program_id}/{function_name}') is invalid");
+        }
+        Ok(())
+    }
+
+    /// Appends the given function and the functions it calls to `calls`, in the order their requests are authorized.
+    fn append_calls(
+        stack: &Stack<N>,
+        function_name: &Identifier<N>,
+        max_calls: usize,
+        calls: &mut Vec<(ProgramID<N>, Identifier<N>)>,
+    ) -> Result<()> {
+        // Ensure the number of calls does not exceed the number of requests.
+        ensure!(calls.len() < max_calls, "The authorization is missing requests for '{}'", stack.program_id());
+        // Append the function.
+        calls.push((*stack.program_id(), *function_name));
+        // Append the functions it calls.
+        for instruction in stack.get_function(function_name)?.instructions() {
+            if let Instruction::Call(call) = instruction {
+                if call.is_function_call(stack)? {
+                    match call.operator() {
+                        CallOperator::Locator(locator) => Self::append_calls(
+                            stack.get_external_stack(locator.program_id())?,
+                            locator.resource(),
+                            max_calls,
+                            calls,
+                        )?,
+                        CallOperator::Resource(resource) => Self::append_calls(stack, resource, max_calls, calls)?,
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
 }
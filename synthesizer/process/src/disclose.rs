@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::account::ViewKey;
+use ledger_block::TransitionDisclosure;
+
+/// The assignment of the circuit that proves a disclosed value key is derived from the transition view key.
+#[derive(Clone, Debug)]
+pub struct DisclosureAssignment<N: Network> {
+    tvk: Field<N>,
+    tcm: Field<N>,
+    function_id: Field<N>,
+    index: u16,
+    key: Field<N>,
+}
+
+impl<N: Network> DisclosureAssignment<N> {
+    /// Initializes a new disclosure assignment.
+    pub const fn new(tvk: Field<N>, tcm: Field<N>, function_id: Field<N>, index: u16, key: Field<N>) -> Self {
+        Self { tvk, tcm, function_id, index, key }
+    }
+
+    /// The circuit for the derivation of a disclosed value key.
+    ///
+    /// # Diagram
+    /// The `[[ ]]` notation is used to denote public inputs.
+    /// ```ignore
+    ///   [[ tcm ]] := Hash( tvk )
+    ///   [[ key ]] := Hash( [[ function_id ]] || tvk || [[ index ]] )
+    /// ```
+    pub fn to_circuit_assignment<A: circuit::Aleo<Network = N>>(&self) -> Result<circuit::Assignment<N::Field>> {
+        use circuit::Inject;
+
+        // Ensure the circuit environment is clean.
+        assert_eq!(A::count(), (0, 1, 0, 0, (0, 0, 0)));
+        A::reset();
+
+        // Inject the transition commitment `tcm` as `Mode::Public`.
+        let tcm = circuit::Field::<A>::new(circuit::Mode::Public, self.tcm);
+        // Inject the function ID as `Mode::Public`.
+        let function_id = circuit::Field::<A>::new(circuit::Mode::Public, self.function_id);
+        // Inject the index as `Mode::Public`.
+        let index = circuit::Field::<A>::new(circuit::Mode::Public, Field::from_u16(self.index));
+        // Inject the key as `Mode::Public`.
+        let key = circuit::Field::<A>::new(circuit::Mode::Public, self.key);
+        // Inject the transition view key `tvk` as `Mode::Private`.
+        let tvk = circuit::Field::<A>::new(circuit::Mode::Private, self.tvk);
+
+        // Enforce the transition commitment is `Hash(tvk)`.
+        A::assert_eq(A::hash_psd2(&[tvk.clone()]), tcm);
+        // Enforce the key is `Hash(function_id || tvk || index)`.
+        A::assert_eq(A::hash_psd4(&[function_id, tvk, index]), key);
+
+        #[cfg(debug_assertions)]
+        Stack::log_circuit::<A, _>(&format!("Disclosure for value {}", self.index));
+
+        // Eject the assignment and reset the circuit environment.
+        Ok(A::eject_assignment_and_reset())
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Synthesizes and returns the `(proving_key, verifying_key)` of the disclosure circuit.
+    #[inline]
+    pub fn disclosure_keys<A: circuit::Aleo<Network = N>>(&self) -> Result<(ProvingKey<N>, VerifyingKey<N>)> {
+        // Construct a sample assignment, as the circuit does not depend on the assigned values.
+        let (tvk, function_id) = (Field::one(), Field::zero());
+        let tcm = N::hash_psd2(&[tvk])?;
+        let key = N::hash_psd4(&[function_id, tvk, Field::from_u16(0)])?;
+        let assignment = DisclosureAssignment::new(tvk, tcm, function_id, 0, key).to_circuit_assignment::<A>()?;
+        // Synthesize the proving and verifying key.
+        self.universal_srs.to_circuit_key(TransitionDisclosure::<N>::CIRCUIT_NAME, &assignment)
+    }
+
+    /// Returns a disclosure of the given transition, revealing the private inputs and outputs,
+    /// and the records at the given output indices, with a proof that each value key is derived
+    /// from the transition view key.
+    #[inline]
+    pub fn disclose<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        transition: &Transition<N>,
+        view_key: &ViewKey<N>,
+        record_indices: &[usize],
+        rng: &mut R,
+    ) -> Result<TransitionDisclosure<N>> {
+        let timer = timer!("Process::disclose");
+
+        let disclosure = transition.disclose(
+            view_key,
+            record_indices,
+            |tvk, values, rng| {
+                // Synthesize the proving key.
+                let (proving_key, _) = self.disclosure_keys::<A>()?;
+                lap!(timer, "Synthesize the proving key");
+
+                // Compute the function ID.
+                let function_id = transition.function_id()?;
+                // Construct the assignment for each value key.
+                let assignments = values
+                    .iter()
+                    .map(|(index, key)| {
+                        DisclosureAssignment::new(tvk, *transition.tcm(), function_id, *index, *key)
+                            .to_circuit_assignment::<A>()
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                lap!(timer, "Construct the assignments");
+
+                // Prove the value keys.
+                ProvingKey::prove_batch(TransitionDisclosure::<N>::CIRCUIT_NAME, &[(proving_key, assignments)], rng)
+            },
+            rng,
+        )?;
+
+        finish!(timer);
+        Ok(disclosure)
+    }
+
+    /// Verifies the given disclosure belongs to the given transition.
+    #[inline]
+    pub fn verify_disclosure<A: circuit::Aleo<Network = N>>(
+        &self,
+        transition: &Transition<N>,
+        disclosure: &TransitionDisclosure<N>,
+    ) -> Result<()> {
+        // Synthesize the verifying key.
+        let (_, verifying_key) = self.disclosure_keys::<A>()?;
+        // Verify the disclosure.
+        disclosure.verify(transition, &verifying_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::prelude::TestRng;
+
+    type CurrentNetwork = console::network::Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_disclosure_keys() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transition view key and function ID.
+        let tvk = Field::<CurrentNetwork>::rand(rng);
+        let function_id = Field::rand(rng);
+        let tcm = CurrentNetwork::hash_psd2(&[tvk]).unwrap();
+        let key = |index: u16| CurrentNetwork::hash_psd4(&[function_id, tvk, Field::from_u16(index)]).unwrap();
+
+        // Construct the assignments for two value keys.
+        let assignments = [1, 3]
+            .map(|index| {
+                DisclosureAssignment::new(tvk, tcm, function_id, index, key(index))
+                    .to_circuit_assignment::<CurrentAleo>()
+                    .unwrap()
+            })
+            .to_vec();
+
+        // Prove the value keys.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        let (proving_key, verifying_key) = process.disclosure_keys::<CurrentAleo>().unwrap();
+        let proof = ProvingKey::prove_batch("disclosure", &[(proving_key, assignments)], rng).unwrap();
+
+        // Ensure the proof verifies, with the verifier inputs as `(1, tcm, function ID, index, key)`.
+        let inputs = |keys: [(u16, Field<CurrentNetwork>); 2]| {
+            let inputs = keys.map(|(index, key)| [Field::one(), tcm, function_id, Field::from_u16(index), key]);
+            vec![(verifying_key.clone(), inputs.map(|inputs| inputs.map(|field| *field).to_vec()).to_vec())]
+        };
+        assert!(VerifyingKey::verify_batch("disclosure", inputs([(1, key(1)), (3, key(3))]), &proof));
+        // Ensure the proof does not verify for a different key, or for a key at a different index.
+        assert!(!VerifyingKey::verify_batch("disclosure", inputs([(1, key(1)), (3, Field::rand(rng))]), &proof));
+        assert!(!VerifyingKey::verify_batch("disclosure", inputs([(1, key(1)), (2, key(3))]), &proof));
+    }
+}
@@ -26,36 +26,51 @@ impl<N: Network> Process<N> {
         deployment_or_execution_id: Field<N>,
         rng: &mut R,
     ) -> Result<(Response<N>, Transition<N>, Trace<N>)> {
-        let timer = timer!("Process::execute_fee");
+        // Authorize the fee.
+        let authorization =
+            self.authorize_fee::<A, R>(private_key, credits, fee_in_microcredits, deployment_or_execution_id, rng)?;
+        // Execute the fee.
+        self.execute_fee_authorization::<A>(authorization)
+    }
+
+    /// Executes the public fee given the fee amount (in microcredits) and the deployment or execution ID.
+    #[inline]
+    pub fn execute_fee_public<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        fee_in_microcredits: u64,
+        deployment_or_execution_id: Field<N>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Transition<N>, Trace<N>)> {
+        // Authorize the fee.
+        let authorization =
+            self.authorize_fee_public::<A, R>(private_key, fee_in_microcredits, deployment_or_execution_id, rng)?;
+        // Execute the fee.
+        self.execute_fee_authorization::<A>(authorization)
+    }
 
+    /// Executes the fee for the given fee authorization.
+    #[inline]
+    pub fn execute_fee_authorization<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+    ) -> Result<(Response<N>, Transition<N>, Trace<N>)> {
+        let timer = timer!("Process::execute_fee_authorization");
+
+        // Ensure the authorization contains exactly 1 request.
+        ensure!(authorization.len() == 1, "The fee authorization must contain exactly 1 request");
+        // Retrieve the request.
+        let request = authorization.peek_next()?;
         // Ensure the fee has the correct program ID.
         let program_id = ProgramID::from_str("credits.aleo")?;
         // Ensure the fee has the correct function.
-        let function_name = Identifier::from_str("fee")?;
-
-        // Retrieve the input types.
-        let input_types = self.get_program(program_id)?.get_function(&function_name)?.input_types();
-        // Prepare the fee in microcredits.
-        let fee_in_microcredits = Value::from_str(&U64::<N>::new(fee_in_microcredits).to_string())?;
-        // Prepare the deployment or execution ID.
-        let deployment_or_execution_id = Value::from(Literal::Field(deployment_or_execution_id));
-        // Construct the inputs.
-        let inputs = [Value::Record(credits), fee_in_microcredits, deployment_or_execution_id];
-        lap!(timer, "Construct the inputs");
-
-        // Compute the request.
-        let request = Request::sign(private_key, program_id, function_name, inputs.iter(), &input_types, rng)?;
-        lap!(timer, "Compute the request");
-
-        // Initialize the authorization.
-        let authorization = Authorization::new(&[request.clone()]);
-        lap!(timer, "Initialize the authorization");
-
-        // Construct the call stack.
-        let call_stack = CallStack::Authorize(vec![request], *private_key, authorization.clone());
-        // Construct the authorization from the function.
-        let _response = self.get_stack(program_id)?.execute_function::<A>(call_stack)?;
-        lap!(timer, "Construct the authorization from the function");
+        let function_name = *request.function_name();
+        let is_fee_function = ["fee", "fee_public"].contains(&function_name.to_string().as_str());
+        // Ensure the request is for the fee.
+        ensure!(
+            request.program_id() == &program_id && is_fee_function,
+            "The fee authorization must be for '{program_id}/fee' or '{program_id}/fee_public'"
+        );
 
         // Prepare the stack.
         let stack = self.get_stack(program_id)?;
@@ -58,7 +58,43 @@ impl<N: Network> Process<N> {
         })
     }
 
-    /// Finalizes the execution.
+    /// Runs the constructor of the given program stack (if any), and returns its finalize operations.
+    /// This method assumes the program stack is from a deployment that **is valid**, and has been finalized.
+    /// This method should **only** be called by `VM::finalize()`, exactly once per program.
+    #[inline]
+    pub fn finalize_constructor<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        stack: &Stack<N>,
+        transition_id: N::TransitionID,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        // If the program has no constructor, return early.
+        let Some(constructor) = stack.program().constructor() else {
+            return Ok(Vec::new());
+        };
+
+        let timer = timer!("Process::finalize_constructor");
+
+        atomic_batch_scope!(store, {
+            // Retrieve the constructor logic.
+            let logic = constructor.logic();
+            // Retrieve the constructor types.
+            let constructor_types = stack.get_constructor_types()?.clone();
+            // Initialize the registers.
+            let mut registers = FinalizeRegisters::<N>::new(state, transition_id, *logic.name(), constructor_types);
+            // Evaluate the commands.
+            // Note: The constructor does not emit events, which is checked when the stack is initialized.
+            let (finalize_operations, _) = finalize_commands(store, stack, logic, &mut registers, 0)?;
+            finish!(timer);
+
+            // Return the finalize operations.
+            Ok(finalize_operations)
+        })
+    }
+
+    /// Finalizes the execution, and returns the finalize operations and the emitted events.
+    /// The transitions are finalized atomically, so if any finalize scope fails, none of them are applied.
     /// This method assumes the given execution **is valid**.
     /// This method should **only** be called by `VM::finalize()`.
     #[inline]
@@ -67,7 +103,7 @@ impl<N: Network> Process<N> {
         state: FinalizeGlobalState,
         store: &FinalizeStore<N, P>,
         execution: &Execution<N>,
-    ) -> Result<Vec<FinalizeOperation<N>>> {
+    ) -> Result<(Vec<FinalizeOperation<N>>, Vec<Event<N>>)> {
         let timer = timer!("Program::finalize_execution");
 
         // Ensure the execution contains transitions.
@@ -92,82 +128,74 @@ impl<N: Network> Process<N> {
         atomic_batch_scope!(store, {
             // Initialize a list for finalize operations.
             let mut finalize_operations = Vec::new();
+            // Initialize a list for the emitted events.
+            let mut events = Vec::new();
+
+            // Initialize a stack of the finalized subtrees, with their number of transitions and finalize outputs.
+            let mut finalized: Vec<(usize, Vec<Plaintext<N>>)> = Vec::new();
 
-            // TODO (howardwu): This is a temporary approach. We should create a "CallStack" and recurse through the stack.
-            //  Currently this loop assumes a linearly execution stack.
-            // Finalize each transition, starting from the last one.
+            // Finalize each transition in the order of the execution.
+            // Note: The transitions are in post-order, so the callees of a transition are finalized before it,
+            // in the order of their calls, and the caller is finalized last.
             for transition in execution.transitions() {
                 #[cfg(debug_assertions)]
                 println!("Finalizing transition for {}/{}...", transition.program_id(), transition.function_name());
 
                 // Retrieve the stack.
                 let stack = self.get_stack(transition.program_id())?;
-                // Retrieve the function name.
-                let function_name = transition.function_name();
-
-                // If there is a finalize scope, finalize the function.
-                if let Some((_, finalize)) = stack.get_function(function_name)?.finalize() {
-                    // Retrieve the finalize inputs.
-                    let inputs = match transition.finalize() {
-                        Some(inputs) => inputs,
-                        // Ensure the transition contains finalize inputs.
-                        None => bail!("The transition is missing inputs for 'finalize'"),
-                    };
-
-                    // Initialize the registers.
-                    let mut registers = FinalizeRegisters::<N>::new(
-                        state,
-                        *transition.id(),
-                        *function_name,
-                        stack.get_finalize_types(finalize.name())?.clone(),
-                    );
-
-                    // Store the inputs.
-                    finalize.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(
-                        |(register, input)| {
-                            // Assign the input value to the register.
-                            registers.store(stack, register, input.clone())
-                        },
-                    )?;
-
-                    // Initialize a counter for the index of the commands.
-                    let mut counter = 0;
-
-                    // Evaluate the commands.
-                    while counter < finalize.commands().len() {
-                        // Retrieve the command.
-                        let command = &finalize.commands()[counter];
-                        // Finalize the command.
-                        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| match &command {
-                            Command::BranchEq(branch_eq) => {
-                                counter = branch_to(counter, branch_eq, finalize, stack, &registers)?;
-                                Ok(None)
-                            }
-                            Command::BranchNeq(branch_neq) => {
-                                counter = branch_to(counter, branch_neq, finalize, stack, &registers)?;
-                                Ok(None)
-                            }
-                            _ => {
-                                let operations = command.finalize(stack, store, &mut registers);
-                                counter += 1;
-                                operations
-                            }
-                        }));
-
-                        match result {
-                            // If the evaluation succeeds with an operation, add it to the list.
-                            Ok(Ok(Some(finalize_operation))) => finalize_operations.push(finalize_operation),
-                            // If the evaluation succeeds with no operation, continue.
-                            Ok(Ok(None)) => (),
-                            // If the evaluation fails, bail and return the error.
-                            Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
-                            // If the evaluation fails, bail and return the error.
-                            Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                // Retrieve the number of transitions in the subtree of this transition.
+                let num_calls = stack.get_number_of_calls(transition.function_name())?;
+
+                // Collect the finalize outputs of the callees, which are the subtrees preceding this transition.
+                let mut callees = Vec::new();
+                let mut num_callee_calls = 0;
+                while num_callee_calls < num_calls - 1 {
+                    match finalized.pop() {
+                        Some((num_subtree_calls, outputs)) => {
+                            num_callee_calls += num_subtree_calls;
+                            callees.push(outputs);
                         }
+                        None => bail!("The execution is missing callees for '{}'", transition.function_name()),
                     }
-                    lap!(timer, "Finalize transition for {function_name}");
                 }
+                ensure!(num_callee_calls == num_calls - 1, "The execution has an invalid call structure");
+                // Order the finalize outputs of the callees by their calls.
+                let callee_outputs = callees.into_iter().rev().flatten().collect();
+
+                // Finalize the transition, and store its finalize operations, events, and outputs.
+                let (operations, transition_events, outputs) =
+                    finalize_transition(state, store, &stack, transition, callee_outputs, events.len())?;
+                finalize_operations.extend(operations);
+                events.extend(transition_events);
+                finalized.push((num_calls, outputs));
+                lap!(timer, "Finalize transition for {}", transition.function_name());
             }
+            // Ensure every transition was finalized as part of the root transition.
+            ensure!(finalized.len() == 1, "The execution has an invalid call structure");
+            finish!(timer);
+
+            // Return the finalize operations and events.
+            Ok((finalize_operations, events))
+        })
+    }
+
+    /// Finalizes the fee.
+    /// This method assumes the given fee **is valid**.
+    /// This method should **only** be called by `VM::finalize()`.
+    #[inline]
+    pub fn finalize_fee<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        fee: &Fee<N>,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        let timer = timer!("Program::finalize_fee");
+
+        atomic_batch_scope!(store, {
+            // Finalize the fee transition, which only contains a finalize scope for a public fee.
+            // Note: The fee finalize scope does not emit events.
+            let (finalize_operations, _, _) =
+                finalize_transition(state, store, self.get_stack(fee.program_id())?, fee.transition(), vec![], 0)?;
             finish!(timer);
 
             // Return the finalize operations.
@@ -176,36 +204,227 @@ impl<N: Network> Process<N> {
     }
 }
 
+// A helper function that finalizes the given transition, and returns the finalize operations, events, and outputs.
+// Note: The given callee outputs are assigned to the finalize inputs that follow the inputs from the transition,
+// and the given number of events is the number emitted by the preceding transitions of the execution.
+#[inline]
+fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
+    state: FinalizeGlobalState,
+    store: &FinalizeStore<N, P>,
+    stack: &Stack<N>,
+    transition: &Transition<N>,
+    callee_outputs: Vec<Plaintext<N>>,
+    num_events: usize,
+) -> Result<(Vec<FinalizeOperation<N>>, Vec<Event<N>>, Vec<Plaintext<N>>)> {
+    // Retrieve the function name.
+    let function_name = transition.function_name();
+
+    // Initialize a list for the finalize operations.
+    let mut finalize_operations = Vec::new();
+    // Initialize a list for the emitted events.
+    let mut events = Vec::new();
+    // Initialize a list for the finalize outputs.
+    let mut outputs = Vec::new();
+
+    // If there is a finalize scope, finalize the function.
+    if let Some((_, finalize)) = stack.get_function(function_name)?.finalize() {
+        // Retrieve the finalize inputs.
+        let inputs = match transition.finalize() {
+            Some(inputs) => inputs,
+            // Ensure the transition contains finalize inputs.
+            None => bail!("The transition is missing inputs for 'finalize'"),
+        };
+
+        // Initialize the registers.
+        let mut registers = FinalizeRegisters::<N>::new(
+            state,
+            *transition.id(),
+            *function_name,
+            stack.get_finalize_types(finalize.name())?.clone(),
+        );
+
+        // Append the finalize outputs of the callees to the inputs.
+        let inputs = inputs.iter().cloned().chain(callee_outputs.into_iter().map(Value::Plaintext));
+
+        // Store the inputs.
+        finalize.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
+            // Assign the input value to the register.
+            registers.store(stack, register, input)
+        })?;
+
+        // Evaluate the commands.
+        (finalize_operations, events) = finalize_commands(store, stack, finalize, &mut registers, num_events)?;
+
+        // Load the outputs.
+        for output in finalize.outputs() {
+            match registers.load(stack, output.operand())? {
+                Value::Plaintext(plaintext) => outputs.push(plaintext),
+                Value::Record(..) => bail!("Finalize cannot output a record"),
+            }
+        }
+    }
+    Ok((finalize_operations, events, outputs))
+}
+
+// A helper function that evaluates the commands of the given finalize scope, and returns the finalize operations
+// and emitted events. The given number of events is the number already emitted by the transaction.
+#[inline]
+fn finalize_commands<N: Network, P: FinalizeStorage<N>>(
+    store: &FinalizeStore<N, P>,
+    stack: &Stack<N>,
+    finalize: &Finalize<N>,
+    registers: &mut FinalizeRegisters<N>,
+    num_events: usize,
+) -> Result<(Vec<FinalizeOperation<N>>, Vec<Event<N>>)> {
+    // Initialize a list for the finalize operations.
+    let mut finalize_operations = Vec::new();
+    // Initialize a list for the emitted events.
+    let mut events = Vec::new();
+
+    // Initialize a counter for the index of the commands.
+    let mut counter = 0;
+    // Initialize a tracker for the number of iterations taken by each backward branch.
+    let mut iterations = HashMap::new();
+    // Initialize a counter for the number of writes.
+    // Note: A write inside a loop is counted on each iteration, so this bounds the writes that are executed.
+    let mut num_writes = 0u16;
+
+    // Evaluate the commands.
+    while counter < finalize.commands().len() {
+        // Retrieve the index of the command.
+        let index = counter;
+        // Retrieve the command.
+        let command = &finalize.commands()[index];
+        // Finalize the command.
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| match &command {
+            Command::BranchEq(branch_eq) => {
+                counter = branch_to(counter, branch_eq, finalize, stack, registers, &mut iterations)?;
+                Ok(None)
+            }
+            Command::BranchNeq(branch_neq) => {
+                counter = branch_to(counter, branch_neq, finalize, stack, registers, &mut iterations)?;
+                Ok(None)
+            }
+            Command::Emit(emit) => {
+                // Ensure the transaction does not exceed the maximum number of events.
+                ensure!(
+                    num_events + events.len() < N::MAX_EVENTS as usize,
+                    "Cannot emit more than {} events in a transaction",
+                    N::MAX_EVENTS
+                );
+                events.push(emit.finalize(stack, registers)?);
+                counter += 1;
+                Ok(None)
+            }
+            _ => {
+                // Ensure the finalize scope does not exceed the maximum number of writes.
+                if command.is_write() {
+                    num_writes = num_writes.saturating_add(1);
+                    ensure!(num_writes <= N::MAX_WRITES, "Cannot execute more than {} writes", N::MAX_WRITES);
+                }
+                let operations = command.finalize(stack, store, registers);
+                counter += 1;
+                operations
+            }
+        }));
+
+        match result {
+            // If the evaluation succeeds with an operation, add it to the list.
+            Ok(Ok(Some(finalize_operation))) => finalize_operations.push(finalize_operation),
+            // If the evaluation succeeds with no operation, continue.
+            Ok(Ok(None)) => (),
+            // If the evaluation fails, bail and return the error, with the reason for the rejection.
+            Ok(Err(error)) => {
+                let reason = rejection_reason(stack, finalize, index, command)?;
+                let error = format!("'finalize' failed to evaluate command ({command}): {error}");
+                return Err(anyhow!(reason).context(error));
+            }
+            // If the evaluation fails, bail and return the error, with the reason for the rejection.
+            Err(_) => {
+                let reason = rejection_reason(stack, finalize, index, command)?;
+                let error = format!("'finalize' failed to evaluate command ({command})");
+                return Err(anyhow!(reason).context(error));
+            }
+        }
+    }
+    Ok((finalize_operations, events))
+}
+
+// A helper function that returns the reason for rejecting the given finalize scope, when the given command fails.
+// Note: The reason is attached to the returned error, so that it may be recovered with `Error::downcast_ref`.
+#[inline]
+fn rejection_reason<N: Network>(
+    stack: &Stack<N>,
+    finalize: &Finalize<N>,
+    index: usize,
+    command: &Command<N>,
+) -> Result<RejectionReason<N>> {
+    RejectionReason::new(
+        *stack.program_id(),
+        *finalize.name(),
+        u16::try_from(index)?,
+        *command.opcode(),
+        command.message().map(|message| &**message),
+    )
+}
+
 // A helper function that returns the index to branch to.
+// Note: A branch to an earlier position may be taken at most its maximum number of iterations in total,
+// i.e. the count is not reset on each pass of an enclosing loop (see `Branch`). Each jump clears the
+// registers assigned since that position, so that the loop body may assign them again.
 #[inline]
 fn branch_to<N: Network, const VARIANT: u8>(
     counter: usize,
     branch: &Branch<N, VARIANT>,
     finalize: &Finalize<N>,
     stack: &Stack<N>,
-    registers: &FinalizeRegisters<N>,
+    registers: &mut FinalizeRegisters<N>,
+    iterations: &mut HashMap<usize, u16>,
 ) -> Result<usize> {
     // Retrieve the inputs.
     let first = registers.load(stack, branch.first())?;
     let second = registers.load(stack, branch.second())?;
 
-    // A helper to get the index corresponding to a position.
-    let get_position_index = |position: &Identifier<N>| match finalize.positions().get(position) {
-        Some(index) if *index > counter => Ok(*index),
-        Some(_) => bail!("Cannot branch to an earlier position '{position}' in the program"),
-        None => bail!("The position '{position}' does not exist."),
-    };
-
-    // Compare the operands and determine the index to branch to.
-    match VARIANT {
+    // Compare the operands and determine if the branch is taken.
+    let is_taken = match VARIANT {
         // The `branch.eq` variant.
-        0 if first == second => get_position_index(branch.position()),
-        0 if first != second => Ok(counter + 1),
+        0 => first == second,
         // The `branch.neq` variant.
-        1 if first == second => Ok(counter + 1),
-        1 if first != second => get_position_index(branch.position()),
+        1 => first != second,
         _ => bail!("Invalid 'branch' variant: {VARIANT}"),
+    };
+    // If the branch is not taken, proceed to the next command.
+    if !is_taken {
+        return Ok(counter + 1);
+    }
+
+    // Retrieve the index corresponding to the position.
+    let position = branch.position();
+    let index = match finalize.positions().get(position) {
+        Some(index) => *index,
+        None => bail!("The position '{position}' does not exist."),
+    };
+    // If the position is later in the program, branch forward.
+    if index > counter {
+        return Ok(index);
     }
+
+    // Ensure the branch to an earlier position declares a maximum number of iterations.
+    let Some(max_iterations) = branch.max_iterations() else {
+        bail!("Cannot branch to an earlier position '{position}' without a maximum number of iterations")
+    };
+    // Increment the number of iterations for the branch.
+    let num_iterations = iterations.entry(counter).or_insert(0u16);
+    *num_iterations = num_iterations.saturating_add(1);
+    // Ensure the maximum number of iterations has not been exceeded.
+    ensure!(*num_iterations <= max_iterations, "The loop to '{position}' exceeded {max_iterations} iterations");
+
+    // Clear the registers that were assigned at or after the position.
+    let num_registers = finalize.inputs().len()
+        + finalize.commands()[..index].iter().map(|command| command.destinations().len()).sum::<usize>();
+    registers.truncate(num_registers);
+
+    Ok(index)
 }
 
 #[cfg(test)]
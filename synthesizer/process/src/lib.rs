@@ -29,6 +29,8 @@ pub use traits::*;
 
 mod authorize;
 mod deploy;
+mod disclose;
+pub use disclose::*;
 mod evaluate;
 mod execute;
 mod execute_fee;
@@ -50,8 +52,11 @@ use ledger_block::{Deployment, Execution, Fee, Input, Transition};
 use ledger_store::{atomic_batch_scope, FinalizeStorage, FinalizeStore};
 use synthesizer_program::{
     Branch,
+    CallOperator,
     Closure,
     Command,
+    CommandTrait,
+    Event,
     Finalize,
     FinalizeGlobalState,
     FinalizeOperation,
@@ -60,6 +65,7 @@ use synthesizer_program::{
     Program,
     RegistersLoad,
     RegistersStore,
+    RejectionReason,
     StackProgram,
 };
 use synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
@@ -72,6 +78,9 @@ use std::{collections::HashMap, sync::Arc};
 #[cfg(feature = "aleo-cli")]
 use colored::Colorize;
 
+/// The 'credits.aleo' functions whose circuit keys are not shipped with the parameters.
+const UNSHIPPED_CREDITS_FUNCTIONS: [&str; 1] = ["fee_public"];
+
 #[derive(Clone)]
 pub struct Process<N: Network> {
     /// The universal SRS.
@@ -151,6 +160,11 @@ impl<N: Network> Process<N> {
 
         // Synthesize the 'credits.aleo' verifying keys.
         for function_name in program.functions().keys() {
+            // Skip the functions whose circuit keys are not shipped with the parameters.
+            // Note: Their keys are synthesized with `Process::synthesize_credits_keys`.
+            if UNSHIPPED_CREDITS_FUNCTIONS.contains(&function_name.to_string().as_str()) {
+                continue;
+            }
             // Load the verifying key.
             let verifying_key = N::get_credits_verifying_key(function_name.to_string())?;
             stack.insert_verifying_key(function_name, VerifyingKey::new(verifying_key.clone()))?;
@@ -276,6 +290,22 @@ impl<N: Network> Process<N> {
         // Synthesize the proving and verifying key.
         self.get_stack(program_id)?.synthesize_key::<A, R>(function_name, rng)
     }
+
+    /// Synthesizes the circuit keys of the 'credits.aleo' functions that are not shipped with the parameters.
+    /// Note: A circuit key depends only on the constraints of the function, so every node synthesizes the same key.
+    #[inline]
+    pub fn synthesize_credits_keys<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Retrieve the 'credits.aleo' program ID.
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        // Synthesize the proving and verifying key of each function without shipped keys.
+        for function_name in UNSHIPPED_CREDITS_FUNCTIONS {
+            self.synthesize_key::<A, R>(&program_id, &Identifier::from_str(function_name)?, rng)?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
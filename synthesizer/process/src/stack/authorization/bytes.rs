@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for Authorization<N> {
+    /// Reads the authorization from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid authorization version"));
+        }
+        // Read the number of requests.
+        let num_requests = u8::read_le(&mut reader)?;
+        // Ensure the number of requests is nonzero.
+        if num_requests == 0 {
+            return Err(error("Authorization (from 'read_le') has no requests"));
+        }
+        // Read the requests.
+        let requests = (0..num_requests).map(|_| Request::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the authorization.
+        Ok(Self::new(&requests))
+    }
+}
+
+impl<N: Network> ToBytes for Authorization<N> {
+    /// Writes the authorization to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Retrieve the requests.
+        let requests = self.requests.read();
+        // Ensure the number of requests is within bounds.
+        let num_requests = match u8::try_from(requests.len()) {
+            Ok(0) => return Err(error("Authorization (from 'write_le') has no requests")),
+            Ok(num_requests) => num_requests,
+            Err(_) => return Err(error(format!("Authorization has too many requests ({})", requests.len()))),
+        };
+
+        // Write the version.
+        0u8.write_le(&mut writer)?;
+        // Write the number of requests.
+        num_requests.write_le(&mut writer)?;
+        // Write the requests.
+        for request in requests.iter() {
+            request.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Construct a new authorization.
+        let expected = crate::stack::authorization::test_helpers::sample_authorization(&mut rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Authorization::read_le(&expected_bytes[..])?);
+        assert!(Authorization::<console::network::Testnet3>::read_le(&expected_bytes[1..]).is_err());
+
+        // Ensure an empty authorization cannot be written.
+        assert!(Authorization::<console::network::Testnet3>::new(&[]).to_bytes_le().is_err());
+        Ok(())
+    }
+}
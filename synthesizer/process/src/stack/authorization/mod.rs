@@ -12,6 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bytes;
+mod serialize;
+mod string;
+
 use console::{network::prelude::*, program::Request};
 
 use parking_lot::RwLock;
@@ -69,3 +73,44 @@ impl<N: Network> Authorization<N> {
         self.requests.read().clone()
     }
 }
+
+impl<N: Network> PartialEq for Authorization<N> {
+    /// Returns `true` if the authorizations contain the same requests, in the same order.
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.requests, &other.requests) || *self.requests.read() == *other.requests.read()
+    }
+}
+
+impl<N: Network> Eq for Authorization<N> {}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::{
+        account::PrivateKey,
+        network::Testnet3,
+        program::{Identifier, ProgramID, Value, ValueType},
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples an authorization with a few signed requests.
+    pub(crate) fn sample_authorization(rng: &mut TestRng) -> Authorization<CurrentNetwork> {
+        // Sample a private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Construct the requests.
+        let requests = (0..3u64)
+            .map(|i| {
+                let program_id = ProgramID::from_str("token.aleo").unwrap();
+                let function_name = Identifier::from_str("transfer").unwrap();
+                let inputs = [Value::from_str(&format!("{i}u64")).unwrap(), Value::from_str("7field").unwrap()];
+                let input_types =
+                    [ValueType::from_str("u64.public").unwrap(), ValueType::from_str("field.private").unwrap()];
+                Request::sign(&private_key, program_id, function_name, inputs.into_iter(), &input_types, rng).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        Authorization::new(&requests)
+    }
+}
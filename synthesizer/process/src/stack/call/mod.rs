@@ -137,9 +137,11 @@ impl<N: Network> CallTrait<N> for Call<N> {
         let (substack, resource) = match self.operator() {
             // Retrieve the call stack and resource from the locator.
             CallOperator::Locator(locator) => {
-                // Ensure the external call is not to 'credits.aleo/fee'.
-                if &locator.program_id().to_string() == "credits.aleo" && &locator.resource().to_string() == "fee" {
-                    bail!("Cannot perform an external call to 'credits.aleo/fee'.")
+                // Ensure the external call is not to 'credits.aleo/fee' or 'credits.aleo/fee_public'.
+                if &locator.program_id().to_string() == "credits.aleo"
+                    && (&locator.resource().to_string() == "fee" || &locator.resource().to_string() == "fee_public")
+                {
+                    bail!("Cannot perform an external call to '{locator}'.")
                 } else {
                     (stack.get_external_stack(locator.program_id())?.clone(), locator.resource())
                 }
@@ -22,6 +22,8 @@ impl<N: Network> Stack<N> {
 
         // Ensure the program contains functions.
         ensure!(!self.program.functions().is_empty(), "Program '{}' has no functions", self.program.id());
+        // Ensure the program mappings are within their size limits.
+        self.check_mappings()?;
 
         // Initialize a vector for the verifying keys and certificates.
         let mut verifying_keys = Vec::with_capacity(self.program.functions().len());
@@ -66,6 +68,8 @@ impl<N: Network> Stack<N> {
         deployment.check_is_ordered()?;
         // Ensure the program in the stack and deployment matches.
         ensure!(&self.program == deployment.program(), "The stack program does not match the deployment program");
+        // Ensure the program mappings are within their size limits.
+        self.check_mappings()?;
 
         // Check Verifying Keys //
 
@@ -77,6 +77,12 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a closure scope."),
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => bail!("Cannot retrieve the block timestamp from a closure scope."),
+                    // If the operand is the previous block hash, throw an error.
+                    Operand::PreviousBlockHash => {
+                        bail!("Cannot retrieve the previous block hash from a closure scope.")
+                    }
                 }
             })
             .collect();
@@ -192,6 +198,12 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a function scope."),
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => bail!("Cannot retrieve the block timestamp from a function scope."),
+                    // If the operand is the previous block hash, throw an error.
+                    Operand::PreviousBlockHash => {
+                        bail!("Cannot retrieve the previous block hash from a function scope.")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -108,6 +108,14 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a closure scope")
                     }
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => {
+                        bail!("Illegal operation: cannot retrieve the block timestamp in a closure scope")
+                    }
+                    // If the operand is the previous block hash, throw an error.
+                    Operand::PreviousBlockHash => {
+                        bail!("Illegal operation: cannot retrieve the previous block hash in a closure scope")
+                    }
                 }
             })
             .collect();
@@ -282,6 +290,14 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a function scope")
                     }
+                    // If the operand is the block timestamp, throw an error.
+                    Operand::BlockTimestamp => {
+                        bail!("Illegal operation: cannot retrieve the block timestamp in a function scope")
+                    }
+                    // If the operand is the previous block hash, throw an error.
+                    Operand::PreviousBlockHash => {
+                        bail!("Illegal operation: cannot retrieve the previous block hash in a function scope")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
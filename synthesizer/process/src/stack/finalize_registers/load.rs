@@ -38,6 +38,15 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
             Operand::BlockHeight => {
                 return Ok(Value::Plaintext(Plaintext::from(Literal::U32(U32::new(self.state.block_height())))));
             }
+            // If the operand is the block timestamp, load the block timestamp.
+            Operand::BlockTimestamp => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::I64(I64::new(self.state.block_timestamp())))));
+            }
+            // If the operand is the previous block hash, load the previous block hash.
+            Operand::PreviousBlockHash => {
+                let previous_block_hash = Field::from_bytes_le(self.state.previous_block_hash())?;
+                return Ok(Value::Plaintext(Plaintext::from(Literal::Field(previous_block_hash))));
+            }
         };
 
         // Retrieve the plaintext value.
@@ -19,7 +19,7 @@ use crate::FinalizeTypes;
 use console::{
     network::prelude::*,
     program::{Identifier, Literal, Plaintext, Register, Value},
-    types::U32,
+    types::{Field, I64, U32},
 };
 use synthesizer_program::{
     FinalizeGlobalState,
@@ -58,6 +58,12 @@ impl<N: Network> FinalizeRegisters<N> {
     ) -> Self {
         Self { state, transition_id, finalize_types, function_name, registers: IndexMap::new() }
     }
+
+    /// Removes all but the first `num_registers` registers, so that they may be assigned again.
+    #[inline]
+    pub(crate) fn truncate(&mut self, num_registers: usize) {
+        self.registers.truncate(num_registers)
+    }
 }
 
 impl<N: Network> FinalizeRegistersState<N> for FinalizeRegisters<N> {
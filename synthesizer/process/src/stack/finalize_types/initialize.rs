@@ -14,7 +14,19 @@
 
 use super::*;
 use crate::RegisterTypes;
-use synthesizer_program::{Branch, Contains, Get, GetOrUse, RandChaCha, Remove, Set, MAX_ADDITIONAL_SEEDS};
+use synthesizer_program::{
+    Branch,
+    Contains,
+    Emit,
+    Get,
+    GetOrUse,
+    Mapping,
+    MappingLocator,
+    RandChaCha,
+    Remove,
+    Set,
+    MAX_ADDITIONAL_SEEDS,
+};
 
 impl<N: Network> FinalizeTypes<N> {
     /// Initializes a new instance of `FinalizeTypes` for the given finalize.
@@ -39,6 +51,12 @@ impl<N: Network> FinalizeTypes<N> {
             finalize_types.check_command(stack, finalize, command)?;
         }
 
+        // Step 3. Check the outputs are well-formed.
+        for output in finalize.outputs() {
+            // Check the output operand type.
+            finalize_types.check_output(stack, output.operand(), output.plaintext_type())?;
+        }
+
         Ok(finalize_types)
     }
 }
@@ -123,6 +141,21 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensure the given output operand is well-formed.
+    #[inline]
+    fn check_output(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        operand: &Operand<N>,
+        plaintext_type: &PlaintextType<N>,
+    ) -> Result<()> {
+        // Ensure the operand type and the output type match.
+        if *plaintext_type != self.get_type_from_operand(stack, operand)? {
+            bail!("Output '{operand}' does not match the expected output type '{plaintext_type}'.")
+        }
+        Ok(())
+    }
+
     /// Ensures the given command is well-formed.
     #[inline]
     fn check_command(
@@ -143,6 +176,7 @@ impl<N: Network> FinalizeTypes<N> {
             Command::BranchNeq(branch_neq) => self.check_branch(stack, finalize, branch_neq)?,
             // Note that the `Position`s are checked for uniqueness when constructing `Finalize`.
             Command::Position(_) => (),
+            Command::Emit(emit) => self.check_emit(stack, emit)?,
         }
         Ok(())
     }
@@ -177,6 +211,50 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `emit` command is well-formed.
+    #[inline]
+    fn check_emit(&mut self, stack: &(impl StackMatches<N> + StackProgram<N>), emit: &Emit<N>) -> Result<()> {
+        // Ensure the number of operands is within bounds.
+        if emit.operands().len() > N::MAX_OPERANDS {
+            bail!("The number of operands must be <= {}", N::MAX_OPERANDS)
+        }
+        // Ensure each operand is well-typed.
+        for operand in emit.operands() {
+            self.get_type_from_operand(stack, operand)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the mapping for the given mapping locator.
+    /// Note: A mapping in another program can only be read if the program is imported.
+    fn get_mapping(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        finalize_name: &Identifier<N>,
+        mapping_locator: &MappingLocator<N>,
+    ) -> Result<Mapping<N>> {
+        match mapping_locator {
+            MappingLocator::Locator(locator) => {
+                // Ensure the locator does not reference the current program.
+                if locator.program_id() == stack.program_id() {
+                    bail!("Locator '{locator}' does not reference an external mapping.");
+                }
+                // Retrieve the mapping from the imported program.
+                match stack.get_external_program(locator.program_id())?.get_mapping(locator.resource()) {
+                    Ok(mapping) => Ok(mapping),
+                    Err(_) => bail!("Mapping '{locator}' in '{}/{finalize_name}' is not defined.", stack.program_id()),
+                }
+            }
+            MappingLocator::Resource(mapping_name) => {
+                // Ensure the mapping is defined in the program.
+                if !stack.program().contains_mapping(mapping_name) {
+                    bail!("Mapping '{mapping_name}' in '{}/{finalize_name}' is not defined.", stack.program_id())
+                }
+                // Retrieve the mapping from the program.
+                stack.program().get_mapping(mapping_name)
+            }
+        }
+    }
+
     /// Ensures the given `contains` command is well-formed.
     #[inline]
     fn check_contains(
@@ -185,13 +263,8 @@ impl<N: Network> FinalizeTypes<N> {
         finalize_name: &Identifier<N>,
         contains: &Contains<N>,
     ) -> Result<()> {
-        // Ensure the declared mapping in `contains` is defined in the program.
-        if !stack.program().contains_mapping(contains.mapping_name()) {
-            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", contains.mapping_name(), stack.program_id())
-        }
-        // Retrieve the mapping from the program.
-        // Note that the unwrap is safe, as we have already checked the mapping exists.
-        let mapping = stack.program().get_mapping(contains.mapping_name()).unwrap();
+        // Retrieve the mapping in `contains`, which may be defined in an imported program.
+        let mapping = Self::get_mapping(stack, finalize_name, contains.mapping())?;
         // Get the mapping key type.
         let mapping_key_type = mapping.key().plaintext_type();
         // Retrieve the register type of the key.
@@ -219,13 +292,8 @@ impl<N: Network> FinalizeTypes<N> {
         finalize_name: &Identifier<N>,
         get: &Get<N>,
     ) -> Result<()> {
-        // Ensure the declared mapping in `get` is defined in the program.
-        if !stack.program().contains_mapping(get.mapping_name()) {
-            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", get.mapping_name(), stack.program_id())
-        }
-        // Retrieve the mapping from the program.
-        // Note that the unwrap is safe, as we have already checked the mapping exists.
-        let mapping = stack.program().get_mapping(get.mapping_name()).unwrap();
+        // Retrieve the mapping in `get`, which may be defined in an imported program.
+        let mapping = Self::get_mapping(stack, finalize_name, get.mapping())?;
         // Get the mapping key type.
         let mapping_key_type = mapping.key().plaintext_type();
         // Get the mapping value type.
@@ -253,13 +321,8 @@ impl<N: Network> FinalizeTypes<N> {
         finalize_name: &Identifier<N>,
         get_or_use: &GetOrUse<N>,
     ) -> Result<()> {
-        // Ensure the declared mapping in `get.or_use` is defined in the program.
-        if !stack.program().contains_mapping(get_or_use.mapping_name()) {
-            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", get_or_use.mapping_name(), stack.program_id())
-        }
-        // Retrieve the mapping from the program.
-        // Note that the unwrap is safe, as we have already checked the mapping exists.
-        let mapping = stack.program().get_mapping(get_or_use.mapping_name()).unwrap();
+        // Retrieve the mapping in `get.or_use`, which may be defined in an imported program.
+        let mapping = Self::get_mapping(stack, finalize_name, get_or_use.mapping())?;
         // Get the mapping key type.
         let mapping_key_type = mapping.key().plaintext_type();
         // Get the mapping value type.
@@ -503,6 +566,12 @@ impl<N: Network> FinalizeTypes<N> {
                 bail!("Fatal error: Cannot check command '{opcode}' as an instruction in 'finalize {finalize_name}'.")
             }
             Opcode::Commit(opcode) => RegisterTypes::check_commit_opcode(opcode, instruction)?,
+            Opcode::Decrypt => {
+                bail!("Forbidden operation: Cannot invoke 'decrypt' in a `finalize` scope.");
+            }
+            Opcode::Encrypt => {
+                bail!("Forbidden operation: Cannot invoke 'encrypt' in a `finalize` scope.");
+            }
             Opcode::Finalize(opcode) => {
                 bail!("Forbidden operation: Cannot invoke '{opcode}' in a `finalize` scope.");
             }
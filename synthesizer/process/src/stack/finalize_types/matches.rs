@@ -87,6 +87,26 @@ impl<N: Network> FinalizeTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_height_type}' in the operand '{operand}'.",
                     )
                 }
+                // Ensure the block timestamp type (i64) matches the member type.
+                Operand::BlockTimestamp => {
+                    // Retrieve the block timestamp type.
+                    let block_timestamp_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::I64));
+                    // Ensure the block timestamp type matches the member type.
+                    ensure!(
+                        block_timestamp_type == RegisterType::Plaintext(*member_type),
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_timestamp_type}' in the operand '{operand}'.",
+                    )
+                }
+                // Ensure the previous block hash type (field) matches the member type.
+                Operand::PreviousBlockHash => {
+                    // Retrieve the previous block hash type.
+                    let block_hash_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field));
+                    // Ensure the previous block hash type matches the member type.
+                    ensure!(
+                        block_hash_type == RegisterType::Plaintext(*member_type),
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_hash_type}' in the operand '{operand}'.",
+                    )
+                }
             }
         }
         Ok(())
@@ -77,6 +77,8 @@ impl<N: Network> FinalizeTypes<N> {
             Operand::ProgramID(_) => PlaintextType::Literal(LiteralType::Address),
             Operand::Caller => bail!("'self.caller' is not a valid operand in a finalize context."),
             Operand::BlockHeight => PlaintextType::Literal(LiteralType::U32),
+            Operand::BlockTimestamp => PlaintextType::Literal(LiteralType::I64),
+            Operand::PreviousBlockHash => PlaintextType::Literal(LiteralType::Field),
         })
     }
 
@@ -24,6 +24,7 @@ impl<N: Network> Stack<N> {
             external_stacks: Default::default(),
             register_types: Default::default(),
             finalize_types: Default::default(),
+            constructor_types: Default::default(),
             universal_srs: process.universal_srs().clone(),
             proving_keys: Default::default(),
             verifying_keys: Default::default(),
@@ -40,6 +41,12 @@ impl<N: Network> Stack<N> {
             // Add the external stack to the stack.
             stack.insert_external_stack(external_stack.clone())?;
         }
+        // Check the program mappings against their declared size limits.
+        // Note: The default size limit is only enforced on new deployments, in `Stack::check_mappings`.
+        for mapping in program.mappings().values().filter(|mapping| mapping.limit().is_some()) {
+            // Check the mapping.
+            stack.check_mapping(mapping)?;
+        }
         // Add the program closures to the stack.
         for closure in program.closures().values() {
             // Add the closure to the stack.
@@ -50,6 +57,10 @@ impl<N: Network> Stack<N> {
             // Add the function to the stack.
             stack.insert_function(function)?;
         }
+        // Add the program constructor (if any) to the stack.
+        if let Some(constructor) = program.constructor() {
+            stack.insert_constructor(constructor)?;
+        }
         // Return the stack.
         Ok(stack)
     }
@@ -73,6 +84,39 @@ impl<N: Network> Stack<N> {
         Ok(())
     }
 
+    /// Ensures the key and value types of each program mapping cannot exceed the size limit of the mapping.
+    /// Note: A mapping without a declared size limit is bounded by the maximum data size. This bound is
+    /// only enforced on new deployments, so that previously-deployed programs continue to load.
+    #[inline]
+    pub(crate) fn check_mappings(&self) -> Result<()> {
+        self.program.mappings().values().try_for_each(|mapping| self.check_mapping(mapping))
+    }
+
+    /// Ensures the key and value types of the given mapping cannot exceed the size limit of the mapping.
+    #[inline]
+    fn check_mapping(&self, mapping: &Mapping<N>) -> Result<()> {
+        // Retrieve the mapping name.
+        let name = mapping.name();
+        // Retrieve the size limit, in field elements, defaulting to the maximum data size.
+        let limit = mapping.limit().map_or(N::MAX_DATA_SIZE_IN_FIELDS as usize, usize::from);
+
+        // Ensure the key type cannot exceed the size limit.
+        let key_type = mapping.key().plaintext_type();
+        let key_size = self.get_max_size_in_fields(key_type)?;
+        ensure!(
+            key_size <= limit,
+            "Mapping '{name}' key type '{key_type}' may need {key_size} field elements, over the limit of {limit}"
+        );
+        // Ensure the value type cannot exceed the size limit.
+        let value_type = mapping.value().plaintext_type();
+        let value_size = self.get_max_size_in_fields(value_type)?;
+        ensure!(
+            value_size <= limit,
+            "Mapping '{name}' value type '{value_type}' may need {value_size} field elements, over the limit of {limit}"
+        );
+        Ok(())
+    }
+
     /// Inserts the given closure to the stack.
     #[inline]
     fn insert_closure(&mut self, closure: &Closure<N>) -> Result<()> {
@@ -103,7 +147,23 @@ impl<N: Network> Stack<N> {
         self.register_types.insert(*name, register_types);
 
         // If the function contains a finalize, insert it.
-        if let Some((_, finalize)) = function.finalize() {
+        if let Some((command, finalize)) = function.finalize() {
+            // Retrieve the finalize output types of the callees.
+            let callee_output_types = self.get_callee_finalize_output_types(name)?;
+            // Retrieve the number of finalize operands.
+            let num_operands = command.operands().len();
+            // Ensure the finalize inputs are the finalize operands, followed by the finalize outputs of the callees.
+            ensure!(
+                finalize.inputs().len() == num_operands + callee_output_types.len(),
+                "Finalize '{name}' takes {} inputs, but expects {num_operands} operands and {} callee outputs",
+                finalize.inputs().len(),
+                callee_output_types.len()
+            );
+            ensure!(
+                finalize.input_types()[num_operands..] == callee_output_types[..],
+                "Finalize '{name}' has inputs that do not match the finalize outputs of its callees"
+            );
+
             // Compute the finalize types.
             let finalize_types = FinalizeTypes::from_finalize(self, finalize)?;
             // Add the finalize name and finalize types to the stack.
@@ -113,4 +173,22 @@ impl<N: Network> Stack<N> {
         // Return success.
         Ok(())
     }
+
+    /// Adds the register types of the given constructor to the stack.
+    #[inline]
+    fn insert_constructor(&mut self, constructor: &Constructor<N>) -> Result<()> {
+        // Ensure the constructor is not already added.
+        ensure!(self.constructor_types.is_none(), "The constructor already exists");
+        // Ensure the constructor does not emit events, as a deployment does not record them.
+        for command in constructor.commands() {
+            ensure!(!matches!(command, Command::Emit(..)), "Forbidden operation: The constructor cannot emit events");
+        }
+
+        // Compute the constructor types, which are checked as a finalize scope.
+        let constructor_types = FinalizeTypes::from_finalize(self, constructor.logic())?;
+        // Add the constructor types to the stack.
+        self.constructor_types = Some(constructor_types);
+        // Return success.
+        Ok(())
+    }
 }
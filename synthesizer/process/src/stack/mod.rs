@@ -61,7 +61,18 @@ use console::{
     types::{Field, Group},
 };
 use ledger_block::{Deployment, Transition};
-use synthesizer_program::{traits::*, CallOperator, Closure, Function, Instruction, Operand, Program};
+use synthesizer_program::{
+    traits::*,
+    CallOperator,
+    Closure,
+    Command,
+    Constructor,
+    Function,
+    Instruction,
+    Mapping,
+    Operand,
+    Program,
+};
 use synthesizer_snark::{Certificate, ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
@@ -166,6 +177,8 @@ pub struct Stack<N: Network> {
     register_types: IndexMap<Identifier<N>, RegisterTypes<N>>,
     /// The mapping of finalize names to their register types.
     finalize_types: IndexMap<Identifier<N>, FinalizeTypes<N>>,
+    /// The register types of the constructor, if the program has one.
+    constructor_types: Option<FinalizeTypes<N>>,
     /// The universal SRS.
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of function name to proving key.
@@ -198,6 +211,60 @@ impl<N: Network> Stack<N> {
         // Return the stack.
         Stack::initialize(process, program)
     }
+
+    /// Returns the register types of the constructor.
+    #[inline]
+    pub fn get_constructor_types(&self) -> Result<&FinalizeTypes<N>> {
+        // Retrieve the constructor types.
+        self.constructor_types.as_ref().ok_or_else(|| anyhow!("Program '{}' has no constructor", self.program.id()))
+    }
+
+    /// Returns the finalize output types of the functions called by the given function, in order of the calls.
+    /// Note: These are the types of the trailing finalize inputs of the given function.
+    #[inline]
+    pub fn get_callee_finalize_output_types(&self, function_name: &Identifier<N>) -> Result<Vec<PlaintextType<N>>> {
+        // Initialize a list for the output types.
+        let mut output_types = Vec::new();
+        for instruction in self.get_function(function_name)?.instructions() {
+            if let Instruction::Call(call) = instruction {
+                // Determine if this is a function call.
+                if call.is_function_call(self)? {
+                    // Retrieve the callee function.
+                    let callee = match call.operator() {
+                        CallOperator::Locator(locator) => {
+                            self.get_external_stack(locator.program_id())?.get_function(locator.resource())?
+                        }
+                        CallOperator::Resource(resource) => self.get_function(resource)?,
+                    };
+                    // Extend the output types with the finalize output types of the callee, if any.
+                    if let Some((_, finalize)) = callee.finalize() {
+                        output_types.extend(finalize.output_types());
+                    }
+                }
+            }
+        }
+        Ok(output_types)
+    }
+
+    /// Returns the maximum number of bits needed to encode a plaintext of the given type.
+    /// Note: This mirrors the bit encoding of `Plaintext`, and saturates at `usize::MAX`.
+    fn get_max_size_in_bits(&self, plaintext_type: &PlaintextType<N>) -> Result<usize> {
+        match plaintext_type {
+            // A literal is encoded as its variant bits, literal variant, size, and literal bits.
+            PlaintextType::Literal(literal_type) => Ok(2 + 8 + 16 + literal_type.size_in_bits::<N>() as usize),
+            // A struct is encoded as its variant bits, number of members, and members.
+            PlaintextType::Struct(struct_name) => {
+                let mut num_bits: usize = 2 + 8;
+                for (member_name, member_type) in self.program.get_struct(struct_name)?.members() {
+                    // A member is encoded as its name size, name, value size, and value bits.
+                    let member_bits = 8 + member_name.size_in_bits() as usize + 16;
+                    let value_bits = self.get_max_size_in_bits(member_type)?;
+                    num_bits = num_bits.saturating_add(member_bits).saturating_add(value_bits);
+                }
+                Ok(num_bits)
+            }
+        }
+    }
 }
 
 impl<N: Network> StackProgram<N> for Stack<N> {
@@ -282,6 +349,15 @@ impl<N: Network> StackProgram<N> for Stack<N> {
         }
         Ok(num_calls)
     }
+
+    /// Returns the maximum number of field elements needed to encode a plaintext of the given type.
+    #[inline]
+    fn get_max_size_in_fields(&self, plaintext_type: &PlaintextType<N>) -> Result<usize> {
+        // Compute the maximum number of bits, with 1 extra bit for the terminus indicator.
+        let num_bits = self.get_max_size_in_bits(plaintext_type)?.saturating_add(1);
+        // Compute the ceiling division of the number of bits by the number of bits in a field element.
+        Ok(num_bits.saturating_add(Field::<N>::size_in_data_bits() - 1) / Field::<N>::size_in_data_bits())
+    }
 }
 
 impl<N: Network> StackProgramTypes<N> for Stack<N> {
@@ -398,6 +474,7 @@ impl<N: Network> PartialEq for Stack<N> {
             && self.external_stacks == other.external_stacks
             && self.register_types == other.register_types
             && self.finalize_types == other.finalize_types
+            && self.constructor_types == other.constructor_types
     }
 }
 
@@ -447,6 +447,20 @@ impl<N: Network> RegisterTypes<N> {
                 bail!("Forbidden operation: Instruction '{instruction}' cannot invoke command '{opcode}'.");
             }
             Opcode::Commit(opcode) => Self::check_commit_opcode(opcode, instruction)?,
+            Opcode::Decrypt => {
+                // Ensure the instruction is the correct one.
+                ensure!(
+                    matches!(instruction, Instruction::Decrypt(..)),
+                    "Instruction '{instruction}' is not for opcode 'decrypt'."
+                );
+            }
+            Opcode::Encrypt => {
+                // Ensure the instruction is the correct one.
+                ensure!(
+                    matches!(instruction, Instruction::Encrypt(..)),
+                    "Instruction '{instruction}' is not for opcode 'encrypt'."
+                );
+            }
             Opcode::Finalize(opcode) => {
                 bail!("Forbidden operation: Instruction '{instruction}' cannot invoke command '{opcode}'.");
                 // // Ensure the opcode is correct.
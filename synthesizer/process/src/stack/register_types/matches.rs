@@ -92,6 +92,14 @@ impl<N: Network> RegisterTypes<N> {
                 Operand::BlockHeight => bail!(
                     "Struct member '{struct_name}.{member_name}' cannot be from a block height in a non-finalize scope"
                 ),
+                // If the operand is a block timestamp type, throw an error.
+                Operand::BlockTimestamp => bail!(
+                    "Struct member '{struct_name}.{member_name}' cannot be from a block timestamp in a non-finalize scope"
+                ),
+                // If the operand is a previous block hash type, throw an error.
+                Operand::PreviousBlockHash => bail!(
+                    "Struct member '{struct_name}.{member_name}' cannot be from a block hash in a non-finalize scope"
+                ),
             }
         }
         Ok(())
@@ -154,6 +162,12 @@ impl<N: Network> RegisterTypes<N> {
             Operand::BlockHeight => {
                 bail!("Forbidden operation: Cannot cast a block height as a record owner")
             }
+            Operand::BlockTimestamp => {
+                bail!("Forbidden operation: Cannot cast a block timestamp as a record owner")
+            }
+            Operand::PreviousBlockHash => {
+                bail!("Forbidden operation: Cannot cast a block hash as a record owner")
+            }
         }
 
         // Ensure the operand types match the record entry types.
@@ -214,6 +228,18 @@ impl<N: Network> RegisterTypes<N> {
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found a block height in the operand '{operand}'."
                             )
                         }
+                        // Fail if the operand is a block timestamp.
+                        Operand::BlockTimestamp => {
+                            bail!(
+                                "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found a block timestamp in the operand '{operand}'."
+                            )
+                        }
+                        // Fail if the operand is a previous block hash.
+                        Operand::PreviousBlockHash => {
+                            bail!(
+                                "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found a block hash in the operand '{operand}'."
+                            )
+                        }
                     }
                 }
             }
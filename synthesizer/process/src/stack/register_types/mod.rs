@@ -93,6 +93,10 @@ impl<N: Network> RegisterTypes<N> {
             Operand::ProgramID(_) => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
             Operand::Caller => RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
             Operand::BlockHeight => bail!("'block.height' is not a valid operand in a non-finalize context."),
+            Operand::BlockTimestamp => bail!("'block.timestamp' is not a valid operand in a non-finalize context."),
+            Operand::PreviousBlockHash => {
+                bail!("'block.previous_hash' is not a valid operand in a non-finalize context.")
+            }
         })
     }
 
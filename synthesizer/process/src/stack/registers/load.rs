@@ -36,6 +36,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
             Operand::Caller => return Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.caller()?)))),
             // If the operand is the block height, throw an error.
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
+            // If the operand is the block timestamp, throw an error.
+            Operand::BlockTimestamp => bail!("Cannot load the block timestamp in a non-finalize context"),
+            // If the operand is the previous block hash, throw an error.
+            Operand::PreviousBlockHash => bail!("Cannot load the previous block hash in a non-finalize context"),
         };
 
         // Retrieve the stack value.
@@ -111,6 +115,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
             }
             // If the operand is the block height, throw an error.
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
+            // If the operand is the block timestamp, throw an error.
+            Operand::BlockTimestamp => bail!("Cannot load the block timestamp in a non-finalize context"),
+            // If the operand is the previous block hash, throw an error.
+            Operand::PreviousBlockHash => bail!("Cannot load the previous block hash in a non-finalize context"),
         };
 
         // Retrieve the circuit value.
@@ -17,8 +17,8 @@ use circuit::{network::AleoV0, Assignment};
 use console::{
     account::{Address, PrivateKey, ViewKey},
     network::Testnet3,
-    program::{Identifier, Literal, Value},
-    types::Field,
+    program::{Identifier, Literal, PlaintextType, Value},
+    types::{Field, U8},
 };
 use ledger_query::Query;
 use ledger_store::{
@@ -32,7 +32,7 @@ type CurrentAleo = AleoV0;
 
 /// Samples a new finalize state.
 fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-    FinalizeGlobalState::from(block_height, [0u8; 32])
+    FinalizeGlobalState::from(block_height, 0, [0u8; 32], [0u8; 32])
 }
 
 #[test]
@@ -1742,6 +1742,451 @@ finalize compute:
     assert_eq!(candidate, Value::from_str("16u64").unwrap());
 }
 
+#[test]
+fn test_process_execute_and_finalize_get_external_mapping() {
+    // Initialize the program that owns the mapping.
+    let program0 = Program::<CurrentNetwork>::from_str(
+        r"
+program token.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];",
+    )
+    .unwrap();
+
+    // Initialize a program that reads the mapping of `token.aleo`.
+    let program1 = Program::<CurrentNetwork>::from_str(
+        r"
+import token.aleo;
+
+program reader.aleo;
+
+mapping mirror:
+    key owner as address.public;
+    value amount as u64.public;
+
+function copy:
+    input r0 as address.public;
+    finalize r0;
+
+finalize copy:
+    input r0 as address.public;
+    contains token.aleo/account[r0] into r1;
+    assert.eq r1 true;
+    get token.aleo/account[r0] into r2;
+    get.or_use token.aleo/account[r0] 0u64 into r3;
+    add r2 r3 into r4;
+    set r4 into mirror[r0];",
+    )
+    .unwrap();
+
+    // Declare the mapping names.
+    let account = Identifier::from_str("account").unwrap();
+    let mirror = Identifier::from_str("mirror").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("copy").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Deploy both programs.
+    for program in [&program0, &program1] {
+        let deployment = process.deploy::<CurrentAleo, _>(program, rng).unwrap();
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        let (stack, _) = process.finalize_deployment(&finalize_store, &deployment).unwrap();
+        process.add_stack(stack);
+    }
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Store a balance for the caller in `token.aleo`.
+    let key = Plaintext::from(Literal::Address(caller));
+    finalize_store.insert_key_value(program0.id(), &account, key.clone(), Value::from_str("100u64").unwrap()).unwrap();
+
+    // Authorize the function call.
+    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, [r0].iter(), rng)
+        .unwrap();
+
+    // Execute the request.
+    let (_, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("reader", rng).unwrap();
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Finalize the execution.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution).unwrap();
+
+    // Check that the mirrored balance was computed from the mapping in `token.aleo`.
+    let candidate = finalize_store.get_value_speculative(program1.id(), &mirror, &key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("200u64").unwrap());
+    // Check that the mapping in `token.aleo` is unchanged.
+    let candidate = finalize_store.get_value_speculative(program0.id(), &account, &key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("100u64").unwrap());
+
+    // Ensure a mapping cannot be read from a program that is not imported.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program unimported.aleo;
+
+function copy:
+    input r0 as address.public;
+    finalize r0;
+
+finalize copy:
+    input r0 as address.public;
+    get token.aleo/account[r0] into r1;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+
+    // Ensure the key type must match the key type of the external mapping.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+import token.aleo;
+
+program mismatch.aleo;
+
+function copy:
+    input r0 as field.public;
+    finalize r0;
+
+finalize copy:
+    input r0 as field.public;
+    get token.aleo/account[r0] into r1;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+
+    // Ensure a locator cannot reference a mapping in the current program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program self_ref.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function copy:
+    input r0 as address.public;
+    finalize r0;
+
+finalize copy:
+    input r0 as address.public;
+    get self_ref.aleo/account[r0] into r1;",
+    )
+    .unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_execute_and_finalize_block_timestamp_and_hash() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program clock.aleo;
+
+mapping timestamps:
+    key owner as address.public;
+    value timestamp as i64.public;
+
+mapping hashes:
+    key owner as address.public;
+    value hash as field.public;
+
+function record:
+    input r0 as address.public;
+    finalize r0;
+
+finalize record:
+    input r0 as address.public;
+    set block.timestamp into timestamps[r0];
+    set block.previous_hash into hashes[r0];",
+    )
+    .unwrap();
+
+    // Declare the mapping names.
+    let timestamps = Identifier::from_str("timestamps").unwrap();
+    let hashes = Identifier::from_str("hashes").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("record").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Deploy the program.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    let (stack, _) = process.finalize_deployment(&finalize_store, &deployment).unwrap();
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Authorize the function call.
+    let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+        .unwrap();
+
+    // Execute the request.
+    let (_, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    // Prepare the trace.
+    trace.prepare(Query::from(block_store)).unwrap();
+    // Prove the execution.
+    let execution = trace.prove_execution::<CurrentAleo, _>("clock", rng).unwrap();
+    // Verify the execution.
+    process.verify_execution(&execution).unwrap();
+
+    // Construct a finalize state with a block timestamp and previous block hash.
+    let previous_block_hash = Field::<CurrentNetwork>::from_u64(123);
+    let mut previous_block_hash_bytes = [0u8; 32];
+    previous_block_hash_bytes.copy_from_slice(&previous_block_hash.to_bytes_le().unwrap());
+    let state = FinalizeGlobalState::from(1, 1_685_000_000, previous_block_hash_bytes, [0u8; 32]);
+
+    // Finalize the execution.
+    process.finalize_execution(state, &finalize_store, &execution).unwrap();
+
+    // Check that the block timestamp and previous block hash were stored.
+    let key = Plaintext::from(Literal::Address(caller));
+    let candidate = finalize_store.get_value_speculative(program.id(), &timestamps, &key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("1685000000i64").unwrap());
+    let candidate = finalize_store.get_value_speculative(program.id(), &hashes, &key).unwrap().unwrap();
+    assert_eq!(candidate, Value::from_str("123field").unwrap());
+
+    // Ensure the block timestamp is type checked as an `i64`.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program clock.aleo;
+
+mapping timestamps:
+    key owner as address.public;
+    value timestamp as u64.public;
+
+function record:
+    input r0 as address.public;
+    finalize r0;
+
+finalize record:
+    input r0 as address.public;
+    set block.timestamp into timestamps[r0];",
+    )
+    .unwrap();
+    assert!(Process::load().unwrap().add_program(&program).is_err());
+
+    // Ensure the block timestamp is not accessible in a function scope.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program clock.aleo;
+
+function record:
+    add block.timestamp 1i64 into r0;
+    output r0 as i64.public;",
+    )
+    .unwrap();
+    assert!(Process::load().unwrap().add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_execute_and_finalize_bounded_loop() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program looper.aleo;
+
+mapping counter:
+    key id as u8.public;
+    value count as u8.public;
+
+function run:
+    input r0 as u8.public;
+    finalize r0;
+
+finalize run:
+    input r0 as u8.public;
+    position start;
+    get.or_use counter[0u8] 0u8 into r1;
+    add r1 1u8 into r2;
+    set r2 into counter[0u8];
+    branch.neq r2 r0 to start max 4;",
+    )
+    .unwrap();
+
+    // Declare the mapping name.
+    let mapping_name = Identifier::from_str("counter").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("run").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Deploy the program.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    let (stack, _) = process.finalize_deployment(&finalize_store, &deployment).unwrap();
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // A helper to execute `run` with the given target count.
+    let mut execute = |target: &str| {
+        // Authorize the function call.
+        let r0 = Value::<CurrentNetwork>::from_str(target).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+            .unwrap();
+        // Execute the request.
+        let (_, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+        // Prepare the trace.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        trace.prepare(Query::from(block_store)).unwrap();
+        // Prove the execution.
+        trace.prove_execution::<CurrentAleo, _>("looper", rng).unwrap()
+    };
+    let first = execute("3u8");
+    let second = execute("10u8");
+
+    // A helper to retrieve the current count.
+    let count = || {
+        let key = Plaintext::from(Literal::U8(U8::new(0)));
+        finalize_store.get_value_speculative(program.id(), &mapping_name, &key).unwrap().unwrap()
+    };
+
+    // Finalize the first execution, which iterates twice to reach the target count.
+    process.finalize_execution(sample_finalize_state(1), &finalize_store, &first).unwrap();
+    assert_eq!(count(), Value::from_str("3u8").unwrap());
+
+    // Finalize the second execution, which requires more than the maximum number of iterations.
+    assert!(process.finalize_execution(sample_finalize_state(2), &finalize_store, &second).is_err());
+    // Ensure the writes of the failed execution were reverted.
+    assert_eq!(count(), Value::from_str("3u8").unwrap());
+}
+
+#[test]
+fn test_process_execute_and_finalize_loop_write_limit() {
+    // Initialize a new program, whose loop may iterate more times than the maximum number of writes.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program write_looper.aleo;
+
+mapping counter:
+    key id as u8.public;
+    value count as u8.public;
+
+function run:
+    input r0 as u8.public;
+    finalize r0;
+
+finalize run:
+    input r0 as u8.public;
+    position start;
+    get.or_use counter[0u8] 0u8 into r1;
+    add r1 1u8 into r2;
+    set r2 into counter[0u8];
+    branch.neq r2 r0 to start max 16;",
+    )
+    .unwrap();
+
+    // Declare the mapping name.
+    let mapping_name = Identifier::from_str("counter").unwrap();
+    // Declare the function name.
+    let function_name = Identifier::from_str("run").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the process.
+    let mut process = Process::load().unwrap();
+
+    // Initialize a new finalize store.
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Deploy the program.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    let (stack, _) = process.finalize_deployment(&finalize_store, &deployment).unwrap();
+    process.add_stack(stack);
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // A helper to execute `run` with the given target count.
+    let mut execute = |target: &str| {
+        // Authorize the function call.
+        let r0 = Value::<CurrentNetwork>::from_str(target).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+            .unwrap();
+        // Execute the request.
+        let (_, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+        // Prepare the trace.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        trace.prepare(Query::from(block_store)).unwrap();
+        // Prove the execution.
+        trace.prove_execution::<CurrentAleo, _>("write_looper", rng).unwrap()
+    };
+    let first = execute("12u8");
+    let second = execute("10u8");
+
+    // A helper to retrieve the current count.
+    let count = || {
+        let key = Plaintext::from(Literal::U8(U8::new(0)));
+        finalize_store.get_value_speculative(program.id(), &mapping_name, &key).unwrap()
+    };
+
+    // Finalize the first execution, which is within its iterations, but exceeds the maximum number of writes.
+    assert!(process.finalize_execution(sample_finalize_state(1), &finalize_store, &first).is_err());
+    // Ensure the writes of the failed execution were reverted.
+    assert_eq!(count(), None);
+
+    // Finalize the second execution, which writes exactly the maximum number of times.
+    process.finalize_execution(sample_finalize_state(2), &finalize_store, &second).unwrap();
+    assert_eq!(count(), Some(Value::from_str("10u8").unwrap()));
+}
+
 #[test]
 fn test_execution_order() {
     // Initialize a new program.
@@ -2352,3 +2797,487 @@ fn get_assignment(
     let assignment = assignments.read().last().unwrap().0.clone();
     assignment
 }
+
+#[test]
+fn test_verify_authorization() {
+    // Initialize a new program.
+    let (string, program0) = Program::<CurrentNetwork>::parse(
+        r"
+program zero.aleo;
+
+function c:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    output r2 as u8.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Construct the process.
+    let mut process = test_helpers::sample_process(&program0);
+
+    // Initialize another program.
+    let (string, program1) = Program::<CurrentNetwork>::parse(
+        r"
+import zero.aleo;
+
+program one.aleo;
+
+function b:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    call zero.aleo/c r0 r1 into r2;
+    output r2 as u8.private;",
+    )
+    .unwrap();
+    assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+    // Add the program to the process.
+    process.add_program(&program1).unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the caller.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("b").unwrap();
+
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str("1u8").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("2u8").unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 2);
+
+    // Verify the authorization.
+    process.verify_authorization(&authorization).unwrap();
+
+    // Round-trip the authorization through its string and byte representations.
+    let candidate = Authorization::<CurrentNetwork>::from_str(&authorization.to_string()).unwrap();
+    assert_eq!(authorization, candidate);
+    process.verify_authorization(&candidate).unwrap();
+    let candidate = Authorization::<CurrentNetwork>::from_bytes_le(&authorization.to_bytes_le().unwrap()).unwrap();
+    assert_eq!(authorization, candidate);
+    process.verify_authorization(&candidate).unwrap();
+
+    // Ensure an authorization with the requests out of order is rejected.
+    let requests = authorization.to_vec_deque();
+    let reordered = Authorization::new(&[requests[1].clone(), requests[0].clone()]);
+    assert!(process.verify_authorization(&reordered).is_err());
+
+    // Ensure an authorization with a missing request is rejected.
+    let truncated = Authorization::new(&[requests[0].clone()]);
+    assert!(process.verify_authorization(&truncated).is_err());
+
+    // Ensure an authorization with an extra request is rejected.
+    let extended = Authorization::new(&[requests[0].clone(), requests[1].clone(), requests[1].clone()]);
+    assert!(process.verify_authorization(&extended).is_err());
+
+    // Ensure an authorization with a request signed by a different caller is rejected.
+    let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let r0 = Value::<CurrentNetwork>::from_str("1u8").unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("2u8").unwrap();
+    let other = process
+        .authorize::<CurrentAleo, _>(&other_private_key, program1.id(), function_name, [r0, r1].iter(), rng)
+        .unwrap();
+    let mixed = Authorization::new(&[requests[0].clone(), other.get(1).unwrap()]);
+    assert!(process.verify_authorization(&mixed).is_err());
+
+    // Execute the deserialized authorization.
+    let (response, _trace) = process.execute::<CurrentAleo>(candidate).unwrap();
+    let candidate = response.outputs();
+    assert_eq!(1, candidate.len());
+    assert_eq!(Value::<CurrentNetwork>::from_str("3u8").unwrap(), candidate[0]);
+}
+
+#[test]
+fn test_process_encrypt() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program memo.aleo;
+
+struct memo:
+    amount as u64;
+    note as field;
+
+struct memo_ciphertext:
+    nonce as group;
+    c0 as field;
+    c1 as field;
+    c2 as field;
+
+function send:
+    input r0 as memo.private;
+    input r1 as address.public;
+    encrypt r0 r1 into r2 as memo_ciphertext;
+    output r2 as memo_ciphertext.public;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = super::test_helpers::sample_process(&program);
+
+    // Initialize the caller and the recipient.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let recipient_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let recipient_view_key = ViewKey::try_from(&recipient_private_key).unwrap();
+    let recipient = Address::try_from(&recipient_private_key).unwrap();
+
+    // Prepare the memo.
+    let memo = Plaintext::<CurrentNetwork>::from_str("{ amount: 5u64, note: 2field }").unwrap();
+    let inputs = [Value::Plaintext(memo.clone()), Value::from_str(&recipient.to_string()).unwrap()];
+
+    // Encrypt the memo to the recipient.
+    let encrypt = |rng: &mut TestRng| {
+        let authorization =
+            process.authorize::<CurrentAleo, _>(&caller_private_key, program.id(), "send", inputs.iter(), rng).unwrap();
+        let expected = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        let (response, _trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+        assert_eq!(expected.outputs(), response.outputs());
+        match &response.outputs()[0] {
+            Value::Plaintext(output) => synthesizer_program::Encrypt::to_ciphertext(output).unwrap(),
+            _ => unreachable!("The output must be a plaintext"),
+        }
+    };
+    let (nonce, ciphertext) = encrypt(rng);
+
+    // Ensure the recipient can decrypt the memo.
+    assert_eq!(memo, ciphertext.decrypt(recipient_view_key, nonce).unwrap());
+    // Ensure another view key does not recover the memo.
+    let other_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+    assert!(ciphertext.decrypt(other_view_key, nonce).map_or(true, |candidate| candidate != memo));
+
+    // Ensure encrypting the same memo again uses a new randomizer.
+    let (other_nonce, other_ciphertext) = encrypt(rng);
+    assert_ne!(nonce, other_nonce);
+    assert_ne!(ciphertext, other_ciphertext);
+    assert_eq!(memo, other_ciphertext.decrypt(recipient_view_key, other_nonce).unwrap());
+}
+
+#[test]
+fn test_process_encrypt_invalid_types() {
+    // Ensure the destination struct must hold the nonce and the ciphertext.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program memo.aleo;
+
+struct memo_ciphertext:
+    nonce as group;
+    c0 as field;
+    c1 as field;
+
+function send:
+    input r0 as u64.private;
+    input r1 as address.public;
+    encrypt r0 r1 into r2 as memo_ciphertext;
+    output r2 as memo_ciphertext.public;",
+    )
+    .unwrap();
+    assert!(Process::load().unwrap().add_program(&program).is_err());
+
+    // Ensure the nonce must be the first member of the destination struct.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program memo.aleo;
+
+struct memo_ciphertext:
+    c0 as field;
+    nonce as group;
+
+function send:
+    input r0 as u64.private;
+    input r1 as address.public;
+    encrypt r0 r1 into r2 as memo_ciphertext;
+    output r2 as memo_ciphertext.public;",
+    )
+    .unwrap();
+    assert!(Process::load().unwrap().add_program(&program).is_err());
+
+    // Ensure strings cannot be encrypted, as their ciphertext has no fixed size.
+    let program = Program::<CurrentNetwork>::from_str(
+        r#"
+program memo.aleo;
+
+struct memo_ciphertext:
+    nonce as group;
+    c0 as field;
+
+function send:
+    input r0 as address.public;
+    encrypt "memo" r0 into r1 as memo_ciphertext;
+    output r1 as memo_ciphertext.public;"#,
+    )
+    .unwrap();
+    assert!(Process::load().unwrap().add_program(&program).is_err());
+
+    // Ensure the second operand must be an address.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program memo.aleo;
+
+struct memo_ciphertext:
+    nonce as group;
+    c0 as field;
+
+function send:
+    input r0 as u64.private;
+    input r1 as field.public;
+    encrypt r0 r1 into r2 as memo_ciphertext;
+    output r2 as memo_ciphertext.public;",
+    )
+    .unwrap();
+    assert!(Process::load().unwrap().add_program(&program).is_err());
+
+    // Ensure `encrypt` is forbidden in finalize, as it has no transition view key.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program memo.aleo;
+
+struct memo_ciphertext:
+    nonce as group;
+    c0 as field;
+
+mapping memos:
+    key left as address.public;
+    value right as memo_ciphertext.public;
+
+function send:
+    input r0 as u64.public;
+    input r1 as address.public;
+    finalize r0 r1;
+
+finalize send:
+    input r0 as u64.public;
+    input r1 as address.public;
+    encrypt r0 r1 into r2 as memo_ciphertext;
+    set r2 into memos[r1];",
+    )
+    .unwrap();
+    assert!(Process::load().unwrap().add_program(&program).is_err());
+
+    // Ensure the ciphertext can be stored in a mapping, when encrypted outside of finalize.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program memo.aleo;
+
+struct memo_ciphertext:
+    nonce as group;
+    c0 as field;
+
+mapping memos:
+    key left as address.public;
+    value right as memo_ciphertext.public;
+
+function send:
+    input r0 as u64.private;
+    input r1 as address.public;
+    encrypt r0 r1 into r2 as memo_ciphertext;
+    finalize r1 r2;
+
+finalize send:
+    input r0 as address.public;
+    input r1 as memo_ciphertext.public;
+    set r1 into memos[r0];",
+    )
+    .unwrap();
+    assert!(Process::load().unwrap().add_program(&program).is_ok());
+}
+
+#[test]
+fn test_process_decrypt() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program memo.aleo;
+
+struct memo:
+    amount as u64;
+    note as field;
+
+struct memo_ciphertext:
+    nonce as group;
+    c0 as field;
+    c1 as field;
+    c2 as field;
+
+function send:
+    input r0 as memo.private;
+    input r1 as address.public;
+    encrypt r0 r1 into r2 as memo_ciphertext;
+    output r2 as memo_ciphertext.public;
+
+function receive:
+    input r0 as memo_ciphertext.public;
+    input r1 as scalar.private;
+    decrypt r0 r1 into r2 as memo;
+    output r2 as memo.private;",
+    )
+    .unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = super::test_helpers::sample_process(&program);
+
+    // Initialize the sender and the recipient.
+    let sender_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let recipient_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let recipient_view_key = ViewKey::try_from(&recipient_private_key).unwrap();
+    let recipient = Address::try_from(&recipient_private_key).unwrap();
+
+    // Runs the given function, and returns its output.
+    let run = |private_key: &_, function_name: &str, inputs: [Value<_>; 2], rng: &mut TestRng| {
+        let authorization =
+            process.authorize::<CurrentAleo, _>(private_key, program.id(), function_name, inputs.iter(), rng).unwrap();
+        let expected = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        let (response, _trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+        assert_eq!(expected.outputs(), response.outputs());
+        response.outputs()[0].clone()
+    };
+
+    // Encrypt the memo to the recipient.
+    let memo = Value::<CurrentNetwork>::from_str("{ amount: 5u64, note: 2field }").unwrap();
+    let ciphertext =
+        run(&sender_private_key, "send", [memo.clone(), Value::from_str(&recipient.to_string()).unwrap()], rng);
+
+    // Ensure the recipient can decrypt the memo.
+    let view_key = Value::from_str(&(*recipient_view_key).to_string()).unwrap();
+    assert_eq!(memo, run(&recipient_private_key, "receive", [ciphertext, view_key], rng));
+
+    // Ensure `decrypt` is forbidden in finalize, as the view key would be public.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program memo.aleo;
+
+struct memo_ciphertext:
+    nonce as group;
+    c0 as field;
+
+function receive:
+    input r0 as memo_ciphertext.public;
+    input r1 as scalar.public;
+    finalize r0 r1;
+
+finalize receive:
+    input r0 as memo_ciphertext.public;
+    input r1 as scalar.public;
+    decrypt r0 r1 into r2 as u64;",
+    )
+    .unwrap();
+    assert!(Process::load().unwrap().add_program(&program).is_err());
+
+    // Ensure the struct must hold the ciphertext of the plaintext type.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program memo.aleo;
+
+struct memo_ciphertext:
+    nonce as group;
+    c0 as field;
+
+function receive:
+    input r0 as memo_ciphertext.public;
+    input r1 as scalar.private;
+    decrypt r0 r1 into r2 as u128;
+    output r2 as u128.private;",
+    )
+    .unwrap();
+    assert!(Process::load().unwrap().add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_mapping_size_limits() {
+    // Initialize a new process.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Ensure a mapping within its declared size limit is accepted.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program bounded.aleo;
+
+mapping account:
+    key owner as field.public;
+    value amount as field.public;
+    limit 2;
+
+function noop:",
+    )
+    .unwrap();
+    process.add_program(&program).unwrap();
+    // Ensure a field literal may need 2 field elements.
+    let stack = process.get_stack(program.id()).unwrap();
+    let field_type = PlaintextType::from_str("field").unwrap();
+    assert_eq!(stack.get_max_size_in_fields(&field_type).unwrap(), 2);
+
+    // Ensure a mapping exceeding its declared size limit is rejected.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program overflow.aleo;
+
+mapping account:
+    key owner as field.public;
+    value amount as u8.public;
+    limit 1;
+
+function noop:",
+    )
+    .unwrap();
+    let error = process.add_program(&program).unwrap_err();
+    assert!(error.to_string().contains("Mapping 'account' key type 'field'"), "{error}");
+
+    // Ensure the size limit accounts for the members of a struct, and the maximum length of a string.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program names.aleo;
+
+struct name:
+    first as string;
+    last as string;
+
+mapping names:
+    key owner as address.public;
+    value full as name.public;
+    limit 16;
+
+function noop:",
+    )
+    .unwrap();
+    let error = process.add_program(&program).unwrap_err();
+    assert!(error.to_string().contains("Mapping 'names' value type 'name'"), "{error}");
+
+    // Ensure a nested struct that can exceed the maximum data size is not deployable, even without a declared limit.
+    let members = |member_type: &str| (0..32).map(|i| format!("    m{i} as {member_type};\n")).collect::<String>();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        r"
+program nested.aleo;
+
+struct inner:
+{}
+struct outer:
+{}
+mapping nested:
+    key owner as address.public;
+    value data as outer.public;
+
+function noop:",
+        members("string"),
+        members("inner")
+    ))
+    .unwrap();
+    let rng = &mut TestRng::default();
+    let error = process.deploy::<CurrentAleo, _>(&program, rng).unwrap_err();
+    assert!(error.to_string().contains("Mapping 'nested' value type 'outer'"), "{error}");
+    // Ensure the default size limit is not applied to a previously-deployed program.
+    process.add_program(&program).unwrap();
+}
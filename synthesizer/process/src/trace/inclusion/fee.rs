@@ -17,7 +17,7 @@ use super::*;
 macro_rules! prepare_fee_impl {
     ($self:ident, $fee_transition:ident, $query:ident, $get_state_path_for_commitment:ident $(, $await:ident)?) => {{
         // Ensure the fee has the correct program ID and function.
-        ensure!($fee_transition.is_fee(), "Incorrect transition type for the fee, expected 'credits.aleo/fee'");
+        ensure!($fee_transition.is_fee_private(), "Incorrect transition type for the fee, expected 'credits.aleo/fee'");
 
         // Initialize an empty transaction tree.
         let transaction_tree = N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&[])?;
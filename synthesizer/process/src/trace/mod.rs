@@ -107,17 +107,23 @@ impl<N: Network> Trace<N> {
             // If there is 1 transition, check if the transition is a fee transition.
             1 => {
                 &self.transitions[0].program_id().to_string() == "credits.aleo"
-                    && &self.transitions[0].function_name().to_string() == "fee"
+                    && matches!(self.transitions[0].function_name().to_string().as_str(), "fee" | "fee_public")
             }
             // Otherwise, set the indicator to 'false'.
             _ => false,
         }
     }
 
+    /// Returns `true` if the trace is for a fee transition that spends a record.
+    /// Note: A public fee does not spend a record, and is prepared like an execution.
+    fn is_fee_private(&self) -> bool {
+        self.is_fee() && &self.transitions[0].function_name().to_string() == "fee"
+    }
+
     /// Returns the inclusion assignments and global state root for the current transition(s).
     pub fn prepare(&mut self, query: impl QueryTrait<N>) -> Result<()> {
         // Compute the inclusion assignments.
-        let (inclusion_assignments, global_state_root) = match self.is_fee() {
+        let (inclusion_assignments, global_state_root) = match self.is_fee_private() {
             true => self.inclusion_tasks.prepare_fee(&self.transitions[0], query)?,
             false => self.inclusion_tasks.prepare_execution(&self.transitions, query)?,
         };
@@ -133,7 +139,7 @@ impl<N: Network> Trace<N> {
     #[cfg(feature = "async")]
     pub async fn prepare_async(&mut self, query: impl QueryTrait<N>) -> Result<()> {
         // Compute the inclusion assignments.
-        let (inclusion_assignments, global_state_root) = match self.is_fee() {
+        let (inclusion_assignments, global_state_root) = match self.is_fee_private() {
             true => self.inclusion_tasks.prepare_fee_async(&self.transitions[0], query).await?,
             false => self.inclusion_tasks.prepare_execution_async(&self.transitions, query).await?,
         };
@@ -180,8 +186,12 @@ impl<N: Network> Trace<N> {
         // Retrieve the inclusion assignments.
         let inclusion_assignments =
             self.inclusion_assignments.get().ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?;
-        // Ensure there is only 1 inclusion assignment.
-        ensure!(inclusion_assignments.len() == 1, "Expected 1 inclusion assignment for proving the fee");
+        // Ensure there is only 1 inclusion assignment for a private fee, and none for a public fee.
+        let num_expected_assignments = usize::from(self.is_fee_private());
+        ensure!(
+            inclusion_assignments.len() == num_expected_assignments,
+            "Expected {num_expected_assignments} inclusion assignment(s) for proving the fee"
+        );
         // Retrieve the global state root.
         let global_state_root =
             self.global_state_root.get().ok_or_else(|| anyhow!("Global state root has not been set"))?;
@@ -191,7 +201,7 @@ impl<N: Network> Trace<N> {
         let proving_tasks = self.transition_tasks.values().cloned().collect();
         // Compute the proof.
         let (global_state_root, proof) = Self::prove_batch::<A, R>(
-            "credits.aleo/fee",
+            &Locator::new(*fee_transition.program_id(), *fee_transition.function_name()).to_string(),
             proving_tasks,
             inclusion_assignments,
             *global_state_root,
@@ -230,13 +240,14 @@ impl<N: Network> Trace<N> {
         }
         // Retrieve the proof.
         let Some(proof) = fee.proof() else { bail!("Expected the fee to contain a proof") };
-        // Ensure the transition contains an input record.
-        if fee.transition().inputs().iter().filter(|i| matches!(i, Input::Record(..))).count() != 1 {
-            bail!("Inclusion expected the fee to contain an input record")
+        // Ensure the transition contains an input record for a private fee, and none for a public fee.
+        let num_expected_records = usize::from(!fee.is_fee_public());
+        if fee.transition().inputs().iter().filter(|i| matches!(i, Input::Record(..))).count() != num_expected_records {
+            bail!("Inclusion expected the fee to contain {num_expected_records} input record(s)")
         }
         // Verify the fee proof.
         match Self::verify_batch(
-            "credits.aleo/fee",
+            &Locator::new(*fee.program_id(), *fee.function_name()).to_string(),
             vec![verifier_inputs],
             global_state_root,
             [fee.transition()].into_iter(),
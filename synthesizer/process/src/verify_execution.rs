@@ -177,13 +177,20 @@ impl<N: Network> Process<N> {
                     let num_operands = command.operands().len();
                     // Retrieve the number of inputs.
                     let num_inputs = logic.inputs().len();
+                    // Retrieve the number of finalize outputs of the callees.
+                    let stack = self.get_stack(transition.program_id())?;
+                    let num_callee_outputs = stack.get_callee_finalize_output_types(function.name())?.len();
 
                     // Ensure the number of inputs for finalize is within the allowed range.
                     ensure!(finalize.len() <= N::MAX_INPUTS, "Transition exceeds maximum inputs for finalize");
                     // Ensure the number of inputs for finalize matches in the finalize command.
                     ensure!(finalize.len() == num_operands, "The number of inputs for finalize is incorrect");
-                    // Ensure the number of inputs for finalize matches in the finalize logic.
-                    ensure!(finalize.len() == num_inputs, "The number of inputs for finalize is incorrect");
+                    // Ensure the number of inputs for finalize matches the finalize logic.
+                    // Note: The remaining inputs of the finalize logic are the finalize outputs of the callees.
+                    ensure!(
+                        finalize.len() + num_callee_outputs == num_inputs,
+                        "The number of inputs for finalize is incorrect"
+                    );
 
                     // Convert the finalize inputs into concatenated bits.
                     let finalize_bits = finalize.iter().flat_map(ToBits::to_bits_le).collect::<Vec<_>>();
@@ -39,14 +39,19 @@ impl<N: Network> Process<N> {
                 .to_bits_le(),
         )?;
 
-        // Ensure there are exactly 3 inputs.
-        ensure!(fee.inputs().len() == 3, "Incorrect number of inputs to the fee transition");
+        // Retrieve the number of expected inputs, which is 3 for a private fee and 2 for a public fee.
+        let num_expected_inputs = match fee.is_fee_public() {
+            true => 2,
+            false => 3,
+        };
+        // Ensure there are exactly the expected number of inputs.
+        ensure!(fee.inputs().len() == num_expected_inputs, "Incorrect number of inputs to the fee transition");
         // Ensure each input is valid.
         if fee.inputs().iter().enumerate().any(|(index, input)| !input.verify(function_id, fee.tcm(), index)) {
             bail!("Failed to verify a fee input")
         }
-        // Retrieve the 3rd input as the candidate ID.
-        let candidate_id = match fee.inputs().get(2) {
+        // Retrieve the last input as the candidate ID.
+        let candidate_id = match fee.inputs().last() {
             Some(Input::Public(_, Some(Plaintext::Literal(Literal::Field(candidate_id), _)))) => *candidate_id,
             _ => bail!("Failed to get the deployment or execution ID in the fee transition"),
         };
@@ -77,6 +82,17 @@ impl<N: Network> Process<N> {
         inputs.extend(fee.inputs().iter().flat_map(|input| input.verifier_inputs()));
         // Extend the inputs with the output IDs.
         inputs.extend(fee.outputs().iter().flat_map(|output| output.verifier_inputs()));
+        // If this is a public fee, extend the inputs with the checksum of the finalize inputs.
+        if fee.is_fee_public() {
+            // Retrieve the finalize inputs.
+            let Some(finalize) = fee.finalize() else {
+                bail!("The public fee transition is missing inputs for 'finalize'")
+            };
+            // Compute the checksum of the finalize inputs.
+            let checksum = N::hash_bhp1024(&finalize.iter().flat_map(ToBits::to_bits_le).collect::<Vec<_>>())?;
+            // Extend the inputs with the checksum.
+            inputs.push(*checksum);
+        }
         lap!(timer, "Construct the verifier inputs");
 
         // Retrieve the stack.
@@ -91,14 +107,29 @@ impl<N: Network> Process<N> {
         #[cfg(debug_assertions)]
         println!("Fee public inputs ({} elements): {:#?}", inputs.len(), inputs);
 
-        // Ensure the fee contains input records.
-        ensure!(
-            fee.inputs().iter().any(|input| matches!(input, Input::Record(..))),
-            "The fee proof is the wrong type (found *no* input records)"
-        );
-
-        // Ensure the fee transition does not contain a finalize scope.
-        ensure!(fee.finalize().is_none(), "The fee transition should not contain finalize inputs");
+        match fee.is_fee_public() {
+            true => {
+                // Ensure the public fee does not contain input records.
+                ensure!(
+                    !fee.inputs().iter().any(|input| matches!(input, Input::Record(..))),
+                    "The public fee proof is the wrong type (found input records)"
+                );
+                // Ensure the public fee transition contains the finalize inputs for the function.
+                ensure!(
+                    function.finalize().map(|(_, logic)| logic.inputs().len()) == fee.finalize().map(|f| f.len()),
+                    "The public fee transition contains the wrong number of finalize inputs"
+                );
+            }
+            false => {
+                // Ensure the fee contains input records.
+                ensure!(
+                    fee.inputs().iter().any(|input| matches!(input, Input::Record(..))),
+                    "The fee proof is the wrong type (found *no* input records)"
+                );
+                // Ensure the fee transition does not contain a finalize scope.
+                ensure!(fee.finalize().is_none(), "The fee transition should not contain finalize inputs");
+            }
+        }
 
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(stack.program_id(), function.name())?;
@@ -14,6 +14,26 @@
 
 use super::*;
 
+/// The version of a program whose components use the legacy encodings.
+pub(crate) const PROGRAM_VERSION_LEGACY: u8 = 0;
+/// The version of a program that may declare a constructor, loop bounds, finalize outputs,
+/// and mapping size limits, or access the mappings of imported programs.
+pub(crate) const PROGRAM_VERSION_1: u8 = 1;
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Returns the lowest version that can encode the program.
+    /// Note: A program that only uses legacy features keeps the legacy version, so its bytes are unchanged.
+    fn version(&self) -> u8 {
+        let constructor = match self.constructor.is_some() {
+            true => PROGRAM_VERSION_1,
+            false => PROGRAM_VERSION_LEGACY,
+        };
+        let mappings = self.mappings.values().map(|mapping| mapping.version());
+        let functions = self.functions.values().map(|function| function.version());
+        mappings.chain(functions).fold(constructor, u8::max)
+    }
+}
+
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> FromBytes
     for ProgramCore<N, Instruction, Command>
 {
@@ -21,7 +41,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        if version != PROGRAM_VERSION_LEGACY && version != PROGRAM_VERSION_1 {
             return Err(error("Invalid program version"));
         }
 
@@ -46,7 +66,10 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
             // Match the variant.
             match variant {
                 // Read the mapping.
-                0 => program.add_mapping(Mapping::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
+                0 => {
+                    let mapping = Mapping::read_le_versioned(&mut reader, version)?;
+                    program.add_mapping(mapping).map_err(|e| error(e.to_string()))?
+                }
                 // Read the struct.
                 1 => program.add_struct(Struct::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
                 // Read the record.
@@ -54,12 +77,27 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
                 // Read the closure.
                 3 => program.add_closure(ClosureCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
                 // Read the function.
-                4 => program.add_function(FunctionCore::read_le(&mut reader)?).map_err(|e| error(e.to_string()))?,
+                4 => program
+                    .add_function(FunctionCore::read_le_versioned(&mut reader, version)?)
+                    .map_err(|e| error(e.to_string()))?,
                 // Invalid variant.
                 _ => return Err(error(format!("Failed to parse program. Invalid component variant '{variant}'"))),
             }
         }
 
+        // Read the constructor, which may only be declared from version 1.
+        if version != PROGRAM_VERSION_LEGACY {
+            let variant = u8::read_le(&mut reader)?;
+            match variant {
+                0 => (),
+                1 => {
+                    let constructor = ConstructorCore::read_le(&mut reader)?;
+                    program.add_constructor(constructor).map_err(|e| error(e.to_string()))?;
+                }
+                _ => return Err(error(format!("Failed to parse program. Invalid constructor variant '{variant}'"))),
+            }
+        }
+
         Ok(program)
     }
 }
@@ -69,7 +107,8 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
 {
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        0u8.write_le(&mut writer)?;
+        let version = self.version();
+        version.write_le(&mut writer)?;
 
         // Write the program ID.
         self.id.write_le(&mut writer)?;
@@ -91,7 +130,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
                         // Write the variant.
                         0u8.write_le(&mut writer)?;
                         // Write the mapping.
-                        mapping.write_le(&mut writer)?;
+                        mapping.write_le_versioned(&mut writer, version)?;
                     }
                     None => return Err(error(format!("Mapping '{identifier}' is not defined"))),
                 },
@@ -127,13 +166,24 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
                         // Write the variant.
                         4u8.write_le(&mut writer)?;
                         // Write the function.
-                        function.write_le(&mut writer)?;
+                        function.write_le_versioned(&mut writer, version)?;
                     }
                     None => return Err(error(format!("Function '{identifier}' is not defined."))),
                 },
             }
         }
 
+        // Write the constructor, which may only be declared from version 1.
+        if version != PROGRAM_VERSION_LEGACY {
+            match &self.constructor {
+                None => 0u8.write_le(&mut writer)?,
+                Some(constructor) => {
+                    1u8.write_le(&mut writer)?;
+                    constructor.write_le(&mut writer)?;
+                }
+            }
+        }
+
         Ok(())
     }
 }
@@ -170,6 +220,68 @@ function compute:
         assert_eq!(expected, candidate);
         assert_eq!(expected_bytes, candidate.to_bytes_le()?);
 
+        // A program without a constructor is written in the legacy version.
+        assert_eq!(expected_bytes[0], 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_constructor() -> Result<()> {
+        let program = r"
+program token.aleo;
+
+mapping supply:
+    key id as u8.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.private;
+
+constructor:
+    set 1000u64 into supply[0u8];";
+
+        // Initialize a new program.
+        let expected = Program::<CurrentNetwork>::from_str(program)?;
+        let expected_bytes = expected.to_bytes_le()?;
+
+        let candidate = Program::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert!(candidate.constructor().is_some());
+        assert_eq!(expected_bytes[0], 1);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+
+        Ok(())
+    }
+    #[test]
+    fn test_bytes_external_mapping() -> Result<()> {
+        let program = r"
+import token.aleo;
+
+program exchange.aleo;
+
+function check:
+    input r0 as address.public;
+    finalize r0;
+
+finalize check:
+    input r0 as address.public;
+    get token.aleo/balances[r0] into r1;";
+
+        // Initialize a new program.
+        let expected = Program::<CurrentNetwork>::from_str(program)?;
+        let expected_bytes = expected.to_bytes_le()?;
+
+        let candidate = Program::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(expected, candidate);
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+
+        // A program that accesses the mapping of an imported program is written in version 1.
+        assert_eq!(expected_bytes[0], 1);
+        assert!(candidate.constructor().is_none());
+
         Ok(())
     }
 }
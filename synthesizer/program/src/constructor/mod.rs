@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod parse;
+
+use crate::{traits::CommandTrait, FinalizeCore};
+use console::{network::prelude::*, program::Identifier};
+
+/// A constructor initializes the program state, and is run exactly once, when the program is deployed.
+/// A constructor takes no inputs, and is of the form `constructor:` followed by its commands.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ConstructorCore<N: Network, Command: CommandTrait<N>> {
+    /// The constructor logic, as a finalize scope without inputs or outputs.
+    logic: FinalizeCore<N, Command>,
+}
+
+impl<N: Network, Command: CommandTrait<N>> ConstructorCore<N, Command> {
+    /// Initializes a new constructor.
+    pub fn new() -> Result<Self> {
+        Ok(Self { logic: FinalizeCore::new(Identifier::from_str(Self::type_name())?) })
+    }
+
+    /// Returns the constructor commands.
+    pub fn commands(&self) -> &[Command] {
+        self.logic.commands()
+    }
+
+    /// Returns the constructor logic, which is run as a finalize scope named `constructor`.
+    pub const fn logic(&self) -> &FinalizeCore<N, Command> {
+        &self.logic
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> ConstructorCore<N, Command> {
+    /// Adds the given command to the constructor.
+    ///
+    /// # Errors
+    /// This method will halt if the command is not a valid finalize command.
+    #[inline]
+    pub fn add_command(&mut self, command: Command) -> Result<()> {
+        self.logic.add_command(command)
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> TypeName for ConstructorCore<N, Command> {
+    /// Returns the type name as a string.
+    #[inline]
+    fn type_name() -> &'static str {
+        "constructor"
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, Command: CommandTrait<N>> Parser for ConstructorCore<N, Command> {
+    /// Parses a string into a constructor.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the 'constructor' keyword from the string.
+        let (string, _) = tag(Self::type_name())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the colon ':' keyword from the string.
+        let (string, _) = tag(":")(string)?;
+
+        // Parse the commands from the string.
+        let (string, commands) = many1(Command::parse)(string)?;
+
+        map_res(take(0usize), move |_| {
+            // Initialize a new constructor.
+            let mut constructor = Self::new()?;
+            if let Err(error) = commands.iter().cloned().try_for_each(|command| constructor.add_command(command)) {
+                eprintln!("{error}");
+                return Err(error);
+            }
+            Ok::<_, Error>(constructor)
+        })(string)
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> FromStr for ConstructorCore<N, Command> {
+    type Err = Error;
+
+    /// Returns a constructor from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> Debug for ConstructorCore<N, Command> {
+    /// Prints the constructor as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> Display for ConstructorCore<N, Command> {
+    /// Prints the constructor as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Write the constructor to a string.
+        write!(f, "{}:", Self::type_name())?;
+        self.commands().iter().try_for_each(|command| write!(f, "\n    {command}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Constructor;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_constructor_parse() {
+        let constructor = Constructor::<CurrentNetwork>::parse(
+            r"
+constructor:
+    add 1u64 2u64 into r0;
+    set r0 into supply[0u8];",
+        )
+        .unwrap()
+        .1;
+        assert_eq!(2, constructor.commands().len());
+        assert_eq!("constructor", constructor.logic().name().to_string());
+        assert!(constructor.logic().inputs().is_empty());
+
+        // Ensure a constructor must contain commands.
+        assert!(Constructor::<CurrentNetwork>::parse("constructor:").is_err());
+        // Ensure a constructor cannot take inputs.
+        let candidate = r"
+constructor:
+    input r0 as u64.public;
+    set r0 into supply[0u8];";
+        assert!(Constructor::<CurrentNetwork>::from_str(candidate).is_err());
+    }
+
+    #[test]
+    fn test_constructor_display() {
+        let expected = r"constructor:
+    add 1u64 2u64 into r0;
+    set r0 into supply[0u8];";
+        let constructor = Constructor::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(expected, format!("{constructor}"),);
+    }
+}
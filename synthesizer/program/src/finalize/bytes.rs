@@ -13,11 +13,20 @@
 // limitations under the License.
 
 use super::*;
+use crate::bytes::{PROGRAM_VERSION_1, PROGRAM_VERSION_LEGACY};
+
+impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
+    /// Returns the lowest program version that can encode the finalize, as set by its commands and outputs.
+    pub(crate) fn version(&self) -> u8 {
+        let outputs = match self.outputs.is_empty() {
+            true => PROGRAM_VERSION_LEGACY,
+            false => PROGRAM_VERSION_1,
+        };
+        self.commands.iter().map(|command| command.version()).fold(outputs, u8::max)
+    }
 
-impl<N: Network, Command: CommandTrait<N>> FromBytes for FinalizeCore<N, Command> {
-    /// Reads the finalize from a buffer.
-    #[inline]
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+    /// Reads the finalize from a buffer, using the encodings of the given program version.
+    pub(crate) fn read_le_versioned<R: Read>(mut reader: R, version: u8) -> IoResult<Self> {
         // Read the associated function name.
         let name = Identifier::<N>::read_le(&mut reader)?;
 
@@ -35,22 +44,32 @@ impl<N: Network, Command: CommandTrait<N>> FromBytes for FinalizeCore<N, Command
         }
         let mut commands = Vec::with_capacity(num_commands as usize);
         for _ in 0..num_commands {
-            commands.push(Command::read_le(&mut reader)?);
+            commands.push(Command::read_le_versioned(&mut reader, version)?);
+        }
+
+        // Read the outputs, which are encoded from version 1.
+        let mut outputs = Vec::new();
+        if version != PROGRAM_VERSION_LEGACY {
+            let num_outputs = u16::read_le(&mut reader)?;
+            if num_outputs > u16::try_from(N::MAX_OUTPUTS).map_err(|e| error(e.to_string()))? {
+                return Err(error(format!("Failed to deserialize finalize: too many outputs ({num_outputs})")));
+            }
+            for _ in 0..num_outputs {
+                outputs.push(Output::read_le(&mut reader)?);
+            }
         }
 
         // Initialize a new finalize.
         let mut finalize = Self::new(name);
         inputs.into_iter().try_for_each(|input| finalize.add_input(input)).map_err(|e| error(e.to_string()))?;
         commands.into_iter().try_for_each(|command| finalize.add_command(command)).map_err(|e| error(e.to_string()))?;
+        outputs.into_iter().try_for_each(|output| finalize.add_output(output)).map_err(|e| error(e.to_string()))?;
 
         Ok(finalize)
     }
-}
 
-impl<N: Network, Command: CommandTrait<N>> ToBytes for FinalizeCore<N, Command> {
-    /// Writes the finalize to a buffer.
-    #[inline]
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+    /// Writes the finalize to a buffer, using the encodings of the given program version.
+    pub(crate) fn write_le_versioned<W: Write>(&self, mut writer: W, version: u8) -> IoResult<()> {
         // Write the associated function name.
         self.name.write_le(&mut writer)?;
 
@@ -75,13 +94,47 @@ impl<N: Network, Command: CommandTrait<N>> ToBytes for FinalizeCore<N, Command>
 
         // Write the commands.
         for command in self.commands.iter() {
-            command.write_le(&mut writer)?;
+            command.write_le_versioned(&mut writer, version)?;
+        }
+
+        // Write the outputs, which are encoded from version 1.
+        if version == PROGRAM_VERSION_LEGACY && !self.outputs.is_empty() {
+            return Err(error(format!("Program version {version} cannot declare the outputs of '{}'", self.name)));
+        }
+        if version != PROGRAM_VERSION_LEGACY {
+            // Write the number of outputs for the finalize.
+            let num_outputs = self.outputs.len();
+            match num_outputs <= N::MAX_OUTPUTS {
+                true => u16::try_from(num_outputs).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?,
+                false => return Err(error(format!("Failed to write {num_outputs} outputs as bytes"))),
+            }
+
+            // Write the outputs.
+            for output in self.outputs.iter() {
+                output.write_le(&mut writer)?;
+            }
         }
 
         Ok(())
     }
 }
 
+impl<N: Network, Command: CommandTrait<N>> FromBytes for FinalizeCore<N, Command> {
+    /// Reads the finalize from a buffer.
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_versioned(reader, PROGRAM_VERSION_1)
+    }
+}
+
+impl<N: Network, Command: CommandTrait<N>> ToBytes for FinalizeCore<N, Command> {
+    /// Writes the finalize to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_le_versioned(writer, PROGRAM_VERSION_1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,7 +158,8 @@ finalize main:
     add r0 r1 into r8;
     add r0 r1 into r9;
     add r0 r1 into r10;
-    add r0 r1 into r11;";
+    add r0 r1 into r11;
+    output r11 as field.public;";
 
         let expected = Finalize::<CurrentNetwork>::from_str(finalize_string)?;
         let expected_bytes = expected.to_bytes_le()?;
@@ -116,4 +170,36 @@ finalize main:
         assert_eq!(expected_bytes, candidate.to_bytes_le()?);
         Ok(())
     }
+
+    #[test]
+    fn test_finalize_bytes_legacy() -> Result<()> {
+        let finalize_string = r"
+finalize main:
+    input r0 as field.public;
+    add r0 r0 into r1;";
+
+        let expected = Finalize::<CurrentNetwork>::from_str(finalize_string)?;
+        let expected_bytes = expected.to_bytes_le()?;
+
+        // A legacy program writes the name, inputs, and commands, without outputs.
+        let mut legacy_bytes = expected.name().to_bytes_le()?;
+        legacy_bytes.extend(1u16.to_bytes_le()?);
+        legacy_bytes.extend(expected.inputs()[0].to_bytes_le()?);
+        legacy_bytes.extend(1u16.to_bytes_le()?);
+        legacy_bytes.extend(expected.commands()[0].to_bytes_le()?);
+        let mut candidate_bytes = Vec::new();
+        expected.write_le_versioned(&mut candidate_bytes, PROGRAM_VERSION_LEGACY)?;
+        assert_eq!(legacy_bytes, candidate_bytes);
+        // Version 1 also writes the number of outputs.
+        assert_eq!([legacy_bytes.as_slice(), &0u16.to_bytes_le()?].concat(), expected_bytes);
+
+        let candidate = Finalize::<CurrentNetwork>::read_le_versioned(&legacy_bytes[..], PROGRAM_VERSION_LEGACY)?;
+        assert_eq!(expected, candidate);
+
+        // Ensure a legacy program cannot declare the outputs of a finalize.
+        let finalize =
+            Finalize::<CurrentNetwork>::from_str(&format!("{finalize_string}\n    output r1 as field.public;"))?;
+        assert!(finalize.write_le_versioned(Vec::new(), PROGRAM_VERSION_LEGACY).is_err());
+        Ok(())
+    }
 }
@@ -15,10 +15,13 @@
 mod input;
 use input::*;
 
+mod output;
+use output::*;
+
 mod bytes;
 mod parse;
 
-use crate::traits::CommandTrait;
+use crate::{traits::CommandTrait, MAX_BRANCH_ITERATIONS};
 use console::{
     network::prelude::*,
     program::{Identifier, PlaintextType, Register},
@@ -36,6 +39,8 @@ pub struct FinalizeCore<N: Network, Command: CommandTrait<N>> {
     inputs: IndexSet<Input<N>>,
     /// The commands, in order of execution.
     commands: Vec<Command>,
+    /// The output statements, in order of the desired output.
+    outputs: IndexSet<Output<N>>,
     /// The number of write commands.
     num_writes: u16,
     /// A mapping from `Position`s to their index in `commands`.
@@ -45,7 +50,14 @@ pub struct FinalizeCore<N: Network, Command: CommandTrait<N>> {
 impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
     /// Initializes a new finalize with the given name.
     pub fn new(name: Identifier<N>) -> Self {
-        Self { name, inputs: IndexSet::new(), commands: Vec::new(), num_writes: 0, positions: HashMap::new() }
+        Self {
+            name,
+            inputs: IndexSet::new(),
+            commands: Vec::new(),
+            outputs: IndexSet::new(),
+            num_writes: 0,
+            positions: HashMap::new(),
+        }
     }
 
     /// Returns the name of the associated function.
@@ -68,6 +80,16 @@ impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
         &self.commands
     }
 
+    /// Returns the finalize outputs.
+    pub const fn outputs(&self) -> &IndexSet<Output<N>> {
+        &self.outputs
+    }
+
+    /// Returns the finalize output types.
+    pub fn output_types(&self) -> Vec<PlaintextType<N>> {
+        self.outputs.iter().map(|output| *output.plaintext_type()).collect()
+    }
+
     /// Returns the number of write commands.
     pub const fn num_writes(&self) -> u16 {
         self.num_writes
@@ -77,6 +99,23 @@ impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
     pub const fn positions(&self) -> &HashMap<Identifier<N>, usize> {
         &self.positions
     }
+
+    /// Returns the maximum number of times each command may be executed, in order of the commands.
+    /// A command runs once, plus once per iteration of each loop whose body contains it.
+    pub fn max_executions(&self) -> Vec<u64> {
+        // Initialize the number of executions for each command.
+        let mut max_executions = vec![1u64; self.commands.len()];
+        // Add the iterations of each loop to the commands in its body.
+        for (index, command) in self.commands.iter().enumerate() {
+            // Note: Only backward branches declare a maximum number of iterations.
+            if let (Some(position), Some(max_iterations)) = (command.branch_to(), command.max_iterations()) {
+                if let Some(start) = self.positions.get(position) {
+                    max_executions[*start..=index].iter_mut().for_each(|count| *count += max_iterations as u64);
+                }
+            }
+        }
+        max_executions
+    }
 }
 
 impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
@@ -107,9 +146,13 @@ impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
     /// Adds the given command to finalize.
     ///
     /// # Errors
+    /// This method will halt if an output was previously added.
     /// This method will halt if the maximum number of commands has been reached.
     #[inline]
     pub fn add_command(&mut self, command: Command) -> Result<()> {
+        // Ensure there are no outputs in memory.
+        ensure!(self.outputs.is_empty(), "Cannot add commands after outputs have been added");
+
         // Ensure the maximum number of commands has not been exceeded.
         ensure!(self.commands.len() < N::MAX_COMMANDS, "Cannot add more than {} commands", N::MAX_COMMANDS);
         // Ensure the number of write commands has not been exceeded.
@@ -128,8 +171,21 @@ impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
 
         // Check if the command is a branch command.
         if let Some(position) = command.branch_to() {
-            // Ensure the branch target does not reference an earlier position.
-            ensure!(!self.positions.contains_key(position), "Cannot branch to an earlier position '{position}'");
+            match (self.positions.contains_key(position), command.max_iterations()) {
+                // Ensure a branch to an earlier position declares a valid maximum number of iterations.
+                (true, Some(max_iterations)) => ensure!(
+                    (1..=MAX_BRANCH_ITERATIONS).contains(&max_iterations),
+                    "The maximum number of iterations for '{position}' must be between 1 and {MAX_BRANCH_ITERATIONS}"
+                ),
+                (true, None) => {
+                    bail!("Cannot branch to an earlier position '{position}' without a maximum number of iterations")
+                }
+                // Ensure a branch to a later position does not declare a maximum number of iterations.
+                (false, Some(_)) => {
+                    bail!("Cannot declare a maximum number of iterations for a branch to a later position '{position}'")
+                }
+                (false, None) => (),
+            }
         }
 
         // Check if the command is a position command.
@@ -152,6 +208,23 @@ impl<N: Network, Command: CommandTrait<N>> FinalizeCore<N, Command> {
         self.commands.push(command);
         Ok(())
     }
+
+    /// Adds the output statement to finalize.
+    ///
+    /// # Errors
+    /// This method will halt if the maximum number of outputs has been reached.
+    /// This method will halt if the output statement was previously added.
+    #[inline]
+    fn add_output(&mut self, output: Output<N>) -> Result<()> {
+        // Ensure the maximum number of outputs has not been exceeded.
+        ensure!(self.outputs.len() < N::MAX_OUTPUTS, "Cannot add more than {} outputs", N::MAX_OUTPUTS);
+        // Ensure the output statement was not previously added.
+        ensure!(!self.outputs.contains(&output), "Cannot add duplicate output statement");
+
+        // Insert the output statement.
+        self.outputs.insert(output);
+        Ok(())
+    }
 }
 
 impl<N: Network, Command: CommandTrait<N>> TypeName for FinalizeCore<N, Command> {
@@ -35,6 +35,8 @@ impl<N: Network, Command: CommandTrait<N>> Parser for FinalizeCore<N, Command> {
         let (string, inputs) = many0(Input::parse)(string)?;
         // Parse the commands from the string.
         let (string, commands) = many1(Command::parse)(string)?;
+        // Parse the outputs from the string.
+        let (string, outputs) = many0(Output::parse)(string)?;
 
         map_res(take(0usize), move |_| {
             // Initialize a new finalize.
@@ -47,6 +49,10 @@ impl<N: Network, Command: CommandTrait<N>> Parser for FinalizeCore<N, Command> {
                 eprintln!("{error}");
                 return Err(error);
             }
+            if let Err(error) = outputs.iter().cloned().try_for_each(|output| finalize.add_output(output)) {
+                eprintln!("{error}");
+                return Err(error);
+            }
             Ok::<_, Error>(finalize)
         })(string)
     }
@@ -82,7 +88,8 @@ impl<N: Network, Command: CommandTrait<N>> Display for FinalizeCore<N, Command>
         // Write the finalize to a string.
         write!(f, "{} {}:", Self::type_name(), self.name)?;
         self.inputs.iter().try_for_each(|input| write!(f, "\n    {input}"))?;
-        self.commands.iter().try_for_each(|command| write!(f, "\n    {command}"))
+        self.commands.iter().try_for_each(|command| write!(f, "\n    {command}"))?;
+        self.outputs.iter().try_for_each(|output| write!(f, "\n    {output}"))
     }
 }
 
@@ -137,6 +144,85 @@ finalize foo:
         assert_eq!(1, finalize.commands().len());
     }
 
+    #[test]
+    fn test_finalize_parse_loop() {
+        let finalize = Finalize::<CurrentNetwork>::parse(
+            r"
+finalize foo:
+    input r0 as u8.public;
+    position start;
+    add r0 1u8 into r1;
+    branch.neq r1 0u8 to start max 3;
+    position end;",
+        )
+        .unwrap()
+        .1;
+        assert_eq!(4, finalize.commands().len());
+        assert_eq!(vec![4, 4, 4, 1], finalize.max_executions());
+
+        // Ensure a nested loop is priced with its maximum as a total budget across the passes of the outer loop.
+        let finalize = Finalize::<CurrentNetwork>::parse(
+            r"
+finalize foo:
+    input r0 as u8.public;
+    position outer;
+    position inner;
+    add r0 1u8 into r1;
+    branch.neq r1 0u8 to inner max 5;
+    branch.neq r1 1u8 to outer max 2;",
+        )
+        .unwrap()
+        .1;
+        assert_eq!(vec![3, 8, 8, 8, 3], finalize.max_executions());
+
+        // Ensure a branch to an earlier position must declare a maximum number of iterations.
+        let candidate = r"
+finalize foo:
+    position start;
+    branch.eq 0u8 0u8 to start;";
+        assert!(Finalize::<CurrentNetwork>::parse(candidate).is_err());
+
+        // Ensure the maximum number of iterations must be non-zero.
+        let candidate = r"
+finalize foo:
+    position start;
+    branch.eq 0u8 0u8 to start max 0;";
+        assert!(Finalize::<CurrentNetwork>::parse(candidate).is_err());
+
+        // Ensure a branch to a later position cannot declare a maximum number of iterations.
+        let candidate = r"
+finalize foo:
+    branch.eq 0u8 0u8 to end max 3;
+    position end;";
+        assert!(Finalize::<CurrentNetwork>::parse(candidate).is_err());
+    }
+
+    #[test]
+    fn test_finalize_parse_outputs() {
+        let finalize = Finalize::<CurrentNetwork>::parse(
+            r"
+finalize foo:
+    input r0 as u64.public;
+    add r0 1u64 into r1;
+    output r1 as u64.public;
+    output r0 as u64.public;",
+        )
+        .unwrap()
+        .1;
+        assert_eq!(1, finalize.commands().len());
+        assert_eq!(2, finalize.outputs().len());
+        assert_eq!(vec![PlaintextType::from_str("u64").unwrap(); 2], finalize.output_types());
+
+        // Ensure a duplicate output is rejected.
+        let candidate = r"
+finalize foo:
+    input r0 as u64.public;
+    add r0 1u64 into r1;
+    output r1 as u64.public;
+    output r1 as u64.public;";
+        assert!(Finalize::<CurrentNetwork>::parse(candidate).is_err());
+    }
+
     #[test]
     fn test_finalize_display() {
         let expected = r"finalize foo:
@@ -145,5 +231,12 @@ finalize foo:
     add r0 r1 into r2;";
         let finalize = Finalize::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(expected, format!("{finalize}"),);
+
+        let expected = r"finalize foo:
+    input r0 as field.public;
+    add r0 r0 into r1;
+    output r1 as field.public;";
+        let finalize = Finalize::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(expected, format!("{finalize}"),);
     }
 }
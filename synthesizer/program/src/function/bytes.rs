@@ -13,13 +13,16 @@
 // limitations under the License.
 
 use super::*;
+use crate::bytes::{PROGRAM_VERSION_1, PROGRAM_VERSION_LEGACY};
 
-impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> FromBytes
-    for FunctionCore<N, Instruction, Command>
-{
-    /// Reads the function from a buffer.
-    #[inline]
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> FunctionCore<N, Instruction, Command> {
+    /// Returns the lowest program version that can encode the function, as set by its finalize scope.
+    pub(crate) fn version(&self) -> u8 {
+        self.finalize.as_ref().map_or(PROGRAM_VERSION_LEGACY, |(_, finalize)| finalize.version())
+    }
+
+    /// Reads the function from a buffer, using the encodings of the given program version.
+    pub(crate) fn read_le_versioned<R: Read>(mut reader: R, version: u8) -> IoResult<Self> {
         // Read the function name.
         let name = Identifier::<N>::read_le(&mut reader)?;
 
@@ -51,7 +54,10 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
         let variant = u8::read_le(&mut reader)?;
         let finalize = match variant {
             0 => None,
-            1 => Some((Command::FinalizeCommand::read_le(&mut reader)?, FinalizeCore::read_le(&mut reader)?)),
+            1 => Some((
+                Command::FinalizeCommand::read_le(&mut reader)?,
+                FinalizeCore::read_le_versioned(&mut reader, version)?,
+            )),
             _ => return Err(error(format!("Failed to deserialize a function: invalid finalize variant ({variant})"))),
         };
 
@@ -67,14 +73,9 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
 
         Ok(function)
     }
-}
 
-impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToBytes
-    for FunctionCore<N, Instruction, Command>
-{
-    /// Writes the function to a buffer.
-    #[inline]
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+    /// Writes the function to a buffer, using the encodings of the given program version.
+    pub(crate) fn write_le_versioned<W: Write>(&self, mut writer: W, version: u8) -> IoResult<()> {
         // Write the function name.
         self.name.write_le(&mut writer)?;
 
@@ -122,7 +123,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
                 // Write the finalize scope command.
                 command.write_le(&mut writer)?;
                 // Write the finalize scope logic.
-                logic.write_le(&mut writer)?;
+                logic.write_le_versioned(&mut writer, version)?;
             }
         }
 
@@ -130,6 +131,26 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToB
     }
 }
 
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> FromBytes
+    for FunctionCore<N, Instruction, Command>
+{
+    /// Reads the function from a buffer.
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_versioned(reader, PROGRAM_VERSION_1)
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ToBytes
+    for FunctionCore<N, Instruction, Command>
+{
+    /// Writes the function to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_le_versioned(writer, PROGRAM_VERSION_1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -186,7 +186,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fun
     /// This method will halt if a finalize scope has already been added.
     /// This method will halt if name in the finalize scope does not match the function name.
     /// This method will halt if the maximum number of finalize inputs has been reached.
-    /// This method will halt if the number of finalize operands does not match the number of finalize inputs.
+    /// This method will halt if the number of finalize operands exceeds the number of finalize inputs.
     #[inline]
     fn add_finalize(&mut self, command: Command::FinalizeCommand, finalize: FinalizeCore<N, Command>) -> Result<()> {
         // Ensure there is no finalize scope in memory.
@@ -195,10 +195,12 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fun
         ensure!(*finalize.name() == self.name, "Finalize scope name must match function name '{}'", self.name);
         // Ensure the number of finalize inputs has not been exceeded.
         ensure!(finalize.inputs().len() <= N::MAX_INPUTS, "Cannot add more than {} inputs to finalize", N::MAX_INPUTS);
-        // Ensure the finalize command has the same number of operands as the finalize inputs.
+        // Ensure the finalize command does not have more operands than the finalize inputs.
+        // Note: The remaining finalize inputs are assigned the finalize outputs of the callees,
+        // which are checked against the callees in `Stack::insert_function`.
         ensure!(
-            command.num_operands() == finalize.inputs().len(),
-            "The 'finalize' command has {} operands, but 'finalize' takes {} inputs",
+            command.num_operands() <= finalize.inputs().len(),
+            "The 'finalize' command has {} operands, but 'finalize' only takes {} inputs",
             command.num_operands(),
             finalize.inputs().len()
         );
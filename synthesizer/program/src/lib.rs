@@ -20,10 +20,14 @@ pub type Program<N> = crate::ProgramCore<N, Instruction<N>, Command<N>>;
 pub type Function<N> = crate::FunctionCore<N, Instruction<N>, Command<N>>;
 pub type Finalize<N> = crate::FinalizeCore<N, Command<N>>;
 pub type Closure<N> = crate::ClosureCore<N, Instruction<N>>;
+pub type Constructor<N> = crate::ConstructorCore<N, Command<N>>;
 
 mod closure;
 pub use closure::*;
 
+mod constructor;
+pub use constructor::*;
+
 pub mod finalize;
 pub use finalize::*;
 
@@ -59,6 +63,7 @@ use console::{
         many1,
         map,
         map_res,
+        opt,
         tag,
         take,
         Debug,
@@ -121,6 +126,8 @@ pub struct ProgramCore<N: Network, Instruction: InstructionTrait<N>, Command: Co
     closures: IndexMap<Identifier<N>, ClosureCore<N, Instruction>>,
     /// A map of the declared functions for the program.
     functions: IndexMap<Identifier<N>, FunctionCore<N, Instruction, Command>>,
+    /// The declared constructor for the program, if any.
+    constructor: Option<ConstructorCore<N, Command>>,
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -139,6 +146,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
             records: IndexMap::new(),
             closures: IndexMap::new(),
             functions: IndexMap::new(),
+            constructor: None,
         })
     }
 
@@ -183,6 +191,11 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         &self.functions
     }
 
+    /// Returns the constructor in the program, if any.
+    pub const fn constructor(&self) -> Option<&ConstructorCore<N, Command>> {
+        self.constructor.as_ref()
+    }
+
     /// Returns `true` if the program contains an import with the given program ID.
     pub fn contains_import(&self, id: &ProgramID<N>) -> bool {
         self.imports.contains_key(id)
@@ -316,6 +329,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
     /// # Errors
     /// This method will halt if the mapping name is already in use.
     /// This method will halt if the mapping name is a reserved opcode or keyword.
+    /// This method will halt if the declared size limit is zero or exceeds the maximum data size.
     #[inline]
     fn add_mapping(&mut self, mapping: Mapping<N>) -> Result<()> {
         // Retrieve the mapping name.
@@ -330,6 +344,15 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         ensure!(!Self::is_reserved_keyword(&mapping_name), "'{mapping_name}' is a reserved keyword.");
         // Ensure the mapping name is not a reserved opcode.
         ensure!(!Self::is_reserved_opcode(&mapping_name.to_string()), "'{mapping_name}' is a reserved opcode.");
+        // Ensure the declared size limit (if any) is nonzero, and does not exceed the maximum data size.
+        if let Some(limit) = mapping.limit() {
+            ensure!(limit > 0, "Mapping '{mapping_name}' must declare a nonzero size limit");
+            ensure!(
+                limit as u32 <= N::MAX_DATA_SIZE_IN_FIELDS,
+                "Mapping '{mapping_name}' declares a size limit exceeding {} field elements",
+                N::MAX_DATA_SIZE_IN_FIELDS
+            );
+        }
 
         // Add the mapping name to the identifiers.
         if self.identifiers.insert(mapping_name, ProgramDefinition::Mapping).is_some() {
@@ -533,6 +556,19 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         }
         Ok(())
     }
+
+    /// Adds the constructor to the program.
+    ///
+    /// # Errors
+    /// This method will halt if a constructor was previously added.
+    #[inline]
+    fn add_constructor(&mut self, constructor: ConstructorCore<N, Command>) -> Result<()> {
+        // Ensure the program does not already have a constructor.
+        ensure!(self.constructor.is_none(), "Program '{}' cannot have more than one constructor", self.id);
+        // Add the constructor to the program.
+        self.constructor = Some(constructor);
+        Ok(())
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -665,6 +701,44 @@ mapping message:
         Ok(())
     }
 
+    #[test]
+    fn test_program_mapping_limit() -> Result<()> {
+        // Initialize a new program with a mapping that declares a size limit.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program unknown.aleo;
+mapping message:
+    key first as field.public;
+    value second as field.public;
+    limit 2;",
+        )?;
+        // Ensure the retrieved mapping has the declared limit.
+        assert_eq!(Some(2), program.get_mapping(&Identifier::from_str("message")?)?.limit());
+
+        // Ensure a zero size limit is rejected.
+        let program = r"
+program unknown.aleo;
+mapping message:
+    key first as field.public;
+    value second as field.public;
+    limit 0;";
+        assert!(Program::<CurrentNetwork>::from_str(program).is_err());
+
+        // Ensure a size limit exceeding the maximum data size is rejected.
+        let program = format!(
+            r"
+program unknown.aleo;
+mapping message:
+    key first as field.public;
+    value second as field.public;
+    limit {};",
+            CurrentNetwork::MAX_DATA_SIZE_IN_FIELDS + 1
+        );
+        assert!(Program::<CurrentNetwork>::from_str(&program).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_struct() -> Result<()> {
         // Create a new struct.
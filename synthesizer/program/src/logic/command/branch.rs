@@ -12,9 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Opcode, Operand};
+use crate::{
+    bytes::{PROGRAM_VERSION_1, PROGRAM_VERSION_LEGACY},
+    Opcode,
+    Operand,
+};
 use console::{network::prelude::*, program::Identifier};
 
+/// The maximum number of iterations that a backward branch may declare.
+pub const MAX_BRANCH_ITERATIONS: u16 = 1024;
+
 /// Jumps to `position`, if `first` equals `second`.
 pub type BranchEq<N> = Branch<N, { Variant::BranchEq as u8 }>;
 /// Jumps to `position`, if `first` does **not** equal `second`.
@@ -26,6 +33,13 @@ enum Variant {
 }
 
 /// Compares `first` and `second` and jumps to `position`, if the condition is met.
+/// A branch to an earlier position forms a loop, and must declare its maximum number of iterations,
+/// e.g. `branch.neq r0 r1 to start max 8;`.
+///
+/// The maximum is a budget for the whole finalize scope, not for each pass of an enclosing loop:
+/// the branch may jump back at most `max` times in total, and the counter is never reset.
+/// For a loop nested in another loop, the inner maximum must therefore cover the iterations of
+/// all outer passes combined. `Finalize::max_executions` prices the commands on the same basis.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Branch<N: Network, const VARIANT: u8> {
     /// The first operand.
@@ -34,6 +48,8 @@ pub struct Branch<N: Network, const VARIANT: u8> {
     second: Operand<N>,
     /// The position.
     position: Identifier<N>,
+    /// The maximum number of times the branch may jump to an earlier position, across the whole finalize scope.
+    max_iterations: Option<u16>,
 }
 
 impl<N: Network, const VARIANT: u8> Branch<N, VARIANT> {
@@ -64,6 +80,12 @@ impl<N: Network, const VARIANT: u8> Branch<N, VARIANT> {
     pub fn position(&self) -> &Identifier<N> {
         &self.position
     }
+
+    /// Returns the maximum number of iterations, if the branch is a loop.
+    #[inline]
+    pub const fn max_iterations(&self) -> Option<u16> {
+        self.max_iterations
+    }
 }
 
 impl<N: Network, const VARIANT: u8> Parser for Branch<N, VARIANT> {
@@ -94,12 +116,24 @@ impl<N: Network, const VARIANT: u8> Parser for Branch<N, VARIANT> {
         // Parse the position from the string.
         let (string, position) = Identifier::parse(string)?;
 
+        // Parse the optional maximum number of iterations from the string.
+        let (string, max_iterations) = opt(|string| {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the "max" from the string.
+            let (string, _) = tag("max")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the maximum number of iterations from the string.
+            map_res(recognize(many1(one_of("0123456789"))), |iterations: &str| iterations.parse::<u16>())(string)
+        })(string)?;
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the ";" from the string.
         let (string, _) = tag(";")(string)?;
 
-        Ok((string, Self { first, second, position }))
+        Ok((string, Self { first, second, position, max_iterations }))
     }
 }
 
@@ -132,34 +166,81 @@ impl<N: Network, const VARIANT: u8> Display for Branch<N, VARIANT> {
     /// Prints the command to a string.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         // Print the command.
-        write!(f, "{} {} {} to {};", Self::opcode(), self.first, self.second, self.position)
+        write!(f, "{} {} {} to {}", Self::opcode(), self.first, self.second, self.position)?;
+        // Print the maximum number of iterations, if it is declared.
+        if let Some(max_iterations) = self.max_iterations {
+            write!(f, " max {max_iterations}")?;
+        }
+        write!(f, ";")
     }
 }
 
-impl<N: Network, const VARIANT: u8> FromBytes for Branch<N, VARIANT> {
-    /// Reads the command from a buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+impl<N: Network, const VARIANT: u8> Branch<N, VARIANT> {
+    /// Returns the lowest program version that can encode the command.
+    /// Note: A loop bound is only encoded from version 1, so a legacy program cannot declare a loop.
+    pub(crate) fn version(&self) -> u8 {
+        match self.max_iterations.is_some() {
+            true => PROGRAM_VERSION_1,
+            false => PROGRAM_VERSION_LEGACY,
+        }
+    }
+
+    /// Reads the command from a buffer, using the encoding of the given program version.
+    pub(crate) fn read_le_versioned<R: Read>(mut reader: R, version: u8) -> IoResult<Self> {
         // Read the first operand.
         let first = Operand::read_le(&mut reader)?;
         // Read the second operand.
         let second = Operand::read_le(&mut reader)?;
         // Read the position.
         let position = Identifier::read_le(&mut reader)?;
+        // Read the maximum number of iterations, which is encoded from version 1.
+        let max_iterations = match version {
+            PROGRAM_VERSION_LEGACY => None,
+            _ => match u8::read_le(&mut reader)? {
+                0 => None,
+                1 => Some(u16::read_le(&mut reader)?),
+                variant => return Err(error(format!("Invalid maximum iterations variant: {variant}"))),
+            },
+        };
 
         // Return the command.
-        Ok(Self { first, second, position })
+        Ok(Self { first, second, position, max_iterations })
     }
-}
 
-impl<N: Network, const VARIANT: u8> ToBytes for Branch<N, VARIANT> {
-    /// Writes the command to a buffer.
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+    /// Writes the command to a buffer, using the encoding of the given program version.
+    pub(crate) fn write_le_versioned<W: Write>(&self, mut writer: W, version: u8) -> IoResult<()> {
         // Write the first operand.
         self.first.write_le(&mut writer)?;
         // Write the second operand.
         self.second.write_le(&mut writer)?;
         // Write the position.
-        self.position.write_le(&mut writer)
+        self.position.write_le(&mut writer)?;
+        // Write the maximum number of iterations, which is encoded from version 1.
+        match (version, self.max_iterations) {
+            (PROGRAM_VERSION_LEGACY, None) => Ok(()),
+            (PROGRAM_VERSION_LEGACY, Some(_)) => {
+                Err(error(format!("Program version {version} cannot declare the maximum iterations of '{self}'")))
+            }
+            (_, None) => 0u8.write_le(&mut writer),
+            (_, Some(max_iterations)) => {
+                1u8.write_le(&mut writer)?;
+                max_iterations.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for Branch<N, VARIANT> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_versioned(reader, PROGRAM_VERSION_1)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for Branch<N, VARIANT> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_le_versioned(writer, PROGRAM_VERSION_1)
     }
 }
 
@@ -186,5 +267,41 @@ mod tests {
         assert_eq!(branch.first, Operand::Register(Register::Locator(3)), "The first operand is incorrect");
         assert_eq!(branch.second, Operand::Register(Register::Locator(4)), "The second operand is incorrect");
         assert_eq!(branch.position, Identifier::from_str("start").unwrap(), "The position is incorrect");
+        assert_eq!(branch.max_iterations, None, "The maximum number of iterations is incorrect");
+
+        let (string, branch) = BranchNeq::<CurrentNetwork>::parse("branch.neq r3 r4 to start max 8;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(branch.position, Identifier::from_str("start").unwrap(), "The position is incorrect");
+        assert_eq!(branch.max_iterations, Some(8), "The maximum number of iterations is incorrect");
+
+        // Ensure the maximum number of iterations must fit in a `u16`.
+        assert!(BranchNeq::<CurrentNetwork>::parse("branch.neq r3 r4 to start max 65536;").is_err());
+    }
+
+    #[test]
+    fn test_display_and_bytes() {
+        for expected in ["branch.eq r0 r1 to exit;", "branch.eq r0 1u8 to start max 8;"] {
+            let branch = BranchEq::<CurrentNetwork>::from_str(expected).unwrap();
+            assert_eq!(expected, branch.to_string());
+            assert_eq!(branch, BranchEq::from_bytes_le(&branch.to_bytes_le().unwrap()).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_bytes_legacy() {
+        let branch = BranchEq::<CurrentNetwork>::from_str("branch.eq r0 r1 to exit;").unwrap();
+
+        // Ensure a legacy program writes the operands and the position.
+        let mut expected = Operand::<CurrentNetwork>::Register(Register::Locator(0)).to_bytes_le().unwrap();
+        expected.extend(Operand::<CurrentNetwork>::Register(Register::Locator(1)).to_bytes_le().unwrap());
+        expected.extend(Identifier::<CurrentNetwork>::from_str("exit").unwrap().to_bytes_le().unwrap());
+        let mut bytes = Vec::new();
+        branch.write_le_versioned(&mut bytes, PROGRAM_VERSION_LEGACY).unwrap();
+        assert_eq!(bytes, expected);
+        assert_eq!(branch, BranchEq::read_le_versioned(&expected[..], PROGRAM_VERSION_LEGACY).unwrap());
+
+        // Ensure a legacy program cannot declare a loop.
+        let branch = BranchEq::<CurrentNetwork>::from_str("branch.eq r0 r1 to start max 8;").unwrap();
+        assert!(branch.write_le_versioned(Vec::new(), PROGRAM_VERSION_LEGACY).is_err());
     }
 }
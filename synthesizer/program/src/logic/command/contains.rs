@@ -13,13 +13,15 @@
 // limitations under the License.
 
 use crate::{
+    bytes::PROGRAM_VERSION_1,
     traits::{FinalizeStoreTrait, RegistersLoad, RegistersStore, StackMatches, StackProgram},
+    MappingLocator,
     Opcode,
     Operand,
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, Literal, Register, Value},
+    program::{Literal, Register, Value},
     types::Boolean,
 };
 
@@ -27,8 +29,8 @@ use console::{
 /// Contains is `true` if a (`key`, `value`) entry exists in `mapping`, stores the result in `destination`.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Contains<N: Network> {
-    /// The mapping name.
-    mapping: Identifier<N>,
+    /// The mapping.
+    mapping: MappingLocator<N>,
     /// The key to access the mapping.
     key: Operand<N>,
     /// The destination register.
@@ -48,9 +50,9 @@ impl<N: Network> Contains<N> {
         vec![self.key.clone()]
     }
 
-    /// Returns the mapping name.
+    /// Returns the mapping.
     #[inline]
-    pub const fn mapping_name(&self) -> &Identifier<N> {
+    pub const fn mapping(&self) -> &MappingLocator<N> {
         &self.mapping
     }
 
@@ -76,16 +78,22 @@ impl<N: Network> Contains<N> {
         store: &impl FinalizeStoreTrait<N>,
         registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
     ) -> Result<()> {
+        // Determine the program ID and mapping name.
+        let (program_id, mapping_name) = match &self.mapping {
+            MappingLocator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+            MappingLocator::Resource(mapping_name) => (*stack.program_id(), *mapping_name),
+        };
+
         // Ensure the mapping exists in storage.
-        if !store.contains_mapping_confirmed(stack.program_id(), &self.mapping)? {
-            bail!("Mapping '{}/{}' does not exist in storage", stack.program_id(), self.mapping);
+        if !store.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            bail!("Mapping '{program_id}/{mapping_name}' does not exist in storage");
         }
 
         // Load the operand as a plaintext.
         let key = registers.load_plaintext(stack, &self.key)?;
 
         // Determine if the key exists in the mapping.
-        let contains_key = store.contains_key_speculative(stack.program_id(), &self.mapping, &key)?;
+        let contains_key = store.contains_key_speculative(&program_id, &mapping_name, &key)?;
 
         // Assign the value to the destination register.
         registers.store(stack, &self.destination, Value::from(Literal::Boolean(Boolean::new(contains_key))))?;
@@ -105,8 +113,8 @@ impl<N: Network> Parser for Contains<N> {
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
 
-        // Parse the mapping name from the string.
-        let (string, mapping) = Identifier::parse(string)?;
+        // Parse the mapping from the string.
+        let (string, mapping) = MappingLocator::parse(string)?;
         // Parse the "[" from the string.
         let (string, _) = tag("[")(string)?;
         // Parse the whitespace from the string.
@@ -173,11 +181,11 @@ impl<N: Network> Display for Contains<N> {
     }
 }
 
-impl<N: Network> FromBytes for Contains<N> {
-    /// Reads the command from a buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Read the mapping name.
-        let mapping = Identifier::read_le(&mut reader)?;
+impl<N: Network> Contains<N> {
+    /// Reads the command from a buffer, using the mapping encoding of the given program version.
+    pub(crate) fn read_le_versioned<R: Read>(mut reader: R, version: u8) -> IoResult<Self> {
+        // Read the mapping.
+        let mapping = MappingLocator::read_le_versioned(&mut reader, version)?;
         // Read the key operand.
         let key = Operand::read_le(&mut reader)?;
         // Read the destination register.
@@ -185,13 +193,11 @@ impl<N: Network> FromBytes for Contains<N> {
         // Return the command.
         Ok(Self { mapping, key, destination })
     }
-}
 
-impl<N: Network> ToBytes for Contains<N> {
-    /// Writes the operation to a buffer.
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the mapping name.
-        self.mapping.write_le(&mut writer)?;
+    /// Writes the command to a buffer, using the mapping encoding of the given program version.
+    pub(crate) fn write_le_versioned<W: Write>(&self, mut writer: W, version: u8) -> IoResult<()> {
+        // Write the mapping.
+        self.mapping.write_le_versioned(&mut writer, version)?;
         // Write the key operand.
         self.key.write_le(&mut writer)?;
         // Write the destination register.
@@ -199,6 +205,20 @@ impl<N: Network> ToBytes for Contains<N> {
     }
 }
 
+impl<N: Network> FromBytes for Contains<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_versioned(reader, PROGRAM_VERSION_1)
+    }
+}
+
+impl<N: Network> ToBytes for Contains<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_le_versioned(writer, PROGRAM_VERSION_1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,7 +230,7 @@ mod tests {
     fn test_parse() {
         let (string, contains) = Contains::<CurrentNetwork>::parse("contains account[r0] into r1;").unwrap();
         assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
-        assert_eq!(contains.mapping, Identifier::from_str("account").unwrap());
+        assert_eq!(contains.mapping, MappingLocator::from_str("account").unwrap());
         assert_eq!(contains.operands().len(), 1, "The number of operands is incorrect");
         assert_eq!(contains.key, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(contains.destination, Register::Locator(1), "The second operand is incorrect");
@@ -0,0 +1,223 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, StackMatches, StackProgram},
+    Event,
+    Opcode,
+    Operand,
+};
+use console::{network::prelude::*, program::Identifier};
+
+/// An emit command, e.g. `emit transfer r0 r1;`.
+/// Emits an event with the given name, whose values are the plaintext values of the operands.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Emit<N: Network> {
+    /// The event name.
+    name: Identifier<N>,
+    /// The operands as `values`.
+    operands: Vec<Operand<N>>,
+}
+
+impl<N: Network> Emit<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("emit")
+    }
+
+    /// Returns the event name.
+    #[inline]
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        &self.operands
+    }
+}
+
+impl<N: Network> Emit<N> {
+    /// Finalizes the command, and returns the emitted event.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &impl RegistersLoad<N>,
+    ) -> Result<Event<N>> {
+        // Ensure the number of operands is within bounds.
+        if self.operands.len() > N::MAX_OPERANDS {
+            bail!("The number of operands must be <= {}", N::MAX_OPERANDS)
+        }
+
+        // Load the operand values.
+        let values: Vec<_> =
+            self.operands.iter().map(|operand| registers.load_plaintext(stack, operand)).try_collect()?;
+
+        // Return the event.
+        Event::new(*stack.program_id(), self.name, values)
+    }
+}
+
+impl<N: Network> Parser for Emit<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        /// Parses an operand from the string.
+        fn parse_operand<N: Network>(string: &str) -> ParserResult<Operand<N>> {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the operand from the string.
+            Operand::parse(string)
+        }
+
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the event name from the string.
+        let (string, name) = Identifier::parse(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = many0(parse_operand)(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        match operands.len() <= N::MAX_OPERANDS {
+            true => Ok((string, Self { name, operands })),
+            false => {
+                map_res(fail, |_: ParserResult<Self>| Err(error("Failed to parse 'emit' opcode: too many operands")))(
+                    string,
+                )
+            }
+        }
+    }
+}
+
+impl<N: Network> FromStr for Emit<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Emit<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Emit<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() > N::MAX_OPERANDS {
+            return Err(fmt::Error);
+        }
+
+        // Print the command.
+        write!(f, "{} {}", Self::opcode(), self.name)?;
+        self.operands.iter().try_for_each(|operand| write!(f, " {operand}"))?;
+        write!(f, ";")
+    }
+}
+
+impl<N: Network> FromBytes for Emit<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the event name.
+        let name = Identifier::read_le(&mut reader)?;
+        // Read the number of operands.
+        let num_operands = u8::read_le(&mut reader)? as usize;
+
+        // Ensure that the number of operands does not exceed the upper bound.
+        if num_operands > N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be <= {}", N::MAX_OPERANDS)));
+        }
+
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(num_operands);
+        // Read the operands.
+        for _ in 0..num_operands {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+
+        // Return the command.
+        Ok(Self { name, operands })
+    }
+}
+
+impl<N: Network> ToBytes for Emit<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is within the bounds.
+        if self.operands.len() > N::MAX_OPERANDS {
+            return Err(error(format!("The number of operands must be <= {}", N::MAX_OPERANDS)));
+        }
+
+        // Write the event name.
+        self.name.write_le(&mut writer)?;
+        // Write the number of operands.
+        u8::try_from(self.operands.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::Register};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, emit) = Emit::<CurrentNetwork>::parse("emit mint;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(emit.name, Identifier::from_str("mint").unwrap(), "The event name is incorrect");
+        assert_eq!(emit.operands.len(), 0, "The number of operands is incorrect");
+
+        let (string, emit) = Emit::<CurrentNetwork>::parse("emit transfer r0 r1.amount 5u64;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(emit.name, Identifier::from_str("transfer").unwrap(), "The event name is incorrect");
+        assert_eq!(emit.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(emit.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(emit.operands[2], Operand::from_str("5u64").unwrap(), "The third operand is incorrect");
+
+        // Ensure the display round-trips.
+        assert_eq!(emit.to_string(), "emit transfer r0 r1.amount 5u64;");
+
+        // Ensure an event name is required.
+        assert!(Emit::<CurrentNetwork>::parse("emit;").is_err());
+    }
+}
@@ -13,21 +13,142 @@
 // limitations under the License.
 
 use crate::{
+    bytes::{PROGRAM_VERSION_1, PROGRAM_VERSION_LEGACY},
     traits::{FinalizeStoreTrait, RegistersLoad, RegistersStore, StackMatches, StackProgram},
     Opcode,
     Operand,
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, Register, Value},
+    program::{Identifier, Locator, Register, Value},
 };
 
+/// The mapping that a command reads from.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum MappingLocator<N: Network> {
+    /// The reference to a mapping in an imported program, e.g. `token.aleo/balances`.
+    Locator(Locator<N>),
+    /// The reference to a mapping in the current program, e.g. `balances`.
+    Resource(Identifier<N>),
+}
+
+impl<N: Network> MappingLocator<N> {
+    /// Returns the lowest program version that can encode the mapping locator.
+    /// Note: Only a mapping of an imported program requires version 1.
+    pub(crate) fn version(&self) -> u8 {
+        match self {
+            MappingLocator::Locator(_) => PROGRAM_VERSION_1,
+            MappingLocator::Resource(_) => PROGRAM_VERSION_LEGACY,
+        }
+    }
+
+    /// Reads the mapping locator from a buffer, using the encoding of the given program version.
+    /// Note: A legacy program only names mappings of its own program, so it stores the name without a variant.
+    pub(crate) fn read_le_versioned<R: Read>(mut reader: R, version: u8) -> IoResult<Self> {
+        // Read the mapping name, if the program is of the legacy version.
+        if version == PROGRAM_VERSION_LEGACY {
+            return Ok(MappingLocator::Resource(Identifier::read_le(&mut reader)?));
+        }
+        // Read the variant.
+        let variant = u8::read_le(&mut reader)?;
+        match variant {
+            0 => Ok(MappingLocator::Locator(Locator::read_le(&mut reader)?)),
+            1 => Ok(MappingLocator::Resource(Identifier::read_le(&mut reader)?)),
+            2.. => Err(error(format!("Invalid mapping locator variant: {variant}"))),
+        }
+    }
+
+    /// Writes the mapping locator to a buffer, using the encoding of the given program version.
+    pub(crate) fn write_le_versioned<W: Write>(&self, mut writer: W, version: u8) -> IoResult<()> {
+        // Write the mapping name, if the program is of the legacy version.
+        if version == PROGRAM_VERSION_LEGACY {
+            return match self {
+                MappingLocator::Locator(locator) => {
+                    Err(error(format!("Program version {version} cannot access the external mapping '{locator}'")))
+                }
+                MappingLocator::Resource(resource) => resource.write_le(&mut writer),
+            };
+        }
+        match self {
+            MappingLocator::Locator(locator) => {
+                // Write the variant.
+                0u8.write_le(&mut writer)?;
+                // Write the locator.
+                locator.write_le(&mut writer)
+            }
+            MappingLocator::Resource(resource) => {
+                // Write the variant.
+                1u8.write_le(&mut writer)?;
+                // Write the resource.
+                resource.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<N: Network> Parser for MappingLocator<N> {
+    /// Parses a string into a mapping locator.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        alt((map(Locator::parse, MappingLocator::Locator), map(Identifier::parse, MappingLocator::Resource)))(string)
+    }
+}
+
+impl<N: Network> FromStr for MappingLocator<N> {
+    type Err = Error;
+
+    /// Parses a string into a mapping locator.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for MappingLocator<N> {
+    /// Prints the mapping locator as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for MappingLocator<N> {
+    /// Prints the mapping locator to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            MappingLocator::Locator(locator) => Display::fmt(locator, f),
+            MappingLocator::Resource(resource) => Display::fmt(resource, f),
+        }
+    }
+}
+
+impl<N: Network> FromBytes for MappingLocator<N> {
+    /// Reads the mapping locator from a buffer.
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_versioned(reader, PROGRAM_VERSION_1)
+    }
+}
+
+impl<N: Network> ToBytes for MappingLocator<N> {
+    /// Writes the mapping locator to a buffer.
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_le_versioned(writer, PROGRAM_VERSION_1)
+    }
+}
+
 /// A get command, e.g. `get accounts[r0] into r1;`.
 /// Gets the value stored at `operand` in `mapping` and stores the result in `destination`.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Get<N: Network> {
-    /// The mapping name.
-    mapping: Identifier<N>,
+    /// The mapping.
+    mapping: MappingLocator<N>,
     /// The key to access the mapping.
     key: Operand<N>,
     /// The destination register.
@@ -47,9 +168,9 @@ impl<N: Network> Get<N> {
         vec![self.key.clone()]
     }
 
-    /// Returns the mapping name.
+    /// Returns the mapping.
     #[inline]
-    pub const fn mapping_name(&self) -> &Identifier<N> {
+    pub const fn mapping(&self) -> &MappingLocator<N> {
         &self.mapping
     }
 
@@ -75,20 +196,26 @@ impl<N: Network> Get<N> {
         store: &impl FinalizeStoreTrait<N>,
         registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
     ) -> Result<()> {
+        // Determine the program ID and mapping name.
+        let (program_id, mapping_name) = match &self.mapping {
+            MappingLocator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+            MappingLocator::Resource(mapping_name) => (*stack.program_id(), *mapping_name),
+        };
+
         // Ensure the mapping exists in storage.
-        if !store.contains_mapping_confirmed(stack.program_id(), &self.mapping)? {
-            bail!("Mapping '{}/{}' does not exist in storage", stack.program_id(), self.mapping);
+        if !store.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            bail!("Mapping '{program_id}/{mapping_name}' does not exist in storage");
         }
 
         // Load the operand as a plaintext.
         let key = registers.load_plaintext(stack, &self.key)?;
 
         // Retrieve the value from storage as a literal.
-        let value = match store.get_value_speculative(stack.program_id(), &self.mapping, &key)? {
+        let value = match store.get_value_speculative(&program_id, &mapping_name, &key)? {
             Some(Value::Plaintext(plaintext)) => Value::Plaintext(plaintext),
             Some(Value::Record(..)) => bail!("Cannot 'get' a 'record'"),
             // If a key does not exist, then bail.
-            None => bail!("Key '{}' does not exist in mapping '{}/{}'", key, stack.program_id(), self.mapping),
+            None => bail!("Key '{key}' does not exist in mapping '{program_id}/{mapping_name}'"),
         };
 
         // Assign the value to the destination register.
@@ -109,8 +236,8 @@ impl<N: Network> Parser for Get<N> {
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
 
-        // Parse the mapping name from the string.
-        let (string, mapping) = Identifier::parse(string)?;
+        // Parse the mapping from the string.
+        let (string, mapping) = MappingLocator::parse(string)?;
         // Parse the "[" from the string.
         let (string, _) = tag("[")(string)?;
         // Parse the whitespace from the string.
@@ -177,11 +304,11 @@ impl<N: Network> Display for Get<N> {
     }
 }
 
-impl<N: Network> FromBytes for Get<N> {
-    /// Reads the command from a buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Read the mapping name.
-        let mapping = Identifier::read_le(&mut reader)?;
+impl<N: Network> Get<N> {
+    /// Reads the command from a buffer, using the mapping encoding of the given program version.
+    pub(crate) fn read_le_versioned<R: Read>(mut reader: R, version: u8) -> IoResult<Self> {
+        // Read the mapping.
+        let mapping = MappingLocator::read_le_versioned(&mut reader, version)?;
         // Read the key operand.
         let key = Operand::read_le(&mut reader)?;
         // Read the destination register.
@@ -189,13 +316,11 @@ impl<N: Network> FromBytes for Get<N> {
         // Return the command.
         Ok(Self { mapping, key, destination })
     }
-}
 
-impl<N: Network> ToBytes for Get<N> {
-    /// Writes the operation to a buffer.
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the mapping name.
-        self.mapping.write_le(&mut writer)?;
+    /// Writes the command to a buffer, using the mapping encoding of the given program version.
+    pub(crate) fn write_le_versioned<W: Write>(&self, mut writer: W, version: u8) -> IoResult<()> {
+        // Write the mapping.
+        self.mapping.write_le_versioned(&mut writer, version)?;
         // Write the key operand.
         self.key.write_le(&mut writer)?;
         // Write the destination register.
@@ -203,6 +328,20 @@ impl<N: Network> ToBytes for Get<N> {
     }
 }
 
+impl<N: Network> FromBytes for Get<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_versioned(reader, PROGRAM_VERSION_1)
+    }
+}
+
+impl<N: Network> ToBytes for Get<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_le_versioned(writer, PROGRAM_VERSION_1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,9 +353,40 @@ mod tests {
     fn test_parse() {
         let (string, get) = Get::<CurrentNetwork>::parse("get account[r0] into r1;").unwrap();
         assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
-        assert_eq!(get.mapping, Identifier::from_str("account").unwrap());
+        assert_eq!(get.mapping, MappingLocator::from_str("account").unwrap());
         assert_eq!(get.operands().len(), 1, "The number of operands is incorrect");
         assert_eq!(get.key, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(get.destination, Register::Locator(1), "The second operand is incorrect");
     }
+
+    #[test]
+    fn test_parse_external_mapping() {
+        let (string, get) = Get::<CurrentNetwork>::parse("get token.aleo/balances[r0] into r1;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(get.mapping, MappingLocator::Locator(Locator::from_str("token.aleo/balances").unwrap()));
+        assert_eq!(get.key, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(get.destination, Register::Locator(1), "The second operand is incorrect");
+
+        // Ensure the command round-trips through its string and byte representations.
+        assert_eq!(get.to_string(), "get token.aleo/balances[r0] into r1;");
+        assert_eq!(get, Get::read_le(&get.to_bytes_le().unwrap()[..]).unwrap());
+    }
+
+    #[test]
+    fn test_bytes_legacy_mapping() {
+        let (_, get) = Get::<CurrentNetwork>::parse("get account[r0] into r1;").unwrap();
+
+        // Ensure a legacy program writes the mapping name, key, and destination.
+        let mut expected = Identifier::<CurrentNetwork>::from_str("account").unwrap().to_bytes_le().unwrap();
+        expected.extend(Operand::<CurrentNetwork>::Register(Register::Locator(0)).to_bytes_le().unwrap());
+        expected.extend(Register::<CurrentNetwork>::Locator(1).to_bytes_le().unwrap());
+        let mut bytes = Vec::new();
+        get.write_le_versioned(&mut bytes, PROGRAM_VERSION_LEGACY).unwrap();
+        assert_eq!(bytes, expected);
+        assert_eq!(get, Get::read_le_versioned(&expected[..], PROGRAM_VERSION_LEGACY).unwrap());
+
+        // Ensure a legacy program cannot access the mapping of an imported program.
+        let (_, get) = Get::<CurrentNetwork>::parse("get token.aleo/balances[r0] into r1;").unwrap();
+        assert!(get.write_le_versioned(Vec::new(), PROGRAM_VERSION_LEGACY).is_err());
+    }
 }
@@ -13,13 +13,15 @@
 // limitations under the License.
 
 use crate::{
+    bytes::PROGRAM_VERSION_1,
     traits::{FinalizeStoreTrait, RegistersLoad, RegistersStore, StackMatches, StackProgram},
+    MappingLocator,
     Opcode,
     Operand,
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, Register, Value},
+    program::{Register, Value},
 };
 
 /// A get command that uses the provided default in case of failure, e.g. `get.or_use accounts[r0] r1 into r2;`.
@@ -27,8 +29,8 @@ use console::{
 /// If the key is not present, `default` is stored in `destination`.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct GetOrUse<N: Network> {
-    /// The mapping name.
-    mapping: Identifier<N>,
+    /// The mapping.
+    mapping: MappingLocator<N>,
     /// The key to access the mapping.
     key: Operand<N>,
     /// The default value.
@@ -50,9 +52,9 @@ impl<N: Network> GetOrUse<N> {
         vec![self.key.clone(), self.default.clone()]
     }
 
-    /// Returns the mapping name.
+    /// Returns the mapping.
     #[inline]
-    pub const fn mapping_name(&self) -> &Identifier<N> {
+    pub const fn mapping(&self) -> &MappingLocator<N> {
         &self.mapping
     }
 
@@ -84,16 +86,22 @@ impl<N: Network> GetOrUse<N> {
         store: &impl FinalizeStoreTrait<N>,
         registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
     ) -> Result<()> {
+        // Determine the program ID and mapping name.
+        let (program_id, mapping_name) = match &self.mapping {
+            MappingLocator::Locator(locator) => (*locator.program_id(), *locator.resource()),
+            MappingLocator::Resource(mapping_name) => (*stack.program_id(), *mapping_name),
+        };
+
         // Ensure the mapping exists in storage.
-        if !store.contains_mapping_confirmed(stack.program_id(), &self.mapping)? {
-            bail!("Mapping '{}/{}' does not exist in storage", stack.program_id(), self.mapping);
+        if !store.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            bail!("Mapping '{program_id}/{mapping_name}' does not exist in storage");
         }
 
         // Load the operand as a plaintext.
         let key = registers.load_plaintext(stack, &self.key)?;
 
         // Retrieve the value from storage as a literal.
-        let value = match store.get_value_speculative(stack.program_id(), &self.mapping, &key)? {
+        let value = match store.get_value_speculative(&program_id, &mapping_name, &key)? {
             Some(Value::Plaintext(plaintext)) => Value::Plaintext(plaintext),
             Some(Value::Record(..)) => bail!("Cannot 'get.or_use' a 'record'"),
             // If a key does not exist, then use the default value.
@@ -119,8 +127,8 @@ impl<N: Network> Parser for GetOrUse<N> {
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
 
-        // Parse the mapping name from the string.
-        let (string, mapping) = Identifier::parse(string)?;
+        // Parse the mapping from the string.
+        let (string, mapping) = MappingLocator::parse(string)?;
         // Parse the "[" from the string.
         let (string, _) = tag("[")(string)?;
         // Parse the whitespace from the string.
@@ -191,11 +199,11 @@ impl<N: Network> Display for GetOrUse<N> {
     }
 }
 
-impl<N: Network> FromBytes for GetOrUse<N> {
-    /// Reads the command from a buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Read the mapping name.
-        let mapping = Identifier::read_le(&mut reader)?;
+impl<N: Network> GetOrUse<N> {
+    /// Reads the command from a buffer, using the mapping encoding of the given program version.
+    pub(crate) fn read_le_versioned<R: Read>(mut reader: R, version: u8) -> IoResult<Self> {
+        // Read the mapping.
+        let mapping = MappingLocator::read_le_versioned(&mut reader, version)?;
         // Read the key operand.
         let key = Operand::read_le(&mut reader)?;
         // Read the default value.
@@ -205,13 +213,11 @@ impl<N: Network> FromBytes for GetOrUse<N> {
         // Return the command.
         Ok(Self { mapping, key, default, destination })
     }
-}
 
-impl<N: Network> ToBytes for GetOrUse<N> {
-    /// Writes the operation to a buffer.
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the mapping name.
-        self.mapping.write_le(&mut writer)?;
+    /// Writes the command to a buffer, using the mapping encoding of the given program version.
+    pub(crate) fn write_le_versioned<W: Write>(&self, mut writer: W, version: u8) -> IoResult<()> {
+        // Write the mapping.
+        self.mapping.write_le_versioned(&mut writer, version)?;
         // Write the key operand.
         self.key.write_le(&mut writer)?;
         // Write the default value.
@@ -221,6 +227,20 @@ impl<N: Network> ToBytes for GetOrUse<N> {
     }
 }
 
+impl<N: Network> FromBytes for GetOrUse<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_versioned(reader, PROGRAM_VERSION_1)
+    }
+}
+
+impl<N: Network> ToBytes for GetOrUse<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_le_versioned(writer, PROGRAM_VERSION_1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -232,7 +252,7 @@ mod tests {
     fn test_parse() {
         let (string, get_or_use) = GetOrUse::<CurrentNetwork>::parse("get.or_use account[r0] r1 into r2;").unwrap();
         assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
-        assert_eq!(get_or_use.mapping, Identifier::from_str("account").unwrap());
+        assert_eq!(get_or_use.mapping, MappingLocator::from_str("account").unwrap());
         assert_eq!(get_or_use.operands().len(), 2, "The number of operands is incorrect");
         assert_eq!(get_or_use.key, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(get_or_use.default, Operand::Register(Register::Locator(1)), "The second operand is incorrect");
@@ -18,6 +18,9 @@ pub use branch::*;
 mod contains;
 pub use contains::*;
 
+mod emit;
+pub use emit::*;
+
 mod get;
 pub use get::*;
 
@@ -37,6 +40,7 @@ mod set;
 pub use set::*;
 
 use crate::{
+    bytes::{PROGRAM_VERSION_1, PROGRAM_VERSION_LEGACY},
     traits::{
         CommandTrait,
         FinalizeStoreTrait,
@@ -50,10 +54,12 @@ use crate::{
     FinalizeOperation,
     FinalizeRegistersState,
     Instruction,
+    Opcode,
 };
 use console::{
     network::prelude::*,
     program::{Identifier, Register, RegisterType},
+    types::StringType,
 };
 
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -79,6 +85,8 @@ pub enum Command<N: Network> {
     BranchNeq(BranchNeq<N>),
     /// Indicates a position to which the program can branch to.
     Position(Position<N>),
+    /// Emits an event with the `name` and the values of the operands.
+    Emit(Emit<N>),
 }
 
 impl<N: Network> CommandTrait<N> for Command<N> {
@@ -97,7 +105,8 @@ impl<N: Network> CommandTrait<N> for Command<N> {
             | Command::Set(_)
             | Command::BranchEq(_)
             | Command::BranchNeq(_)
-            | Command::Position(_) => vec![],
+            | Command::Position(_)
+            | Command::Emit(_) => vec![],
         }
     }
 
@@ -112,6 +121,17 @@ impl<N: Network> CommandTrait<N> for Command<N> {
         }
     }
 
+    /// Returns the maximum number of iterations, if the command is a branch command that declares one.
+    /// Otherwise, returns `None`.
+    #[inline]
+    fn max_iterations(&self) -> Option<u16> {
+        match self {
+            Command::BranchEq(branch_eq) => branch_eq.max_iterations(),
+            Command::BranchNeq(branch_neq) => branch_neq.max_iterations(),
+            _ => None,
+        }
+    }
+
     /// Returns the position name, if the command is a position command.
     /// Otherwise, returns `None`.
     #[inline]
@@ -138,56 +158,33 @@ impl<N: Network> CommandTrait<N> for Command<N> {
     fn is_write(&self) -> bool {
         matches!(self, Command::Set(_) | Command::Remove(_))
     }
-}
 
-impl<N: Network> Command<N> {
-    /// Finalizes the command.
+    /// Returns the lowest program version that can encode the command.
     #[inline]
-    pub fn finalize(
-        &self,
-        stack: &(impl StackMatches<N> + StackProgram<N>),
-        store: &impl FinalizeStoreTrait<N>,
-        registers: &mut (impl RegistersLoad<N> + RegistersStore<N> + FinalizeRegistersState<N>),
-    ) -> Result<Option<FinalizeOperation<N>>> {
+    fn version(&self) -> u8 {
         match self {
-            // Finalize the instruction, and return no finalize operation.
-            Command::Instruction(instruction) => instruction.finalize(stack, registers).map(|_| None),
-            // Finalize the 'contains' command, and return no finalize operation.
-            Command::Contains(contains) => contains.finalize(stack, store, registers).map(|_| None),
-            // Finalize the 'get' command, and return no finalize operation.
-            Command::Get(get) => get.finalize(stack, store, registers).map(|_| None),
-            // Finalize the 'get.or_use' command, and return no finalize operation.
-            Command::GetOrUse(get_or_use) => get_or_use.finalize(stack, store, registers).map(|_| None),
-            // Finalize the `rand.chacha` command, and return no finalize operation.
-            Command::RandChaCha(rand_chacha) => rand_chacha.finalize(stack, registers).map(|_| None),
-            // Finalize the 'remove' command, and return the finalize operation.
-            Command::Remove(remove) => remove.finalize(stack, store, registers).map(Some),
-            // Finalize the 'set' command, and return the finalize operation.
-            Command::Set(set) => set.finalize(stack, store, registers).map(Some),
-            // 'branch.eq' and 'branch.neq' instructions are processed by the caller of this method.
-            Command::BranchEq(_) | Command::BranchNeq(_) => {
-                bail!("`branch` instructions cannot be finalized directly.")
-            }
-            // Finalize the `position` command, and return no finalize operation.
-            Command::Position(position) => position.finalize().map(|_| None),
+            Command::Contains(contains) => contains.mapping().version(),
+            Command::Get(get) => get.mapping().version(),
+            Command::GetOrUse(get_or_use) => get_or_use.mapping().version(),
+            Command::BranchEq(branch_eq) => branch_eq.version(),
+            Command::BranchNeq(branch_neq) => branch_neq.version(),
+            _ => PROGRAM_VERSION_LEGACY,
         }
     }
-}
 
-impl<N: Network> FromBytes for Command<N> {
-    /// Reads the command from a buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+    /// Reads the command from a buffer, using the encodings of the given program version.
+    fn read_le_versioned<R: Read>(mut reader: R, version: u8) -> IoResult<Self> {
         // Read the variant.
         let variant = u8::read_le(&mut reader)?;
         match variant {
             // Read the instruction.
             0 => Ok(Self::Instruction(Instruction::read_le(&mut reader)?)),
             // Read the `contains` operation.
-            1 => Ok(Self::Contains(Contains::read_le(&mut reader)?)),
+            1 => Ok(Self::Contains(Contains::read_le_versioned(&mut reader, version)?)),
             // Read the `get` operation.
-            2 => Ok(Self::Get(Get::read_le(&mut reader)?)),
+            2 => Ok(Self::Get(Get::read_le_versioned(&mut reader, version)?)),
             // Read the `get.or_use` operation.
-            3 => Ok(Self::GetOrUse(GetOrUse::read_le(&mut reader)?)),
+            3 => Ok(Self::GetOrUse(GetOrUse::read_le_versioned(&mut reader, version)?)),
             // Read the `rand.chacha` operation.
             4 => Ok(Self::RandChaCha(RandChaCha::read_le(&mut reader)?)),
             // Read the `remove` operation.
@@ -195,20 +192,20 @@ impl<N: Network> FromBytes for Command<N> {
             // Read the `set` operation.
             6 => Ok(Self::Set(Set::read_le(&mut reader)?)),
             // Read the `branch.eq` command.
-            7 => Ok(Self::BranchEq(BranchEq::read_le(&mut reader)?)),
+            7 => Ok(Self::BranchEq(BranchEq::read_le_versioned(&mut reader, version)?)),
             // Read the `branch.neq` command.
-            8 => Ok(Self::BranchNeq(BranchNeq::read_le(&mut reader)?)),
+            8 => Ok(Self::BranchNeq(BranchNeq::read_le_versioned(&mut reader, version)?)),
             // Read the `position` command.
             9 => Ok(Self::Position(Position::read_le(&mut reader)?)),
+            // Read the `emit` command.
+            10 => Ok(Self::Emit(Emit::read_le(&mut reader)?)),
             // Invalid variant.
-            10.. => Err(error(format!("Invalid command variant: {variant}"))),
+            11.. => Err(error(format!("Invalid command variant: {variant}"))),
         }
     }
-}
 
-impl<N: Network> ToBytes for Command<N> {
-    /// Writes the command to a buffer.
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+    /// Writes the command to a buffer, using the encodings of the given program version.
+    fn write_le_versioned<W: Write>(&self, mut writer: W, version: u8) -> IoResult<()> {
         match self {
             Self::Instruction(instruction) => {
                 // Write the variant.
@@ -220,19 +217,19 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the variant.
                 1u8.write_le(&mut writer)?;
                 // Write the `contains` operation.
-                contains.write_le(&mut writer)
+                contains.write_le_versioned(&mut writer, version)
             }
             Self::Get(get) => {
                 // Write the variant.
                 2u8.write_le(&mut writer)?;
                 // Write the `get` operation.
-                get.write_le(&mut writer)
+                get.write_le_versioned(&mut writer, version)
             }
             Self::GetOrUse(get_or_use) => {
                 // Write the variant.
                 3u8.write_le(&mut writer)?;
                 // Write the defaulting `get` operation.
-                get_or_use.write_le(&mut writer)
+                get_or_use.write_le_versioned(&mut writer, version)
             }
             Self::RandChaCha(rand_chacha) => {
                 // Write the variant.
@@ -256,13 +253,13 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the variant.
                 7u8.write_le(&mut writer)?;
                 // Write the `branch.eq` command.
-                branch_eq.write_le(&mut writer)
+                branch_eq.write_le_versioned(&mut writer, version)
             }
             Self::BranchNeq(branch_neq) => {
                 // Write the variant.
                 8u8.write_le(&mut writer)?;
                 // Write the `branch.neq` command.
-                branch_neq.write_le(&mut writer)
+                branch_neq.write_le_versioned(&mut writer, version)
             }
             Self::Position(position) => {
                 // Write the variant.
@@ -270,10 +267,94 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the position command.
                 position.write_le(&mut writer)
             }
+            Self::Emit(emit) => {
+                // Write the variant.
+                10u8.write_le(&mut writer)?;
+                // Write the `emit` command.
+                emit.write_le(&mut writer)
+            }
         }
     }
 }
 
+impl<N: Network> Command<N> {
+    /// Returns the opcode of the command.
+    #[inline]
+    pub fn opcode(&self) -> Opcode {
+        match self {
+            Command::Instruction(instruction) => instruction.opcode(),
+            Command::Contains(_) => Contains::<N>::opcode(),
+            Command::Get(_) => Get::<N>::opcode(),
+            Command::GetOrUse(_) => GetOrUse::<N>::opcode(),
+            Command::RandChaCha(_) => RandChaCha::<N>::opcode(),
+            Command::Remove(_) => Remove::<N>::opcode(),
+            Command::Set(_) => Set::<N>::opcode(),
+            Command::BranchEq(_) => BranchEq::<N>::opcode(),
+            Command::BranchNeq(_) => BranchNeq::<N>::opcode(),
+            Command::Position(_) => Position::<N>::opcode(),
+            Command::Emit(_) => Emit::<N>::opcode(),
+        }
+    }
+
+    /// Returns the developer-supplied message of the command, if the command is an `assert` with a message.
+    #[inline]
+    pub fn message(&self) -> Option<&StringType<N>> {
+        match self {
+            Command::Instruction(Instruction::AssertEq(assert_eq)) => assert_eq.message(),
+            Command::Instruction(Instruction::AssertNeq(assert_neq)) => assert_neq.message(),
+            _ => None,
+        }
+    }
+
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        store: &impl FinalizeStoreTrait<N>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N> + FinalizeRegistersState<N>),
+    ) -> Result<Option<FinalizeOperation<N>>> {
+        match self {
+            // Finalize the instruction, and return no finalize operation.
+            Command::Instruction(instruction) => instruction.finalize(stack, registers).map(|_| None),
+            // Finalize the 'contains' command, and return no finalize operation.
+            Command::Contains(contains) => contains.finalize(stack, store, registers).map(|_| None),
+            // Finalize the 'get' command, and return no finalize operation.
+            Command::Get(get) => get.finalize(stack, store, registers).map(|_| None),
+            // Finalize the 'get.or_use' command, and return no finalize operation.
+            Command::GetOrUse(get_or_use) => get_or_use.finalize(stack, store, registers).map(|_| None),
+            // Finalize the `rand.chacha` command, and return no finalize operation.
+            Command::RandChaCha(rand_chacha) => rand_chacha.finalize(stack, registers).map(|_| None),
+            // Finalize the 'remove' command, and return the finalize operation.
+            Command::Remove(remove) => remove.finalize(stack, store, registers).map(Some),
+            // Finalize the 'set' command, and return the finalize operation.
+            Command::Set(set) => set.finalize(stack, store, registers).map(Some),
+            // 'branch.eq' and 'branch.neq' instructions are processed by the caller of this method.
+            Command::BranchEq(_) | Command::BranchNeq(_) => {
+                bail!("`branch` instructions cannot be finalized directly.")
+            }
+            // Finalize the `position` command, and return no finalize operation.
+            Command::Position(position) => position.finalize().map(|_| None),
+            // 'emit' commands are processed by the caller of this method.
+            Command::Emit(_) => bail!("`emit` commands cannot be finalized directly."),
+        }
+    }
+}
+
+impl<N: Network> FromBytes for Command<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_versioned(reader, PROGRAM_VERSION_1)
+    }
+}
+
+impl<N: Network> ToBytes for Command<N> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_le_versioned(writer, PROGRAM_VERSION_1)
+    }
+}
+
 impl<N: Network> Parser for Command<N> {
     /// Parses the string into the command.
     #[inline]
@@ -290,6 +371,7 @@ impl<N: Network> Parser for Command<N> {
             map(BranchEq::parse, |branch_eq| Self::BranchEq(branch_eq)),
             map(BranchNeq::parse, |branch_neq| Self::BranchNeq(branch_neq)),
             map(Position::parse, |position| Self::Position(position)),
+            map(Emit::parse, |emit| Self::Emit(emit)),
             map(Instruction::parse, |instruction| Self::Instruction(instruction)),
         ))(string)
     }
@@ -334,6 +416,7 @@ impl<N: Network> Display for Command<N> {
             Self::BranchEq(branch_eq) => Display::fmt(branch_eq, f),
             Self::BranchNeq(branch_neq) => Display::fmt(branch_neq, f),
             Self::Position(position) => Display::fmt(position, f),
+            Self::Emit(emit) => Display::fmt(emit, f),
         }
     }
 }
@@ -420,6 +503,12 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Emit
+        let expected = "emit transfer r0 r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
     }
 
     #[test]
@@ -497,5 +586,11 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(Command::Position(Position::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
+
+        // Emit
+        let expected = "emit transfer r0 r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Emit(Emit::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
     }
 }
@@ -15,6 +15,7 @@
 use crate::{
     traits::{FinalizeStoreTrait, RegistersLoad, StackMatches, StackProgram},
     FinalizeOperation,
+    FinalizeRegistersState,
     Opcode,
     Operand,
 };
@@ -63,7 +64,7 @@ impl<N: Network> Remove<N> {
         &self,
         stack: &(impl StackMatches<N> + StackProgram<N>),
         store: &impl FinalizeStoreTrait<N>,
-        registers: &mut impl RegistersLoad<N>,
+        registers: &mut (impl RegistersLoad<N> + FinalizeRegistersState<N>),
     ) -> Result<FinalizeOperation<N>> {
         // Ensure the mapping exists in storage.
         if !store.contains_mapping_confirmed(stack.program_id(), &self.mapping)? {
@@ -73,7 +74,11 @@ impl<N: Network> Remove<N> {
         // Load the key operand as a plaintext.
         let key = registers.load_plaintext(stack, &self.key)?;
         // Update the value in storage, and return the finalize operation.
-        store.remove_key_value(stack.program_id(), &self.mapping, &key)
+        // Note: Once storage pricing is active, the operation commits to the size of the removed entry.
+        match registers.state().block_height() >= N::STORAGE_PRICING_HEIGHT {
+            true => store.remove_sized_key_value(stack.program_id(), &self.mapping, &key),
+            false => store.remove_key_value(stack.program_id(), &self.mapping, &key),
+        }
     }
 }
 
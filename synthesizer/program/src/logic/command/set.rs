@@ -15,6 +15,7 @@
 use crate::{
     traits::{FinalizeStoreTrait, RegistersLoad, StackMatches, StackProgram},
     FinalizeOperation,
+    FinalizeRegistersState,
     Opcode,
     Operand,
 };
@@ -74,7 +75,7 @@ impl<N: Network> Set<N> {
         &self,
         stack: &(impl StackMatches<N> + StackProgram<N>),
         store: &impl FinalizeStoreTrait<N>,
-        registers: &mut impl RegistersLoad<N>,
+        registers: &mut (impl RegistersLoad<N> + FinalizeRegistersState<N>),
     ) -> Result<FinalizeOperation<N>> {
         // Ensure the mapping exists in storage.
         if !store.contains_mapping_confirmed(stack.program_id(), &self.mapping)? {
@@ -87,7 +88,12 @@ impl<N: Network> Set<N> {
         let value = Value::Plaintext(registers.load_plaintext(stack, &self.value)?);
 
         // Update the value in storage, and return the finalize operation.
-        store.update_key_value(stack.program_id(), &self.mapping, key, value)
+        // Note: Once storage pricing is active, the operation commits to the sizes of the entries,
+        // and distinguishes a new entry from an overwritten one.
+        match registers.state().block_height() >= N::STORAGE_PRICING_HEIGHT {
+            true => store.update_sized_key_value(stack.program_id(), &self.mapping, key, value),
+            false => store.update_key_value(stack.program_id(), &self.mapping, key, value),
+        }
     }
 }
 
@@ -211,5 +217,8 @@ mod tests {
         assert_eq!(set.operands().len(), 2, "The number of operands is incorrect");
         assert_eq!(set.value, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(set.key, Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+
+        // Ensure a mapping in another program cannot be written to.
+        assert!(Set::<CurrentNetwork>::parse("set r0 into token.aleo/account[r1];").is_err());
     }
 }
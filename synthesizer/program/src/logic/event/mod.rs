@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID},
+    types::Field,
+};
+
+/// An event emitted by a `finalize` scope, as (`program ID`, `event name`, `values`).
+#[derive(Clone, PartialEq, Eq)]
+pub struct Event<N: Network> {
+    /// The program ID that emitted the event.
+    program_id: ProgramID<N>,
+    /// The name of the event.
+    name: Identifier<N>,
+    /// The values of the event.
+    values: Vec<Plaintext<N>>,
+}
+
+impl<N: Network> Event<N> {
+    /// Initializes a new event.
+    pub fn new(program_id: ProgramID<N>, name: Identifier<N>, values: Vec<Plaintext<N>>) -> Result<Self> {
+        // Ensure the number of values is within bounds.
+        ensure!(values.len() <= N::MAX_OPERANDS, "An event cannot contain more than {} values", N::MAX_OPERANDS);
+        // Return the event.
+        Ok(Self { program_id, name, values })
+    }
+
+    /// Returns the program ID that emitted the event.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the name of the event.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the values of the event.
+    pub fn values(&self) -> &[Plaintext<N>] {
+        &self.values
+    }
+
+    /// Returns the event ID, which commits to the program ID, event name, and values.
+    pub fn to_id(&self) -> Result<Field<N>> {
+        // Construct the preimage.
+        let mut preimage = (self.program_id, self.name).to_bits_le();
+        preimage.extend_from_slice(&(self.values.len() as u8).to_bits_le());
+        self.values.iter().for_each(|value| preimage.extend_from_slice(&value.to_bits_le()));
+        // Hash the preimage.
+        N::hash_bhp1024(&preimage)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a list of events.
+    pub(crate) fn sample_events() -> Vec<Event<CurrentNetwork>> {
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        vec![
+            Event::new(program_id, Identifier::from_str("mint").unwrap(), vec![]).unwrap(),
+            Event::new(
+                program_id,
+                Identifier::from_str("transfer").unwrap(),
+                vec![
+                    Plaintext::from_str("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah").unwrap(),
+                    Plaintext::from_str("{ amount: 10u64, memo: 5field }").unwrap(),
+                ],
+            )
+            .unwrap(),
+        ]
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for FinalizeDiff<N> {
+    /// Reads the finalize diff from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        // Read the mapping name.
+        let mapping_name = Identifier::read_le(&mut reader)?;
+        // Read the key.
+        let key = Plaintext::read_le(&mut reader)?;
+        // Read the old value.
+        let old_value = match bool::read_le(&mut reader)? {
+            true => Some(Value::read_le(&mut reader)?),
+            false => None,
+        };
+        // Read the new value.
+        let new_value = match bool::read_le(&mut reader)? {
+            true => Some(Value::read_le(&mut reader)?),
+            false => None,
+        };
+        // Return the finalize diff.
+        Self::new(program_id, mapping_name, key, old_value, new_value).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for FinalizeDiff<N> {
+    /// Writes the finalize diff to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the mapping name.
+        self.mapping_name.write_le(&mut writer)?;
+        // Write the key.
+        self.key.write_le(&mut writer)?;
+        // Write the old value.
+        self.old_value.is_some().write_le(&mut writer)?;
+        if let Some(old_value) = &self.old_value {
+            old_value.write_le(&mut writer)?;
+        }
+        // Write the new value.
+        self.new_value.is_some().write_le(&mut writer)?;
+        if let Some(new_value) = &self.new_value {
+            new_value.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        for expected in crate::logic::finalize_diff::test_helpers::sample_finalize_diffs() {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, FinalizeDiff::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
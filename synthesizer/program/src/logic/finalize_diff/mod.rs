@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+};
+
+/// A human-readable change to a mapping entry, resolved from the finalize operations of a transaction,
+/// as (`program ID`, `mapping name`, `key`, `old value`, `new value`).
+///
+/// An insertion has no old value, and a removal has no new value.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FinalizeDiff<N: Network> {
+    /// The program ID of the mapping.
+    program_id: ProgramID<N>,
+    /// The name of the mapping.
+    mapping_name: Identifier<N>,
+    /// The key of the mapping entry.
+    key: Plaintext<N>,
+    /// The value of the mapping entry prior to the change, if it existed.
+    old_value: Option<Value<N>>,
+    /// The value of the mapping entry after the change, if it still exists.
+    new_value: Option<Value<N>>,
+}
+
+impl<N: Network> FinalizeDiff<N> {
+    /// Initializes a new finalize diff.
+    pub fn new(
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Plaintext<N>,
+        old_value: Option<Value<N>>,
+        new_value: Option<Value<N>>,
+    ) -> Result<Self> {
+        // Ensure the diff changes a value.
+        ensure!(old_value.is_some() || new_value.is_some(), "A finalize diff must contain an old or new value");
+        // Return the finalize diff.
+        Ok(Self { program_id, mapping_name, key, old_value, new_value })
+    }
+
+    /// Returns the program ID of the mapping.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the name of the mapping.
+    pub const fn mapping_name(&self) -> &Identifier<N> {
+        &self.mapping_name
+    }
+
+    /// Returns the key of the mapping entry.
+    pub const fn key(&self) -> &Plaintext<N> {
+        &self.key
+    }
+
+    /// Returns the value of the mapping entry prior to the change, if it existed.
+    pub const fn old_value(&self) -> Option<&Value<N>> {
+        self.old_value.as_ref()
+    }
+
+    /// Returns the value of the mapping entry after the change, if it still exists.
+    pub const fn new_value(&self) -> Option<&Value<N>> {
+        self.new_value.as_ref()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a list of finalize diffs.
+    pub(crate) fn sample_finalize_diffs() -> Vec<FinalizeDiff<CurrentNetwork>> {
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah").unwrap();
+        vec![
+            FinalizeDiff::new(program_id, mapping_name, key.clone(), None, Some(Value::from_str("10u64").unwrap()))
+                .unwrap(),
+            FinalizeDiff::new(
+                program_id,
+                mapping_name,
+                key.clone(),
+                Some(Value::from_str("10u64").unwrap()),
+                Some(Value::from_str("5u64").unwrap()),
+            )
+            .unwrap(),
+            FinalizeDiff::new(program_id, mapping_name, key, Some(Value::from_str("5u64").unwrap()), None).unwrap(),
+        ]
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for FinalizeDiff<N> {
+    /// Serializes the finalize diff to a JSON-string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut diff = serializer.serialize_struct("FinalizeDiff", 5)?;
+                diff.serialize_field("program_id", &self.program_id)?;
+                diff.serialize_field("mapping_name", &self.mapping_name)?;
+                diff.serialize_field("key", &self.key)?;
+                diff.serialize_field("old_value", &self.old_value)?;
+                diff.serialize_field("new_value", &self.new_value)?;
+                diff.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for FinalizeDiff<N> {
+    /// Deserializes the finalize diff from a JSON-string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut diff = serde_json::Value::deserialize(deserializer)?;
+                // Recover the finalize diff.
+                Self::new(
+                    // Retrieve the program ID.
+                    DeserializeExt::take_from_value::<D>(&mut diff, "program_id")?,
+                    // Retrieve the mapping name.
+                    DeserializeExt::take_from_value::<D>(&mut diff, "mapping_name")?,
+                    // Retrieve the key.
+                    DeserializeExt::take_from_value::<D>(&mut diff, "key")?,
+                    // Retrieve the old value.
+                    DeserializeExt::take_from_value::<D>(&mut diff, "old_value")?,
+                    // Retrieve the new value.
+                    DeserializeExt::take_from_value::<D>(&mut diff, "new_value")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "finalize diff"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        for expected in crate::logic::finalize_diff::test_helpers::sample_finalize_diffs() {
+            // Serialize
+            let expected_string = expected.to_string();
+            let candidate_string = serde_json::to_string(&expected).unwrap();
+            assert_eq!(expected_string, candidate_string);
+
+            // Deserialize
+            assert_eq!(expected, FinalizeDiff::from_str(&expected_string).unwrap());
+            assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_bincode() {
+        for expected in crate::logic::finalize_diff::test_helpers::sample_finalize_diffs() {
+            // Serialize
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected).unwrap();
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected, FinalizeDiff::read_le(&expected_bytes[..]).unwrap());
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
+        }
+    }
+}
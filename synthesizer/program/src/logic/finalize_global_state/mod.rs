@@ -18,6 +18,10 @@ use console::network::prelude::*;
 pub struct FinalizeGlobalState {
     /// The block height.
     block_height: u32,
+    /// The block timestamp.
+    block_timestamp: i64,
+    /// The previous block hash, as little-endian bytes.
+    previous_block_hash: [u8; 32],
     /// The block-specific random seed.
     random_seed: [u8; 32],
 }
@@ -28,6 +32,7 @@ impl FinalizeGlobalState {
     pub fn new<N: Network>(
         block_round: u64,
         block_height: u32,
+        block_timestamp: i64,
         block_cumulative_weight: u128,
         block_cumulative_proof_target: u128,
         previous_block_hash: N::BlockHash,
@@ -49,13 +54,27 @@ impl FinalizeGlobalState {
         let mut random_seed = [0u8; 32];
         random_seed.copy_from_slice(&seed[..32]);
 
-        Ok(Self { block_height, random_seed })
+        // Retrieve the previous block hash bytes.
+        let hash = (*previous_block_hash).to_bytes_le()?;
+        // Ensure the previous block hash is 32-bytes.
+        ensure!(hash.len() == 32, "Invalid block hash length for finalize global state.");
+
+        // Convert the previous block hash into a 32-byte array.
+        let mut previous_block_hash = [0u8; 32];
+        previous_block_hash.copy_from_slice(&hash[..32]);
+
+        Ok(Self { block_height, block_timestamp, previous_block_hash, random_seed })
     }
 
     /// Initializes a new global state.
     #[inline]
-    pub const fn from(block_height: u32, random_seed: [u8; 32]) -> Self {
-        Self { block_height, random_seed }
+    pub const fn from(
+        block_height: u32,
+        block_timestamp: i64,
+        previous_block_hash: [u8; 32],
+        random_seed: [u8; 32],
+    ) -> Self {
+        Self { block_height, block_timestamp, previous_block_hash, random_seed }
     }
 
     /// Returns the block height.
@@ -64,6 +83,18 @@ impl FinalizeGlobalState {
         self.block_height
     }
 
+    /// Returns the block timestamp.
+    #[inline]
+    pub const fn block_timestamp(&self) -> i64 {
+        self.block_timestamp
+    }
+
+    /// Returns the previous block hash, as little-endian bytes.
+    #[inline]
+    pub const fn previous_block_hash(&self) -> &[u8; 32] {
+        &self.previous_block_hash
+    }
+
     /// Returns the random seed.
     #[inline]
     pub const fn random_seed(&self) -> &[u8; 32] {
@@ -75,7 +75,45 @@ impl<N: Network> FromBits for FinalizeOperation<N> {
                 // Return the finalize operation.
                 Ok(Self::RemoveMapping(mapping_id))
             }
-            5.. => bail!("Invalid finalize operation variant '{variant}'"),
+            5 => {
+                // Read the mapping ID.
+                let mapping_id = Field::from_bits_le(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the key ID.
+                let key_id = Field::from_bits_le(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the value ID.
+                let value_id = Field::from_bits_le(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the size.
+                let size = u64::from_bits_le(&next_bits(64)?)?;
+                // Return the finalize operation.
+                Ok(Self::InsertSizedKeyValue(mapping_id, key_id, value_id, size))
+            }
+            6 => {
+                // Read the mapping ID.
+                let mapping_id = Field::from_bits_le(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the index.
+                let index = u64::from_bits_le(&next_bits(64)?)?;
+                // Read the key ID.
+                let key_id = Field::from_bits_le(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the value ID.
+                let value_id = Field::from_bits_le(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the previous size.
+                let previous_size = u64::from_bits_le(&next_bits(64)?)?;
+                // Read the size.
+                let size = u64::from_bits_le(&next_bits(64)?)?;
+                // Return the finalize operation.
+                Ok(Self::UpdateSizedKeyValue(mapping_id, index, key_id, value_id, previous_size, size))
+            }
+            7 => {
+                // Read the mapping ID.
+                let mapping_id = Field::from_bits_le(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the index.
+                let index = u64::from_bits_le(&next_bits(64)?)?;
+                // Read the size.
+                let size = u64::from_bits_le(&next_bits(64)?)?;
+                // Return the finalize operation.
+                Ok(Self::RemoveSizedKeyValue(mapping_id, index, size))
+            }
+            8.. => bail!("Invalid finalize operation variant '{variant}'"),
         }
     }
 
@@ -139,7 +177,45 @@ impl<N: Network> FromBits for FinalizeOperation<N> {
                 // Return the finalize operation.
                 Ok(Self::RemoveMapping(mapping_id))
             }
-            5.. => bail!("Invalid finalize operation variant '{variant}'"),
+            5 => {
+                // Read the mapping ID.
+                let mapping_id = Field::from_bits_be(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the key ID.
+                let key_id = Field::from_bits_be(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the value ID.
+                let value_id = Field::from_bits_be(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the size.
+                let size = u64::from_bits_be(&next_bits(64)?)?;
+                // Return the finalize operation.
+                Ok(Self::InsertSizedKeyValue(mapping_id, key_id, value_id, size))
+            }
+            6 => {
+                // Read the mapping ID.
+                let mapping_id = Field::from_bits_be(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the index.
+                let index = u64::from_bits_be(&next_bits(64)?)?;
+                // Read the key ID.
+                let key_id = Field::from_bits_be(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the value ID.
+                let value_id = Field::from_bits_be(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the previous size.
+                let previous_size = u64::from_bits_be(&next_bits(64)?)?;
+                // Read the size.
+                let size = u64::from_bits_be(&next_bits(64)?)?;
+                // Return the finalize operation.
+                Ok(Self::UpdateSizedKeyValue(mapping_id, index, key_id, value_id, previous_size, size))
+            }
+            7 => {
+                // Read the mapping ID.
+                let mapping_id = Field::from_bits_be(&next_bits(Field::<N>::size_in_bits())?)?;
+                // Read the index.
+                let index = u64::from_bits_be(&next_bits(64)?)?;
+                // Read the size.
+                let size = u64::from_bits_be(&next_bits(64)?)?;
+                // Return the finalize operation.
+                Ok(Self::RemoveSizedKeyValue(mapping_id, index, size))
+            }
+            8.. => bail!("Invalid finalize operation variant '{variant}'"),
         }
     }
 }
@@ -205,6 +281,53 @@ impl<N: Network> ToBits for FinalizeOperation<N> {
                 ]
                 .concat()
             }
+            Self::InsertSizedKeyValue(mapping_id, key_id, value_id, size) => {
+                vec![
+                    // Write the variant.
+                    5u8.to_bits_le(),
+                    // Write the mapping ID.
+                    mapping_id.to_bits_le(),
+                    // Write the key ID.
+                    key_id.to_bits_le(),
+                    // Write the value ID.
+                    value_id.to_bits_le(),
+                    // Write the size.
+                    size.to_bits_le(),
+                ]
+                .concat()
+            }
+            Self::UpdateSizedKeyValue(mapping_id, index, key_id, value_id, previous_size, size) => {
+                vec![
+                    // Write the variant.
+                    6u8.to_bits_le(),
+                    // Write the mapping ID.
+                    mapping_id.to_bits_le(),
+                    // Write the index.
+                    index.to_bits_le(),
+                    // Write the key ID.
+                    key_id.to_bits_le(),
+                    // Write the value ID.
+                    value_id.to_bits_le(),
+                    // Write the previous size.
+                    previous_size.to_bits_le(),
+                    // Write the size.
+                    size.to_bits_le(),
+                ]
+                .concat()
+            }
+            Self::RemoveSizedKeyValue(mapping_id, index, size) => {
+                vec![
+                    // Write the variant.
+                    7u8.to_bits_le(),
+                    // Write the mapping ID.
+                    mapping_id.to_bits_le(),
+                    // Write the index.
+                    index.to_bits_le(),
+                    // Write the size.
+                    size.to_bits_le(),
+                ]
+                .concat()
+            }
         }
     }
 
@@ -268,6 +391,53 @@ impl<N: Network> ToBits for FinalizeOperation<N> {
                 ]
                 .concat()
             }
+            Self::InsertSizedKeyValue(mapping_id, key_id, value_id, size) => {
+                vec![
+                    // Write the variant.
+                    5u8.to_bits_be(),
+                    // Write the mapping ID.
+                    mapping_id.to_bits_be(),
+                    // Write the key ID.
+                    key_id.to_bits_be(),
+                    // Write the value ID.
+                    value_id.to_bits_be(),
+                    // Write the size.
+                    size.to_bits_be(),
+                ]
+                .concat()
+            }
+            Self::UpdateSizedKeyValue(mapping_id, index, key_id, value_id, previous_size, size) => {
+                vec![
+                    // Write the variant.
+                    6u8.to_bits_be(),
+                    // Write the mapping ID.
+                    mapping_id.to_bits_be(),
+                    // Write the index.
+                    index.to_bits_be(),
+                    // Write the key ID.
+                    key_id.to_bits_be(),
+                    // Write the value ID.
+                    value_id.to_bits_be(),
+                    // Write the previous size.
+                    previous_size.to_bits_be(),
+                    // Write the size.
+                    size.to_bits_be(),
+                ]
+                .concat()
+            }
+            Self::RemoveSizedKeyValue(mapping_id, index, size) => {
+                vec![
+                    // Write the variant.
+                    7u8.to_bits_be(),
+                    // Write the mapping ID.
+                    mapping_id.to_bits_be(),
+                    // Write the index.
+                    index.to_bits_be(),
+                    // Write the size.
+                    size.to_bits_be(),
+                ]
+                .concat()
+            }
         }
     }
 }
@@ -63,7 +63,45 @@ impl<N: Network> FromBytes for FinalizeOperation<N> {
                 // Return the finalize operation.
                 Ok(Self::RemoveMapping(mapping_id))
             }
-            5.. => Err(error(format!("Failed to decode finalize operation variant {variant}"))),
+            5 => {
+                // Read the mapping ID.
+                let mapping_id = Field::read_le(&mut reader)?;
+                // Read the key ID.
+                let key_id = Field::read_le(&mut reader)?;
+                // Read the value ID.
+                let value_id = Field::read_le(&mut reader)?;
+                // Read the size.
+                let size = u64::read_le(&mut reader)?;
+                // Return the finalize operation.
+                Ok(Self::InsertSizedKeyValue(mapping_id, key_id, value_id, size))
+            }
+            6 => {
+                // Read the mapping ID.
+                let mapping_id = Field::read_le(&mut reader)?;
+                // Read the index.
+                let index = u64::read_le(&mut reader)?;
+                // Read the key ID.
+                let key_id = Field::read_le(&mut reader)?;
+                // Read the value ID.
+                let value_id = Field::read_le(&mut reader)?;
+                // Read the previous size.
+                let previous_size = u64::read_le(&mut reader)?;
+                // Read the size.
+                let size = u64::read_le(&mut reader)?;
+                // Return the finalize operation.
+                Ok(Self::UpdateSizedKeyValue(mapping_id, index, key_id, value_id, previous_size, size))
+            }
+            7 => {
+                // Read the mapping ID.
+                let mapping_id = Field::read_le(&mut reader)?;
+                // Read the index.
+                let index = u64::read_le(&mut reader)?;
+                // Read the size.
+                let size = u64::read_le(&mut reader)?;
+                // Return the finalize operation.
+                Ok(Self::RemoveSizedKeyValue(mapping_id, index, size))
+            }
+            8.. => Err(error(format!("Failed to decode finalize operation variant {variant}"))),
         }
     }
 }
@@ -114,6 +152,44 @@ impl<N: Network> ToBytes for FinalizeOperation<N> {
                 // Write the mapping ID.
                 mapping_id.write_le(&mut writer)?;
             }
+            Self::InsertSizedKeyValue(mapping_id, key_id, value_id, size) => {
+                // Write the variant.
+                5u8.write_le(&mut writer)?;
+                // Write the mapping ID.
+                mapping_id.write_le(&mut writer)?;
+                // Write the key ID.
+                key_id.write_le(&mut writer)?;
+                // Write the value ID.
+                value_id.write_le(&mut writer)?;
+                // Write the size.
+                size.write_le(&mut writer)?;
+            }
+            Self::UpdateSizedKeyValue(mapping_id, index, key_id, value_id, previous_size, size) => {
+                // Write the variant.
+                6u8.write_le(&mut writer)?;
+                // Write the mapping ID.
+                mapping_id.write_le(&mut writer)?;
+                // Write the index.
+                index.write_le(&mut writer)?;
+                // Write the key ID.
+                key_id.write_le(&mut writer)?;
+                // Write the value ID.
+                value_id.write_le(&mut writer)?;
+                // Write the previous size.
+                previous_size.write_le(&mut writer)?;
+                // Write the size.
+                size.write_le(&mut writer)?;
+            }
+            Self::RemoveSizedKeyValue(mapping_id, index, size) => {
+                // Write the variant.
+                7u8.write_le(&mut writer)?;
+                // Write the mapping ID.
+                mapping_id.write_le(&mut writer)?;
+                // Write the index.
+                index.write_le(&mut writer)?;
+                // Write the size.
+                size.write_le(&mut writer)?;
+            }
         }
         Ok(())
     }
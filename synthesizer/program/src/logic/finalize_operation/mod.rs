@@ -35,6 +35,15 @@ pub enum FinalizeOperation<N: Network> {
     RemoveKeyValue(Field<N>, u64),
     /// Removes a mapping from the program tree, as (`mapping ID`).
     RemoveMapping(Field<N>),
+    /// Inserts a key-value leaf into the mapping tree, with the size of the entry in bytes,
+    /// as (`mapping ID`, `key ID`, `value ID`, `size`).
+    InsertSizedKeyValue(Field<N>, Field<N>, Field<N>, u64),
+    /// Updates the key-value leaf at the given index in the mapping tree, with the sizes of the previous
+    /// and new entries in bytes, as (`mapping ID`, `index`, `key ID`, `value ID`, `previous size`, `size`).
+    UpdateSizedKeyValue(Field<N>, u64, Field<N>, Field<N>, u64, u64),
+    /// Removes the key-value leaf at the given index in the mapping tree, with the size of the entry in bytes,
+    /// as (`mapping ID`, `index`, `size`).
+    RemoveSizedKeyValue(Field<N>, u64, u64),
 }
 
 #[cfg(test)]
@@ -69,6 +78,22 @@ pub(crate) mod test_helpers {
         FinalizeOperation::RemoveMapping(Uniform::rand(rng))
     }
 
+    /// Samples a random `InsertSizedKeyValue`.
+    pub(crate) fn sample_insert_sized_key_value(rng: &mut TestRng) -> FinalizeOperation<CurrentNetwork> {
+        FinalizeOperation::InsertSizedKeyValue(Uniform::rand(rng), Uniform::rand(rng), Uniform::rand(rng), rng.gen())
+    }
+
+    /// Samples a random `UpdateSizedKeyValue`.
+    pub(crate) fn sample_update_sized_key_value(rng: &mut TestRng) -> FinalizeOperation<CurrentNetwork> {
+        let (mapping_id, key_id, value_id) = (Uniform::rand(rng), Uniform::rand(rng), Uniform::rand(rng));
+        FinalizeOperation::UpdateSizedKeyValue(mapping_id, rng.gen(), key_id, value_id, rng.gen(), rng.gen())
+    }
+
+    /// Samples a random `RemoveSizedKeyValue`.
+    pub(crate) fn sample_remove_sized_key_value(rng: &mut TestRng) -> FinalizeOperation<CurrentNetwork> {
+        FinalizeOperation::RemoveSizedKeyValue(Uniform::rand(rng), rng.gen(), rng.gen())
+    }
+
     /// Samples a list of random `FinalizeOperation`.
     pub(crate) fn sample_finalize_operations() -> Vec<FinalizeOperation<CurrentNetwork>> {
         let rng = &mut TestRng::default();
@@ -79,11 +104,17 @@ pub(crate) mod test_helpers {
             sample_update_key_value(rng),
             sample_remove_key_value(rng),
             sample_remove_mapping(rng),
+            sample_insert_sized_key_value(rng),
+            sample_update_sized_key_value(rng),
+            sample_remove_sized_key_value(rng),
             sample_initialize_mapping(rng),
             sample_insert_key_value(rng),
             sample_update_key_value(rng),
             sample_remove_key_value(rng),
             sample_remove_mapping(rng),
+            sample_insert_sized_key_value(rng),
+            sample_update_sized_key_value(rng),
+            sample_remove_sized_key_value(rng),
         ]
     }
 }
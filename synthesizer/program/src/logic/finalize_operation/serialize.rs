@@ -19,7 +19,7 @@ impl<N: Network> Serialize for FinalizeOperation<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut operation = serializer.serialize_struct("FinalizeOperation", 5)?;
+                let mut operation = serializer.serialize_struct("FinalizeOperation", 7)?;
                 // Serialize the components.
                 match self {
                     Self::InitializeMapping(mapping_id) => {
@@ -48,6 +48,28 @@ impl<N: Network> Serialize for FinalizeOperation<N> {
                         operation.serialize_field("type", "remove_mapping")?;
                         operation.serialize_field("mapping_id", mapping_id)?;
                     }
+                    Self::InsertSizedKeyValue(mapping_id, key_id, value_id, size) => {
+                        operation.serialize_field("type", "insert_sized_key_value")?;
+                        operation.serialize_field("mapping_id", mapping_id)?;
+                        operation.serialize_field("key_id", key_id)?;
+                        operation.serialize_field("value_id", value_id)?;
+                        operation.serialize_field("size", size)?;
+                    }
+                    Self::UpdateSizedKeyValue(mapping_id, index, key_id, value_id, previous_size, size) => {
+                        operation.serialize_field("type", "update_sized_key_value")?;
+                        operation.serialize_field("mapping_id", mapping_id)?;
+                        operation.serialize_field("index", index)?;
+                        operation.serialize_field("key_id", key_id)?;
+                        operation.serialize_field("value_id", value_id)?;
+                        operation.serialize_field("previous_size", previous_size)?;
+                        operation.serialize_field("size", size)?;
+                    }
+                    Self::RemoveSizedKeyValue(mapping_id, index, size) => {
+                        operation.serialize_field("type", "remove_sized_key_value")?;
+                        operation.serialize_field("mapping_id", mapping_id)?;
+                        operation.serialize_field("index", index)?;
+                        operation.serialize_field("size", size)?;
+                    }
                 }
                 operation.end()
             }
@@ -106,6 +128,44 @@ impl<'de, N: Network> Deserialize<'de> for FinalizeOperation<N> {
                         // Return the operation.
                         Self::RemoveMapping(mapping_id)
                     }
+                    Some("insert_sized_key_value") => {
+                        // Deserialize the mapping ID.
+                        let mapping_id = DeserializeExt::take_from_value::<D>(&mut operation, "mapping_id")?;
+                        // Deserialize the key ID.
+                        let key_id = DeserializeExt::take_from_value::<D>(&mut operation, "key_id")?;
+                        // Deserialize the value ID.
+                        let value_id = DeserializeExt::take_from_value::<D>(&mut operation, "value_id")?;
+                        // Deserialize the size.
+                        let size = DeserializeExt::take_from_value::<D>(&mut operation, "size")?;
+                        // Return the operation.
+                        Self::InsertSizedKeyValue(mapping_id, key_id, value_id, size)
+                    }
+                    Some("update_sized_key_value") => {
+                        // Deserialize the mapping ID.
+                        let mapping_id = DeserializeExt::take_from_value::<D>(&mut operation, "mapping_id")?;
+                        // Deserialize the index.
+                        let index = DeserializeExt::take_from_value::<D>(&mut operation, "index")?;
+                        // Deserialize the key ID.
+                        let key_id = DeserializeExt::take_from_value::<D>(&mut operation, "key_id")?;
+                        // Deserialize the value ID.
+                        let value_id = DeserializeExt::take_from_value::<D>(&mut operation, "value_id")?;
+                        // Deserialize the previous size.
+                        let previous_size = DeserializeExt::take_from_value::<D>(&mut operation, "previous_size")?;
+                        // Deserialize the size.
+                        let size = DeserializeExt::take_from_value::<D>(&mut operation, "size")?;
+                        // Return the operation.
+                        Self::UpdateSizedKeyValue(mapping_id, index, key_id, value_id, previous_size, size)
+                    }
+                    Some("remove_sized_key_value") => {
+                        // Deserialize the mapping ID.
+                        let mapping_id = DeserializeExt::take_from_value::<D>(&mut operation, "mapping_id")?;
+                        // Deserialize the index.
+                        let index = DeserializeExt::take_from_value::<D>(&mut operation, "index")?;
+                        // Deserialize the size.
+                        let size = DeserializeExt::take_from_value::<D>(&mut operation, "size")?;
+                        // Return the operation.
+                        Self::RemoveSizedKeyValue(mapping_id, index, size)
+                    }
                     _ => return Err(de::Error::custom("Invalid finalize operation type")),
                 };
                 // Return the operation.
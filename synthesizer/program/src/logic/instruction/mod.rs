@@ -101,6 +101,10 @@ pub enum Instruction<N: Network> {
     DivWrapped(DivWrapped<N>),
     /// Doubles `first`, storing the outcome in `destination`.
     Double(Double<N>),
+    /// Decrypts `first` with the view key `second`, storing the plaintext in `destination`.
+    Decrypt(Decrypt<N>),
+    /// Encrypts `first` to the address `second`, storing the ciphertext and its nonce in `destination`.
+    Encrypt(Encrypt<N>),
     /// Computes whether `first` is greater than `second` as a boolean, storing the outcome in `destination`.
     GreaterThan(GreaterThan<N>),
     /// Computes whether `first` is greater than or equal to `second` as a boolean, storing the outcome in `destination`.
@@ -273,6 +277,9 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            // Note: New instructions are appended, as the opcode index of an instruction is part of its bytes.
+            Encrypt,
+            Decrypt,
         }}
     };
     // A variant **without** curly braces:
@@ -442,9 +449,14 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            59,
+            61,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
+        // Ensure the opcode indices of the existing instructions are unchanged.
+        let index = |opcode| Instruction::<CurrentNetwork>::OPCODES.iter().position(|candidate| *candidate == opcode);
+        assert_eq!(Some(58), index(Xor::<CurrentNetwork>::opcode()));
+        assert_eq!(Some(59), index(Encrypt::<CurrentNetwork>::opcode()));
+        assert_eq!(Some(60), index(Decrypt::<CurrentNetwork>::opcode()));
     }
 }
@@ -27,6 +27,10 @@ pub enum Opcode {
     Command(&'static str),
     /// The opcode is for a commit operation (i.e. `commit.psd4`).
     Commit(&'static str),
+    /// The opcode is for a decrypt operation (i.e. `decrypt`).
+    Decrypt,
+    /// The opcode is for an encrypt operation (i.e. `encrypt`).
+    Encrypt,
     /// The opcode is for a finalize operation (i.e. `finalize`).
     Finalize(&'static str),
     /// The opcode is for a hash operation (i.e. `hash.psd4`).
@@ -48,6 +52,8 @@ impl Deref for Opcode {
             Opcode::Cast => &"cast",
             Opcode::Command(opcode) => opcode,
             Opcode::Commit(opcode) => opcode,
+            Opcode::Decrypt => &"decrypt",
+            Opcode::Encrypt => &"encrypt",
             Opcode::Finalize(opcode) => opcode,
             Opcode::Hash(opcode) => opcode,
             Opcode::Is(opcode) => opcode,
@@ -72,6 +78,8 @@ impl Display for Opcode {
             Self::Cast => write!(f, "{}", self.deref()),
             Self::Command(opcode) => write!(f, "{opcode}"),
             Self::Commit(opcode) => write!(f, "{opcode}"),
+            Self::Decrypt => write!(f, "{}", self.deref()),
+            Self::Encrypt => write!(f, "{}", self.deref()),
             Self::Finalize(opcode) => write!(f, "{opcode}"),
             Self::Hash(opcode) => write!(f, "{opcode}"),
             Self::Is(opcode) => write!(f, "{opcode}"),
@@ -22,6 +22,8 @@ impl<N: Network> FromBytes for Operand<N> {
             2 => Ok(Self::ProgramID(ProgramID::read_le(&mut reader)?)),
             3 => Ok(Self::Caller),
             4 => Ok(Self::BlockHeight),
+            5 => Ok(Self::BlockTimestamp),
+            6 => Ok(Self::PreviousBlockHash),
             variant => Err(error(format!("Failed to deserialize operand variant {variant}"))),
         }
     }
@@ -44,6 +46,8 @@ impl<N: Network> ToBytes for Operand<N> {
             }
             Self::Caller => 3u8.write_le(&mut writer),
             Self::BlockHeight => 4u8.write_le(&mut writer),
+            Self::BlockTimestamp => 5u8.write_le(&mut writer),
+            Self::PreviousBlockHash => 6u8.write_le(&mut writer),
         }
     }
 }
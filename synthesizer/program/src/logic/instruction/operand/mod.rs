@@ -37,6 +37,12 @@ pub enum Operand<N: Network> {
     /// The operand is the block height.
     /// Note: This variant is only accessible in the `finalize` scope.
     BlockHeight,
+    /// The operand is the block timestamp.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    BlockTimestamp,
+    /// The operand is the previous block hash.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    PreviousBlockHash,
 }
 
 impl<N: Network> From<Literal<N>> for Operand<N> {
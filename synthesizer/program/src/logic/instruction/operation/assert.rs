@@ -20,6 +20,7 @@ use crate::{
 use console::{
     network::prelude::*,
     program::{Register, RegisterType},
+    types::StringType,
 };
 
 /// Asserts two operands are equal to each other.
@@ -32,11 +33,13 @@ enum Variant {
     AssertNeq,
 }
 
-/// Asserts an operation on two operands.
+/// Asserts an operation on two operands, with an optional message to report on failure.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct AssertInstruction<N: Network, const VARIANT: u8> {
     /// The operands.
     operands: Vec<Operand<N>>,
+    /// The developer-supplied message, if any.
+    message: Option<StringType<N>>,
 }
 
 impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
@@ -46,9 +49,22 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
         // Sanity check that the operands is exactly two inputs.
         ensure!(operands.len() == 2, "Assert instructions must have two operands");
         // Return the instruction.
-        Ok(Self { operands })
+        Ok(Self { operands, message: None })
     }
 
+    /// Initializes a new `assert` instruction, with the given message to report on failure.
+    #[inline]
+    pub fn new_with_message(operands: Vec<Operand<N>>, message: StringType<N>) -> Result<Self> {
+        // Sanity check that the operands is exactly two inputs.
+        ensure!(operands.len() == 2, "Assert instructions must have two operands");
+        // Return the instruction.
+        Ok(Self { operands, message: Some(message) })
+    }
+
+    /// The marker that precedes an `assert` instruction with a message.
+    /// This is never a valid operand variant, so it never begins a legacy `assert` instruction.
+    const MESSAGE_MARKER: u8 = u8::MAX;
+
     /// Returns the opcode.
     #[inline]
     pub const fn opcode() -> Opcode {
@@ -68,6 +84,12 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
         &self.operands
     }
 
+    /// Returns the developer-supplied message, if any.
+    #[inline]
+    pub const fn message(&self) -> Option<&StringType<N>> {
+        self.message.as_ref()
+    }
+
     /// Returns the destination register.
     #[inline]
     pub fn destinations(&self) -> Vec<Register<N>> {
@@ -92,16 +114,25 @@ impl<N: Network, const VARIANT: u8> AssertInstruction<N, VARIANT> {
         let input_a = registers.load(stack, &self.operands[0])?;
         let input_b = registers.load(stack, &self.operands[1])?;
 
+        // Prepare the developer-supplied message (if any), to report on failure.
+        let message = self.message.as_ref().map(|message| format!(" - {message}")).unwrap_or_default();
+
         // Assert the inputs.
         match VARIANT {
             0 => {
                 if input_a != input_b {
-                    bail!("'{}' failed: '{input_a}' is not equal to '{input_b}' (should be equal)", Self::opcode())
+                    bail!(
+                        "'{}' failed: '{input_a}' is not equal to '{input_b}' (should be equal){message}",
+                        Self::opcode()
+                    )
                 }
             }
             1 => {
                 if input_a == input_b {
-                    bail!("'{}' failed: '{input_a}' is equal to '{input_b}' (should not be equal)", Self::opcode())
+                    bail!(
+                        "'{}' failed: '{input_a}' is equal to '{input_b}' (should not be equal){message}",
+                        Self::opcode()
+                    )
                 }
             }
             _ => bail!("Invalid 'assert' variant: {VARIANT}"),
@@ -190,8 +221,16 @@ impl<N: Network, const VARIANT: u8> Parser for AssertInstruction<N, VARIANT> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the second operand from the string.
         let (string, second) = Operand::parse(string)?;
-
-        Ok((string, Self { operands: vec![first, second] }))
+        // Parse the optional message from the string.
+        let (string, message) = opt(|string| {
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the message from the string.
+            // Note: The string parser is streaming, so `complete` lets an instruction end without a message.
+            complete(StringType::parse)(string)
+        })(string)?;
+
+        Ok((string, Self { operands: vec![first, second], message }))
     }
 }
 
@@ -229,22 +268,38 @@ impl<N: Network, const VARIANT: u8> Display for AssertInstruction<N, VARIANT> {
         }
         // Print the operation.
         write!(f, "{} ", Self::opcode())?;
-        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        // Print the message, if any.
+        match &self.message {
+            Some(message) => write!(f, "{message} "),
+            None => Ok(()),
+        }
     }
 }
 
 impl<N: Network, const VARIANT: u8> FromBytes for AssertInstruction<N, VARIANT> {
     /// Reads the operation from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the first byte, which is either the message marker or the variant of the first operand.
+        let first = u8::read_le(&mut reader)?;
+        let has_message = first == Self::MESSAGE_MARKER;
+
         // Initialize the vector for the operands.
         let mut operands = Vec::with_capacity(2);
         // Read the operands.
-        for _ in 0..2 {
-            operands.push(Operand::read_le(&mut reader)?);
+        match has_message {
+            true => operands.push(Operand::read_le(&mut reader)?),
+            false => operands.push(Operand::read_le((&[first][..]).chain(&mut reader))?),
         }
+        operands.push(Operand::read_le(&mut reader)?);
+        // Read the message, if any.
+        let message = match has_message {
+            true => Some(StringType::read_le(&mut reader)?),
+            false => None,
+        };
 
         // Return the operation.
-        Ok(Self { operands })
+        Ok(Self { operands, message })
     }
 }
 
@@ -255,8 +310,17 @@ impl<N: Network, const VARIANT: u8> ToBytes for AssertInstruction<N, VARIANT> {
         if self.operands.len() != 2 {
             return Err(error(format!("The number of operands must be 2, found {}", self.operands.len())));
         }
+        // An `assert` instruction without a message is written in the legacy format.
+        if self.message.is_some() {
+            Self::MESSAGE_MARKER.write_le(&mut writer)?;
+        }
         // Write the operands.
-        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the message, if any.
+        match &self.message {
+            Some(message) => message.write_le(&mut writer),
+            None => Ok(()),
+        }
     }
 }
 
@@ -280,5 +344,32 @@ mod tests {
         assert_eq!(assert.operands.len(), 2, "The number of operands is incorrect");
         assert_eq!(assert.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(assert.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(assert.message, None, "The message is incorrect");
+    }
+
+    #[test]
+    fn test_parse_with_message() {
+        let (string, assert) = AssertEq::<CurrentNetwork>::parse("assert.eq r0 r1 \"balance is too low\"").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(assert.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(assert.message().map(|message| &**message), Some("balance is too low"), "The message is incorrect");
+        assert_eq!(assert.to_string(), "assert.eq r0 r1 \"balance is too low\" ");
+
+        // Ensure the message survives a round trip through bytes.
+        let candidate = AssertEq::<CurrentNetwork>::from_bytes_le(&assert.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(assert, candidate);
+    }
+
+    #[test]
+    fn test_bytes_legacy() {
+        let (_, assert) = AssertEq::<CurrentNetwork>::parse("assert.eq r0 r1").unwrap();
+
+        // Ensure an `assert` instruction without a message is written as its operands.
+        let mut expected_bytes = assert.operands[0].to_bytes_le().unwrap();
+        expected_bytes.extend(assert.operands[1].to_bytes_le().unwrap());
+        assert_eq!(assert.to_bytes_le().unwrap(), expected_bytes);
+
+        let candidate = AssertEq::<CurrentNetwork>::from_bytes_le(&expected_bytes).unwrap();
+        assert_eq!(assert, candidate);
     }
 }
@@ -0,0 +1,352 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::encrypt::{check_ciphertext_type, is_fixed_size};
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Encrypt,
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+};
+
+/// Decrypts the output of an `encrypt` instruction with the view key of its recipient.
+///
+/// For `decrypt r0 r1 into r2 as memo`, `r0` is the output of `encrypt`, and `r1` is the view key as a `scalar`.
+/// The ciphertext is decrypted under the plaintext view key `(nonce * view_key).x`, as `Ciphertext::decrypt` does,
+/// and the plaintext must be of the given type.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Decrypt<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+    /// The plaintext type.
+    plaintext_type: PlaintextType<N>,
+}
+
+impl<N: Network> Decrypt<N> {
+    /// Initializes a new `decrypt` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>, plaintext_type: PlaintextType<N>) -> Result<Self> {
+        // Sanity check that the operands is exactly two inputs.
+        ensure!(operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination, plaintext_type })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Decrypt
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly two inputs.
+        debug_assert!(self.operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the plaintext type.
+    #[inline]
+    pub const fn plaintext_type(&self) -> &PlaintextType<N> {
+        &self.plaintext_type
+    }
+}
+
+impl<N: Network> Decrypt<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the nonce and the ciphertext.
+        let (nonce, ciphertext) = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(output) => Encrypt::to_ciphertext(&output)?,
+            Value::Record(..) => bail!("Instruction '{}' cannot be applied to a record", Self::opcode()),
+        };
+        // Retrieve the view key.
+        let view_key = match registers.load(stack, &self.operands[1])? {
+            Value::Plaintext(Plaintext::Literal(Literal::Scalar(view_key), ..)) => view_key,
+            _ => bail!("Instruction '{}' expects a view key as the second operand", Self::opcode()),
+        };
+
+        // Compute the plaintext view key as `(nonce * view_key).x`.
+        let plaintext_view_key = (nonce * view_key).to_x_coordinate();
+        // Decrypt the ciphertext.
+        let plaintext = ciphertext.decrypt_symmetric(plaintext_view_key)?;
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(plaintext))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the members of the output of `encrypt`.
+        let mut members = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Struct(members, _)) => members.into_values(),
+            _ => bail!("Instruction '{}' expects the output of 'encrypt' as the first operand", Self::opcode()),
+        };
+        // Retrieve the nonce.
+        let nonce = match members.next() {
+            Some(circuit::Plaintext::Literal(circuit::Literal::Group(nonce), _)) => nonce,
+            _ => bail!("The first member of the output of 'encrypt' must be the nonce"),
+        };
+        // Retrieve the ciphertext.
+        let ciphertext = members
+            .map(|member| match member {
+                circuit::Plaintext::Literal(circuit::Literal::Field(field), _) => Ok(field),
+                _ => bail!("The ciphertext of the output of 'encrypt' must consist of field elements"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Retrieve the view key.
+        let view_key = match registers.load_circuit(stack, &self.operands[1])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Scalar(view_key), ..)) => view_key,
+            _ => bail!("Instruction '{}' expects a view key as the second operand", Self::opcode()),
+        };
+
+        // Compute the plaintext view key as `(nonce * view_key).x`.
+        let plaintext_view_key = (nonce * view_key).to_x_coordinate();
+        // Decrypt the ciphertext.
+        let plaintext = circuit::Ciphertext::from(ciphertext).decrypt_symmetric(plaintext_view_key);
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(plaintext))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        bail!("Forbidden operation: Finalize cannot invoke '{}', as the view key would be public.", Self::opcode())
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 2 {
+            bail!("Instruction '{}' expects 2 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Ensure the first operand is a struct.
+        let ciphertext_type = match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Struct(ciphertext_type)) => ciphertext_type,
+            _ => bail!("Instruction '{}' expects a struct, found '{}'", Self::opcode(), input_types[0]),
+        };
+        // Ensure the second operand is a scalar.
+        ensure!(
+            input_types[1] == RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Scalar)),
+            "Instruction '{}' expects a view key as the second operand, found '{}'",
+            Self::opcode(),
+            input_types[1]
+        );
+
+        // Ensure the plaintext has a fixed size.
+        ensure!(
+            is_fixed_size(stack, &self.plaintext_type)?,
+            "Instruction '{}' cannot decrypt a string",
+            Self::opcode()
+        );
+        // Ensure the struct holds the nonce, followed by the ciphertext.
+        check_ciphertext_type(stack, ciphertext_type, &self.plaintext_type)?;
+
+        Ok(vec![RegisterType::Plaintext(self.plaintext_type.clone())])
+    }
+}
+
+impl<N: Network> Parser for Decrypt<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the plaintext type from the string.
+        let (string, plaintext_type) = PlaintextType::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second], destination, plaintext_type }))
+    }
+}
+
+impl<N: Network> FromStr for Decrypt<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Decrypt<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Decrypt<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {} as {}", self.destination, self.plaintext_type)
+    }
+}
+
+impl<N: Network> FromBytes for Decrypt<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(2);
+        // Read the operands.
+        for _ in 0..2 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Read the plaintext type.
+        let plaintext_type = PlaintextType::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination, plaintext_type })
+    }
+}
+
+impl<N: Network> ToBytes for Decrypt<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(error(format!("The number of operands must be 2, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)?;
+        // Write the plaintext type.
+        self.plaintext_type.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::Identifier};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, decrypt) = Decrypt::<CurrentNetwork>::parse("decrypt r0 r1 into r2 as memo").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(decrypt.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(decrypt.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(decrypt.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(decrypt.destination, Register::Locator(2), "The destination register is incorrect");
+        assert_eq!(decrypt.plaintext_type, PlaintextType::Struct(Identifier::from_str("memo").unwrap()));
+
+        // Ensure a literal plaintext type is supported.
+        let decrypt = Decrypt::<CurrentNetwork>::from_str("decrypt r0 r1 into r2 as u64").unwrap();
+        assert_eq!(decrypt.plaintext_type, PlaintextType::Literal(LiteralType::U64));
+
+        // Ensure the plaintext type is required.
+        assert!(Decrypt::<CurrentNetwork>::from_str("decrypt r0 r1 into r2").is_err());
+        // Ensure the number of operands is enforced.
+        assert!(Decrypt::<CurrentNetwork>::from_str("decrypt r0 r1 r2 into r3 as memo").is_err());
+    }
+
+    #[test]
+    fn test_bytes() {
+        for string in ["decrypt r0 r1 into r2 as memo", "decrypt r0 r1 into r2 as u64"] {
+            let expected = Decrypt::<CurrentNetwork>::from_str(string).unwrap();
+            let candidate = Decrypt::<CurrentNetwork>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+            assert_eq!(expected, candidate);
+            assert_eq!(expected.to_string(), candidate.to_string());
+        }
+    }
+}
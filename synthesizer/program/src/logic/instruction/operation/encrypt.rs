@@ -0,0 +1,462 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{
+        RegistersCaller,
+        RegistersCallerCircuit,
+        RegistersLoad,
+        RegistersLoadCircuit,
+        RegistersStore,
+        RegistersStoreCircuit,
+        StackMatches,
+        StackProgram,
+    },
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Ciphertext, Identifier, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{Field, Group},
+};
+
+use indexmap::IndexMap;
+
+/// Encrypts a plaintext to an address, as a record entry is encrypted to its owner.
+///
+/// For `encrypt r0 r1 into r2 as ciphertext`, `r0` is the plaintext and `r1` is the address.
+/// The randomizer is `HashToScalar(tvk || index)`, where `index` is the destination register,
+/// and the plaintext is encrypted with `Plaintext::encrypt`, under the plaintext view key `(address * randomizer).x`.
+///
+/// The destination is a struct, whose first member is the nonce `randomizer * G` as a `group`,
+/// followed by one `field` member for each field element of the ciphertext.
+/// The recipient recovers the plaintext with the `decrypt` instruction, or offline with
+/// `Ciphertext::decrypt(view_key, nonce)`, using the ciphertext and nonce from `Encrypt::to_ciphertext`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Encrypt<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+    /// The destination struct.
+    ciphertext_type: Identifier<N>,
+}
+
+impl<N: Network> Encrypt<N> {
+    /// Initializes a new `encrypt` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>, ciphertext_type: Identifier<N>) -> Result<Self> {
+        // Sanity check that the operands is exactly two inputs.
+        ensure!(operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination, ciphertext_type })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Encrypt
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly two inputs.
+        debug_assert!(self.operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the destination struct.
+    #[inline]
+    pub const fn ciphertext_type(&self) -> &Identifier<N> {
+        &self.ciphertext_type
+    }
+
+    /// Returns the nonce and the ciphertext from the given output of an `encrypt` instruction.
+    pub fn to_ciphertext(output: &Plaintext<N>) -> Result<(Group<N>, Ciphertext<N>)> {
+        // Retrieve the struct members.
+        let mut members = match output {
+            Plaintext::Struct(members, _) => members.values(),
+            Plaintext::Literal(..) => bail!("The output of '{}' must be a struct", Self::opcode()),
+        };
+        // Retrieve the nonce.
+        let nonce = match members.next() {
+            Some(Plaintext::Literal(Literal::Group(nonce), _)) => *nonce,
+            _ => bail!("The first member of the output of '{}' must be the nonce", Self::opcode()),
+        };
+        // Retrieve the ciphertext.
+        let ciphertext = members
+            .map(|member| match member {
+                Plaintext::Literal(Literal::Field(field), _) => Ok(*field),
+                _ => bail!("The ciphertext of the output of '{}' must consist of field elements", Self::opcode()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok((nonce, Ciphertext::from_fields(&ciphertext)?))
+    }
+}
+
+impl<N: Network> Encrypt<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersCaller<N> + RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the plaintext.
+        let plaintext = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(plaintext) => plaintext,
+            Value::Record(..) => bail!("Instruction '{}' cannot be applied to a record", Self::opcode()),
+        };
+        // Retrieve the address.
+        let address = match registers.load(stack, &self.operands[1])? {
+            Value::Plaintext(Plaintext::Literal(Literal::Address(address), ..)) => address,
+            _ => bail!("Instruction '{}' expects an address as the second operand", Self::opcode()),
+        };
+
+        // Prepare the index as a field element.
+        let index = Field::from_u64(self.destination.locator());
+        // Compute the randomizer as `HashToScalar(tvk || index)`.
+        let randomizer = N::hash_to_scalar_psd2(&[registers.tvk()?, index])?;
+        // Compute the nonce from the randomizer.
+        let nonce = N::g_scalar_multiply(&randomizer);
+        // Encrypt the plaintext to the address.
+        let ciphertext = plaintext.encrypt(&address, randomizer)?;
+
+        // Retrieve the member names of the destination struct.
+        let struct_ = stack.program().get_struct(&self.ciphertext_type)?;
+        ensure!(
+            struct_.members().len() == ciphertext.len() + 1,
+            "Struct '{}' cannot hold a nonce and {} ciphertext field elements",
+            self.ciphertext_type,
+            ciphertext.len()
+        );
+        // Construct the output, as the nonce followed by the ciphertext.
+        let literals =
+            std::iter::once(Literal::Group(nonce)).chain(ciphertext.iter().map(|field| Literal::Field(*field)));
+        let members = struct_
+            .members()
+            .keys()
+            .zip_eq(literals)
+            .map(|(name, literal)| (*name, Plaintext::from(literal)))
+            .collect::<IndexMap<_, _>>();
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::Struct(members, Default::default())))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersCallerCircuit<N, A> + RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::Inject;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the plaintext.
+        let plaintext = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(plaintext) => plaintext,
+            circuit::Value::Record(..) => bail!("Instruction '{}' cannot be applied to a record", Self::opcode()),
+        };
+        // Retrieve the address.
+        let address = match registers.load_circuit(stack, &self.operands[1])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Address(address), ..)) => address,
+            _ => bail!("Instruction '{}' expects an address as the second operand", Self::opcode()),
+        };
+
+        // Prepare the index as a constant field element.
+        let index = circuit::Field::constant(Field::from_u64(self.destination.locator()));
+        // Compute the randomizer as `HashToScalar(tvk || index)`.
+        let randomizer = A::hash_to_scalar_psd2(&[registers.tvk_circuit()?, index]);
+        // Compute the nonce from the randomizer.
+        let nonce = A::g_scalar_multiply(&randomizer);
+        // Encrypt the plaintext to the address.
+        let ciphertext = plaintext.encrypt(&address, randomizer);
+
+        // Retrieve the member names of the destination struct.
+        let struct_ = stack.program().get_struct(&self.ciphertext_type)?;
+        ensure!(
+            struct_.members().len() == ciphertext.len() + 1,
+            "Struct '{}' cannot hold a nonce and {} ciphertext field elements",
+            self.ciphertext_type,
+            ciphertext.len()
+        );
+        // Construct the output, as the nonce followed by the ciphertext.
+        let literals = std::iter::once(circuit::Literal::Group(nonce))
+            .chain(ciphertext.iter().map(|field| circuit::Literal::Field(field.clone())));
+        let members = struct_
+            .members()
+            .keys()
+            .zip_eq(literals)
+            .map(|(name, literal)| (circuit::Identifier::constant(*name), circuit::Plaintext::from(literal)))
+            .collect::<IndexMap<_, _>>();
+        // Store the output.
+        let output = circuit::Plaintext::Struct(members, Default::default());
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        bail!("Forbidden operation: Finalize cannot invoke '{}', as it has no transition view key.", Self::opcode())
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 2 {
+            bail!("Instruction '{}' expects 2 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Ensure the first operand is a plaintext.
+        let plaintext_type = match &input_types[0] {
+            RegisterType::Plaintext(plaintext_type) => plaintext_type,
+            _ => bail!("Instruction '{}' expects a plaintext, found '{}'", Self::opcode(), input_types[0]),
+        };
+        // Ensure the second operand is an address.
+        ensure!(
+            input_types[1] == RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
+            "Instruction '{}' expects an address as the second operand, found '{}'",
+            Self::opcode(),
+            input_types[1]
+        );
+
+        // Ensure the plaintext has a fixed size.
+        ensure!(is_fixed_size(stack, plaintext_type)?, "Instruction '{}' cannot encrypt a string", Self::opcode());
+        // Ensure the destination struct holds the nonce, followed by the ciphertext.
+        check_ciphertext_type(stack, &self.ciphertext_type, plaintext_type)?;
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Struct(self.ciphertext_type))])
+    }
+}
+
+/// Returns `true` if a plaintext of the given type has a fixed size.
+/// Note: A string literal is variable-length, so its ciphertext cannot be given a fixed type.
+pub(super) fn is_fixed_size<N: Network>(
+    stack: &impl StackProgram<N>,
+    plaintext_type: &PlaintextType<N>,
+) -> Result<bool> {
+    match plaintext_type {
+        PlaintextType::Literal(literal_type) => Ok(*literal_type != LiteralType::String),
+        PlaintextType::Struct(struct_name) => {
+            for member_type in stack.program().get_struct(struct_name)?.members().values() {
+                if !is_fixed_size(stack, member_type)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+    }
+}
+
+/// Ensures the given struct holds the nonce, followed by the ciphertext of a plaintext of the given type.
+pub(super) fn check_ciphertext_type<N: Network>(
+    stack: &impl StackProgram<N>,
+    ciphertext_type: &Identifier<N>,
+    plaintext_type: &PlaintextType<N>,
+) -> Result<()> {
+    // Retrieve the number of field elements in the ciphertext.
+    let num_fields = stack.get_max_size_in_fields(plaintext_type)?;
+
+    // Ensure the struct holds the nonce, followed by the ciphertext.
+    let struct_ = stack.program().get_struct(ciphertext_type)?;
+    ensure!(
+        struct_.members().len() == num_fields + 1,
+        "Struct '{ciphertext_type}' must have {} members, to hold a nonce and the ciphertext of '{plaintext_type}'",
+        num_fields + 1
+    );
+    for (index, member_type) in struct_.members().values().enumerate() {
+        let expected = match index {
+            0 => LiteralType::Group,
+            _ => LiteralType::Field,
+        };
+        ensure!(
+            *member_type == PlaintextType::Literal(expected),
+            "Member {index} of struct '{ciphertext_type}' must be a '{expected}', found '{member_type}'"
+        );
+    }
+    Ok(())
+}
+
+impl<N: Network> Parser for Encrypt<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination struct from the string.
+        let (string, ciphertext_type) = Identifier::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second], destination, ciphertext_type }))
+    }
+}
+
+impl<N: Network> FromStr for Encrypt<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Encrypt<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Encrypt<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {} as {}", self.destination, self.ciphertext_type)
+    }
+}
+
+impl<N: Network> FromBytes for Encrypt<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(2);
+        // Read the operands.
+        for _ in 0..2 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Read the destination struct.
+        let ciphertext_type = Identifier::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination, ciphertext_type })
+    }
+}
+
+impl<N: Network> ToBytes for Encrypt<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(error(format!("The number of operands must be 2, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)?;
+        // Write the destination struct.
+        self.ciphertext_type.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, encrypt) = Encrypt::<CurrentNetwork>::parse("encrypt r0 r1 into r2 as memo_ciphertext").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(encrypt.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(encrypt.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(encrypt.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(encrypt.destination, Register::Locator(2), "The destination register is incorrect");
+        assert_eq!(encrypt.ciphertext_type, Identifier::from_str("memo_ciphertext").unwrap());
+
+        // Ensure the destination struct is required.
+        assert!(Encrypt::<CurrentNetwork>::from_str("encrypt r0 r1 into r2").is_err());
+        // Ensure the number of operands is enforced.
+        assert!(Encrypt::<CurrentNetwork>::from_str("encrypt r0 r1 r2 into r3 as memo_ciphertext").is_err());
+    }
+
+    #[test]
+    fn test_bytes() {
+        let expected = Encrypt::<CurrentNetwork>::from_str("encrypt r0 r1 into r2 as memo_ciphertext").unwrap();
+        let candidate = Encrypt::<CurrentNetwork>::from_bytes_le(&expected.to_bytes_le().unwrap()).unwrap();
+        assert_eq!(expected, candidate);
+        assert_eq!(expected.to_string(), candidate.to_string());
+    }
+}
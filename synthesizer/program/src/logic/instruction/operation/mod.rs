@@ -24,6 +24,12 @@ pub use cast::*;
 mod commit;
 pub use commit::*;
 
+mod decrypt;
+pub use decrypt::*;
+
+mod encrypt;
+pub use encrypt::*;
+
 mod finalize;
 pub use finalize::*;
 
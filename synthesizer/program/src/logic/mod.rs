@@ -15,6 +15,12 @@
 pub mod command;
 pub use command::*;
 
+mod event;
+pub use event::*;
+
+mod finalize_diff;
+pub use finalize_diff::*;
+
 mod finalize_global_state;
 pub use finalize_global_state::*;
 
@@ -23,3 +29,6 @@ pub use finalize_operation::*;
 
 pub mod instruction;
 pub use instruction::*;
+
+mod rejection_reason;
+pub use rejection_reason::*;
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for RejectionReason<N> {
+    /// Reads the rejection reason from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        // Read the finalize name.
+        let name = Identifier::read_le(&mut reader)?;
+        // Read the command index.
+        let command_index = u16::read_le(&mut reader)?;
+        // Read the opcode.
+        let opcode = StringType::<N>::read_le(&mut reader)?;
+        // Read the message, if any.
+        let message = match u8::read_le(&mut reader)? {
+            0u8 => None,
+            1u8 => Some(StringType::<N>::read_le(&mut reader)?),
+            _ => return Err(error("Invalid message variant")),
+        };
+        // Return the rejection reason.
+        Self::new(program_id, name, command_index, &opcode, message.as_deref()).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for RejectionReason<N> {
+    /// Writes the rejection reason to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the finalize name.
+        self.name.write_le(&mut writer)?;
+        // Write the command index.
+        self.command_index.write_le(&mut writer)?;
+        // Write the opcode.
+        StringType::<N>::new(&self.opcode).write_le(&mut writer)?;
+        // Write the message, if any.
+        match &self.message {
+            None => 0u8.write_le(&mut writer),
+            Some(message) => {
+                1u8.write_le(&mut writer)?;
+                StringType::<N>::new(message).write_le(&mut writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        for expected in crate::logic::rejection_reason::test_helpers::sample_rejection_reasons() {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, RejectionReason::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
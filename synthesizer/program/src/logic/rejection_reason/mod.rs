@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+    types::StringType,
+};
+
+/// The reason a `finalize` scope was rejected, as the failed command and its developer-supplied message (if any).
+#[derive(Clone, PartialEq, Eq)]
+pub struct RejectionReason<N: Network> {
+    /// The program ID of the rejected finalize scope.
+    program_id: ProgramID<N>,
+    /// The name of the rejected finalize scope.
+    name: Identifier<N>,
+    /// The index of the failed command in the finalize scope.
+    command_index: u16,
+    /// The opcode of the failed command.
+    opcode: String,
+    /// The developer-supplied message of the failed command, if any.
+    message: Option<String>,
+}
+
+impl<N: Network> RejectionReason<N> {
+    /// Initializes a new rejection reason.
+    pub fn new(
+        program_id: ProgramID<N>,
+        name: Identifier<N>,
+        command_index: u16,
+        opcode: &str,
+        message: Option<&str>,
+    ) -> Result<Self> {
+        // Ensure the opcode and message are within bounds.
+        ensure!(opcode.len() <= N::MAX_STRING_BYTES as usize, "The rejected opcode exceeds the maximum length");
+        if let Some(message) = message {
+            ensure!(message.len() <= N::MAX_STRING_BYTES as usize, "The rejection message exceeds the maximum length");
+        }
+        // Return the rejection reason.
+        Ok(Self { program_id, name, command_index, opcode: opcode.to_string(), message: message.map(String::from) })
+    }
+
+    /// Returns the program ID of the rejected finalize scope.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the name of the rejected finalize scope.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the index of the failed command in the finalize scope.
+    pub const fn command_index(&self) -> u16 {
+        self.command_index
+    }
+
+    /// Returns the opcode of the failed command.
+    pub fn opcode(&self) -> &str {
+        &self.opcode
+    }
+
+    /// Returns the developer-supplied message of the failed command, if any.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a list of rejection reasons.
+    pub(crate) fn sample_rejection_reasons() -> Vec<RejectionReason<CurrentNetwork>> {
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        vec![
+            RejectionReason::new(program_id, Identifier::from_str("mint").unwrap(), 2, "sub", None).unwrap(),
+            RejectionReason::new(
+                program_id,
+                Identifier::from_str("transfer").unwrap(),
+                0,
+                "assert.eq",
+                Some("balance is too low"),
+            )
+            .unwrap(),
+        ]
+    }
+}
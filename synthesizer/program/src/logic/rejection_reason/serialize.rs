@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for RejectionReason<N> {
+    /// Serializes the rejection reason to a JSON-string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut reason = serializer.serialize_struct("RejectionReason", 4 + self.message.is_some() as usize)?;
+                reason.serialize_field("program_id", &self.program_id)?;
+                reason.serialize_field("name", &self.name)?;
+                reason.serialize_field("command_index", &self.command_index)?;
+                reason.serialize_field("opcode", &self.opcode)?;
+                if let Some(message) = &self.message {
+                    reason.serialize_field("message", message)?;
+                }
+                reason.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for RejectionReason<N> {
+    /// Deserializes the rejection reason from a JSON-string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut reason = serde_json::Value::deserialize(deserializer)?;
+                // Retrieve the opcode.
+                let opcode: String = DeserializeExt::take_from_value::<D>(&mut reason, "opcode")?;
+                // Retrieve the message, if it exists.
+                let message: Option<String> =
+                    serde_json::from_value(reason.get_mut("message").unwrap_or(&mut serde_json::Value::Null).take())
+                        .map_err(de::Error::custom)?;
+                // Recover the rejection reason.
+                Self::new(
+                    // Retrieve the program ID.
+                    DeserializeExt::take_from_value::<D>(&mut reason, "program_id")?,
+                    // Retrieve the finalize name.
+                    DeserializeExt::take_from_value::<D>(&mut reason, "name")?,
+                    // Retrieve the command index.
+                    DeserializeExt::take_from_value::<D>(&mut reason, "command_index")?,
+                    &opcode,
+                    message.as_deref(),
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "rejection reason"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        for expected in crate::logic::rejection_reason::test_helpers::sample_rejection_reasons() {
+            // Serialize
+            let expected_string = expected.to_string();
+            let candidate_string = serde_json::to_string(&expected).unwrap();
+            assert_eq!(expected_string, candidate_string);
+
+            // Deserialize
+            assert_eq!(expected, RejectionReason::from_str(&expected_string).unwrap());
+            assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_bincode() {
+        for expected in crate::logic::rejection_reason::test_helpers::sample_rejection_reasons() {
+            // Serialize
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected).unwrap();
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected, RejectionReason::read_le(&expected_bytes[..]).unwrap());
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
+        }
+    }
+}
@@ -14,31 +14,75 @@
 
 use super::*;
 
-impl<N: Network> FromBytes for Mapping<N> {
-    /// Reads the mapping from a buffer.
-    #[inline]
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+use crate::bytes::{PROGRAM_VERSION_1, PROGRAM_VERSION_LEGACY};
+
+impl<N: Network> Mapping<N> {
+    /// Returns the lowest program version that can encode the mapping.
+    /// Note: The size limit was introduced in version 1, so only a mapping that declares one needs it.
+    pub(crate) fn version(&self) -> u8 {
+        match self.limit.is_some() {
+            true => PROGRAM_VERSION_1,
+            false => PROGRAM_VERSION_LEGACY,
+        }
+    }
+
+    /// Reads the mapping from a buffer, using the mapping encoding of the given program version.
+    pub(crate) fn read_le_versioned<R: Read>(mut reader: R, version: u8) -> IoResult<Self> {
         // Read the mapping name.
         let name = Identifier::<N>::read_le(&mut reader)?;
         // Read the key statement.
         let key = FromBytes::read_le(&mut reader)?;
         // Read the value statement.
         let value = FromBytes::read_le(&mut reader)?;
+        // Read the size limit, which is encoded from version 1.
+        let limit = match version {
+            PROGRAM_VERSION_LEGACY => None,
+            _ => match u8::read_le(&mut reader)? {
+                0 => None,
+                1 => Some(u16::read_le(&mut reader)?),
+                variant => return Err(error(format!("Invalid mapping limit variant ({variant})"))),
+            },
+        };
         // Return the new mapping.
-        Ok(Self::new(name, key, value))
+        Ok(Self::new(name, key, value, limit))
     }
-}
 
-impl<N: Network> ToBytes for Mapping<N> {
-    /// Writes the mapping to a buffer.
-    #[inline]
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+    /// Writes the mapping to a buffer, using the mapping encoding of the given program version.
+    pub(crate) fn write_le_versioned<W: Write>(&self, mut writer: W, version: u8) -> IoResult<()> {
         // Write the mapping name.
         self.name.write_le(&mut writer)?;
         // Write the key statement.
         self.key.write_le(&mut writer)?;
         // Write the value statement.
-        self.value.write_le(&mut writer)
+        self.value.write_le(&mut writer)?;
+        // Write the size limit, which is encoded from version 1.
+        match (version, self.limit) {
+            (PROGRAM_VERSION_LEGACY, None) => Ok(()),
+            (PROGRAM_VERSION_LEGACY, Some(_)) => {
+                Err(error(format!("Program version {version} cannot declare the size limit of '{}'", self.name)))
+            }
+            (_, None) => 0u8.write_le(&mut writer),
+            (_, Some(limit)) => {
+                1u8.write_le(&mut writer)?;
+                limit.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<N: Network> FromBytes for Mapping<N> {
+    /// Reads the mapping from a buffer.
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        Self::read_le_versioned(reader, PROGRAM_VERSION_1)
+    }
+}
+
+impl<N: Network> ToBytes for Mapping<N> {
+    /// Writes the mapping to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.write_le_versioned(writer, PROGRAM_VERSION_1)
     }
 }
 
@@ -63,6 +107,38 @@ mapping main:
         let candidate = Mapping::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
         assert_eq!(expected.to_string(), candidate.to_string());
         assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+
+        // A legacy program writes the name, key, and value, without a size limit.
+        let mut legacy_bytes = expected.name().to_bytes_le()?;
+        legacy_bytes.extend(expected.key().to_bytes_le()?);
+        legacy_bytes.extend(expected.value().to_bytes_le()?);
+        let mut candidate_bytes = Vec::new();
+        expected.write_le_versioned(&mut candidate_bytes, PROGRAM_VERSION_LEGACY)?;
+        assert_eq!(legacy_bytes, candidate_bytes);
+        assert_eq!(expected, Mapping::read_le_versioned(&legacy_bytes[..], PROGRAM_VERSION_LEGACY)?);
+        // Version 1 also writes whether a size limit is declared.
+        assert_eq!([legacy_bytes.as_slice(), &[0u8]].concat(), expected_bytes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_mapping_bytes_with_limit() -> Result<()> {
+        let mapping_string = r"
+mapping main:
+    key a as field.public;
+    value b as field.public;
+    limit 16;";
+
+        let expected = Mapping::<CurrentNetwork>::from_str(mapping_string)?;
+        let expected_bytes = expected.to_bytes_le()?;
+
+        let candidate = Mapping::<CurrentNetwork>::from_bytes_le(&expected_bytes)?;
+        assert_eq!(Some(16), candidate.limit());
+        assert_eq!(expected.to_string(), candidate.to_string());
+        assert_eq!(expected_bytes, candidate.to_bytes_le()?);
+
+        // Ensure a legacy program cannot declare the size limit of a mapping.
+        assert!(expected.write_le_versioned(Vec::new(), PROGRAM_VERSION_LEGACY).is_err());
         Ok(())
     }
 }
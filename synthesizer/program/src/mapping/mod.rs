@@ -31,12 +31,14 @@ pub struct Mapping<N: Network> {
     key: MapKey<N>,
     /// The value statement.
     value: MapValue<N>,
+    /// The declared maximum size of a key or value in the mapping, in field elements.
+    limit: Option<u16>,
 }
 
 impl<N: Network> Mapping<N> {
-    /// Initializes a new mapping with the given name, key statement, and value statement.
-    pub fn new(name: Identifier<N>, key: MapKey<N>, value: MapValue<N>) -> Self {
-        Self { name, key, value }
+    /// Initializes a new mapping with the given name, key statement, value statement, and optional size limit.
+    pub fn new(name: Identifier<N>, key: MapKey<N>, value: MapValue<N>, limit: Option<u16>) -> Self {
+        Self { name, key, value, limit }
     }
 
     /// Returns the name of the mapping.
@@ -53,6 +55,11 @@ impl<N: Network> Mapping<N> {
     pub const fn value(&self) -> &MapValue<N> {
         &self.value
     }
+
+    /// Returns the declared size limit of the mapping, in field elements, if one exists.
+    pub const fn limit(&self) -> Option<u16> {
+        self.limit
+    }
 }
 
 impl<N: Network> TypeName for Mapping<N> {
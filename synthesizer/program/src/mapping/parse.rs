@@ -35,9 +35,26 @@ impl<N: Network> Parser for Mapping<N> {
         let (string, key) = MapKey::parse(string)?;
         // Parse the value statement from the string.
         let (string, value) = MapValue::parse(string)?;
+        // Parse the optional size limit statement from the string.
+        let (string, limit) = opt(|string| {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the 'limit' keyword from the string.
+            let (string, _) = tag("limit")(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the size limit from the string.
+            let (string, limit) =
+                map_res(recognize(many1(one_of("0123456789"))), |limit: &str| limit.parse::<u16>())(string)?;
+            // Parse the whitespace from the string.
+            let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Parse the semicolon ';' keyword from the string.
+            let (string, _) = tag(";")(string)?;
+            Ok((string, limit))
+        })(string)?;
 
         // Return the mapping.
-        Ok((string, Self::new(name, key, value)))
+        Ok((string, Self::new(name, key, value, limit)))
     }
 }
 
@@ -71,7 +88,12 @@ impl<N: Network> Display for Mapping<N> {
         // Write the mapping to a string.
         write!(f, "{} {}:", Self::type_name(), self.name)?;
         write!(f, "\n    {}", self.key)?;
-        write!(f, "\n    {}", self.value)
+        write!(f, "\n    {}", self.value)?;
+        // Write the size limit, if one is declared.
+        match self.limit {
+            Some(limit) => write!(f, "\n    limit {limit};"),
+            None => Ok(()),
+        }
     }
 }
 
@@ -97,6 +119,34 @@ mapping foo:
         assert_eq!("field", mapping.key.plaintext_type().to_string());
         assert_eq!("b", mapping.value.name().to_string());
         assert_eq!("field", mapping.value.plaintext_type().to_string());
+        assert_eq!(None, mapping.limit());
+    }
+
+    #[test]
+    fn test_mapping_parse_with_limit() {
+        let mapping = Mapping::<CurrentNetwork>::parse(
+            r"
+mapping foo:
+    key a as field.public;
+    value b as field.public;
+    limit 16;",
+        )
+        .unwrap()
+        .1;
+        assert_eq!("foo", mapping.name().to_string());
+        assert_eq!(Some(16), mapping.limit());
+
+        // Ensure the limit must fit in a u16.
+        assert!(
+            Mapping::<CurrentNetwork>::from_str(
+                r"
+mapping foo:
+    key a as field.public;
+    value b as field.public;
+    limit 65536;"
+            )
+            .is_err()
+        );
     }
 
     #[test]
@@ -106,5 +156,12 @@ mapping foo:
     value b as field.public;";
         let mapping = Mapping::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(expected, format!("{mapping}"),);
+
+        let expected = r"mapping foo:
+    key a as field.public;
+    value b as field.public;
+    limit 16;";
+        let mapping = Mapping::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(expected, format!("{mapping}"),);
     }
 }
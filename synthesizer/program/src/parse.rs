@@ -52,6 +52,9 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
             map(ClosureCore::parse, |closure| P::<N, Instruction, Command>::C(closure)),
             map(FunctionCore::parse, |function| P::<N, Instruction, Command>::F(function)),
         )))(string)?;
+        // Parse the constructor (if any) from the string.
+        // Note: The constructor is declared after all other components of the program.
+        let (string, constructor) = opt(ConstructorCore::parse)(string)?;
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
 
@@ -83,6 +86,13 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
                     }
                 }
             }
+            // Add the constructor (if any) to the program.
+            if let Some(constructor) = &constructor {
+                if let Err(error) = program.add_constructor(constructor.clone()) {
+                    eprintln!("{error}");
+                    return Err(error);
+                }
+            }
             // Lastly, add the imports (if any) to the program.
             for import in imports.iter() {
                 match program.add_import(import.clone()) {
@@ -173,6 +183,10 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Dis
                 },
             }
         }
+        // Print the constructor (if any).
+        if let Some(constructor) = &self.constructor {
+            program.push_str(&format!("{constructor}\n\n"));
+        }
         // Remove the last newline.
         program.pop();
 
@@ -235,6 +249,45 @@ function compute:
         Ok(())
     }
 
+    #[test]
+    fn test_program_parse_constructor() -> Result<()> {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program to_parse.aleo;
+
+mapping supply:
+    key id as u8.public;
+    value amount as u64.public;
+
+function compute:
+    add 1u32 2u32 into r0;
+    output r0 as u32.private;
+
+constructor:
+    set 1000u64 into supply[0u8];",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Ensure the program contains the constructor.
+        assert_eq!(1, program.constructor().unwrap().commands().len());
+
+        // Ensure the constructor must be declared last.
+        let candidate = r"
+program to_parse.aleo;
+
+constructor:
+    set 1000u64 into supply[0u8];
+
+function compute:
+    add 1u32 2u32 into r0;
+    output r0 as u32.private;";
+        assert!(Program::<CurrentNetwork>::from_str(candidate).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_display() -> Result<()> {
         let expected = r"program to_parse.aleo;
@@ -253,6 +306,24 @@ function compute:
         // Ensure the program string matches.
         assert_eq!(expected, format!("{program}"));
 
+        let expected = r"program to_parse.aleo;
+
+mapping supply:
+    key id as u8.public;
+    value amount as u64.public;
+
+function compute:
+    add 1u32 2u32 into r0;
+    output r0 as u32.private;
+
+constructor:
+    set 1000u64 into supply[0u8];
+";
+        // Parse a new program with a constructor.
+        let program = Program::<CurrentNetwork>::from_str(expected)?;
+        // Ensure the program string matches.
+        assert_eq!(expected, format!("{program}"));
+
         Ok(())
     }
 }
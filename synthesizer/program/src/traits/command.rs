@@ -24,6 +24,8 @@ pub trait CommandTrait<N: Network>: Clone + Parser + FromBytes + ToBytes {
     fn destinations(&self) -> Vec<Register<N>>;
     /// Returns the branch target, if the command is a branch command.
     fn branch_to(&self) -> Option<&Identifier<N>>;
+    /// Returns the maximum number of iterations, if the command is a branch command that declares one.
+    fn max_iterations(&self) -> Option<u16>;
     /// Returns the position name, if the command is a position command.
     fn position(&self) -> Option<&Identifier<N>>;
     /// Returns `true` if the command is a call instruction.
@@ -32,6 +34,13 @@ pub trait CommandTrait<N: Network>: Clone + Parser + FromBytes + ToBytes {
     fn is_cast_to_record(&self) -> bool;
     /// Returns `true` if the command is a write operation.
     fn is_write(&self) -> bool;
+
+    /// Returns the lowest program version that can encode the command.
+    fn version(&self) -> u8;
+    /// Reads the command from a buffer, using the encodings of the given program version.
+    fn read_le_versioned<R: Read>(reader: R, version: u8) -> IoResult<Self>;
+    /// Writes the command to a buffer, using the encodings of the given program version.
+    fn write_le_versioned<W: Write>(&self, writer: W, version: u8) -> IoResult<()>;
 }
 
 pub trait FinalizeCommandTrait: Clone + PartialEq + Eq + Parser + FromBytes + ToBytes {
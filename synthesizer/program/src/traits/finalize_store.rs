@@ -62,6 +62,19 @@ pub trait FinalizeStoreTrait<N: Network> {
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>>;
 
+    /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage,
+    /// and returns a finalize operation that commits to the sizes of the entries in bytes.
+    /// If the `mapping name` is not initialized, an error is returned.
+    /// If the `key` does not exist, the `(key, value)` pair is initialized, and an `InsertSizedKeyValue` is returned.
+    /// If the `key` already exists, the `value` is overwritten, and an `UpdateSizedKeyValue` is returned.
+    fn update_sized_key_value(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: Plaintext<N>,
+        value: Value<N>,
+    ) -> Result<FinalizeOperation<N>>;
+
     /// Removes the key-value pair for the given `program ID`, `mapping name`, and `key` from storage.
     fn remove_key_value(
         &self,
@@ -69,4 +82,13 @@ pub trait FinalizeStoreTrait<N: Network> {
         mapping_name: &Identifier<N>,
         key: &Plaintext<N>,
     ) -> Result<FinalizeOperation<N>>;
+
+    /// Removes the key-value pair for the given `program ID`, `mapping name`, and `key` from storage,
+    /// and returns a `RemoveSizedKeyValue` that commits to the size of the removed entry in bytes.
+    fn remove_sized_key_value(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<FinalizeOperation<N>>;
 }
@@ -74,6 +74,9 @@ pub trait StackProgram<N: Network> {
 
     /// Returns the expected number of calls for the given function name.
     fn get_number_of_calls(&self, function_name: &Identifier<N>) -> Result<usize>;
+
+    /// Returns the maximum number of field elements needed to encode a plaintext of the given type.
+    fn get_max_size_in_fields(&self, plaintext_type: &PlaintextType<N>) -> Result<usize>;
 }
 
 pub trait FinalizeRegistersState<N: Network> {
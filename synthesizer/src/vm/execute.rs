@@ -37,9 +37,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             Some((credits, priority_fee_in_microcredits)) => {
                 // Compute the minimum execution cost.
                 let (minimum_execution_cost, (_, _)) = execution_cost(self, &execution)?;
+                // Compute the maximum cost of the mapping entries written in finalize.
+                let storage_cost = max_execution_storage_cost(self, &execution)?;
                 // Determine the fee.
                 let fee_in_microcredits = minimum_execution_cost
-                    .checked_add(priority_fee_in_microcredits)
+                    .checked_add(storage_cost)
+                    .and_then(|fee| fee.checked_add(priority_fee_in_microcredits))
                     .ok_or_else(|| anyhow!("Fee overflowed for an execution transaction"))?;
                 // Compute the execution ID.
                 let execution_id = execution.to_execution_id()?;
@@ -52,6 +55,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     }
 
     /// Returns a new execute transaction for the given authorization.
+    ///
+    /// The authorization may come from an untrusted source, such as a wallet that delegates proving,
+    /// so it is verified before it is executed.
     pub fn execute_authorization<R: Rng + CryptoRng>(
         &self,
         authorization: Authorization<N>,
@@ -59,6 +65,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
     ) -> Result<Transaction<N>> {
+        // Verify the authorization.
+        self.process.read().verify_authorization(&authorization)?;
         // Compute the execution.
         let (_response, execution) = self.execute_authorization_raw(authorization, query, rng)?;
         // Return the execute transaction.
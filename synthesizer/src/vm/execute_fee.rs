@@ -111,6 +111,139 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Process the logic.
         process!(self, logic)
     }
+
+    /// Executes a public fee for the given private key and fee amount (in microcredits).
+    /// The fee is charged from the caller's balance in the `credits.aleo/account` mapping.
+    /// Returns the fee transaction.
+    #[inline]
+    pub fn execute_fee_public<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        fee_in_microcredits: u64,
+        deployment_or_execution_id: Field<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Compute the fee.
+        let fee =
+            self.execute_fee_public_raw(private_key, fee_in_microcredits, deployment_or_execution_id, query, rng)?.1;
+        // Return the fee transaction.
+        Transaction::from_fee(fee)
+    }
+
+    /// Executes a public fee for the given private key and fee amount (in microcredits).
+    /// Returns the response and fee.
+    #[inline]
+    pub fn execute_fee_public_raw<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        fee_in_microcredits: u64,
+        deployment_or_execution_id: Field<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Fee<N>)> {
+        // Retrieve the caller.
+        let caller = Address::try_from(private_key)?;
+        // Ensure that the caller has enough public balance to pay the fee.
+        // Note: The balance is checked again when the fee is finalized.
+        let balance = match self.finalize_store().get_value_confirmed(
+            &ProgramID::from_str("credits.aleo")?,
+            &Identifier::from_str("account")?,
+            &Plaintext::from(Literal::Address(caller)),
+        )? {
+            Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => *balance,
+            Some(..) => bail!("Invalid public balance for '{caller}'"),
+            None => 0,
+        };
+        if balance < fee_in_microcredits {
+            bail!("Public balance does not have enough microcredits to pay the fee")
+        }
+
+        // Prepare the inputs.
+        let inputs = [
+            Value::from_str(&format!("{fee_in_microcredits}u64"))?,
+            Value::from(Literal::Field(deployment_or_execution_id)),
+        ];
+        // Authorize the call to 'credits.aleo/fee_public'.
+        let authorization = self.authorize(private_key, "credits.aleo", "fee_public", inputs, rng)?;
+
+        // Compute the response and fee.
+        self.execute_fee_authorization_raw(authorization, query, rng)
+    }
+
+    /// Executes a fee for the given fee authorization.
+    /// Returns the fee transaction.
+    ///
+    /// The authorization may come from an untrusted source, such as a wallet that delegates proving,
+    /// so it is verified before it is executed.
+    #[inline]
+    pub fn execute_fee_authorization<R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Verify the authorization.
+        self.process.read().verify_authorization(&authorization)?;
+        // Compute the fee.
+        let fee = self.execute_fee_authorization_raw(authorization, query, rng)?.1;
+        // Return the fee transaction.
+        Transaction::from_fee(fee)
+    }
+
+    /// Executes a fee for the given fee authorization.
+    /// Returns the response and fee.
+    #[inline]
+    pub fn execute_fee_authorization_raw<R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Fee<N>)> {
+        let timer = timer!("VM::execute_fee_authorization_raw");
+
+        // Prepare the query.
+        let query = match query {
+            Some(query) => query,
+            None => Query::VM(self.block_store().clone()),
+        };
+        lap!(timer, "Prepare the query");
+
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the authorization.
+                let authorization = cast_ref!(authorization as Authorization<$network>);
+                lap!(timer, "Prepare the authorization");
+
+                // Execute the call to fee.
+                let (response, _fee_transition, mut trace) =
+                    $process.execute_fee_authorization::<$aleo>(authorization.clone())?;
+                lap!(timer, "Execute the call to fee");
+
+                // Prepare the assignments.
+                cast_mut_ref!(trace as Trace<N>).prepare(query)?;
+                lap!(timer, "Prepare the assignments");
+
+                // Compute the proof and construct the fee.
+                let trace = cast_ref!(trace as Trace<$network>);
+                let fee = trace.prove_fee::<$aleo, _>(rng)?;
+                lap!(timer, "Compute the proof and construct the fee");
+
+                // Prepare the return.
+                let response = cast_ref!(response as Response<N>).clone();
+                let fee = cast_ref!(fee as Fee<N>).clone();
+                lap!(timer, "Prepare the response and fee");
+
+                finish!(timer);
+
+                // Return the response and fee.
+                Ok((response, fee))
+            }};
+        }
+        // Process the logic.
+        process!(self, logic)
+    }
 }
 
 #[cfg(test)]
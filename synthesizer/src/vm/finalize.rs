@@ -14,9 +14,16 @@
 
 use super::*;
 use ledger_block::{ConfirmedTransaction, Rejected, Transactions};
+use ledger_store::{atomic_batch_scope, FinalizeStorage};
+use synthesizer_process::Stack;
+use synthesizer_program::{Event, FinalizeOperation, RejectionReason};
+
+#[cfg(feature = "history")]
+use indexmap::IndexMap;
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Speculates on the given list of transactions in the VM, returning the confirmed transactions.
+    /// Note: A transaction whose fee cannot be paid is dropped from the returned transactions.
     #[inline]
     pub fn speculate<'a>(
         &self,
@@ -73,12 +80,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             // Initialize a list of the confirmed transactions.
             let mut confirmed = Vec::with_capacity(num_transactions);
+            // Initialize a counter for the transactions dropped because their fee cannot be paid.
+            let mut num_dropped = 0;
 
             // Finalize the transactions.
-            for (index, transaction) in transactions.enumerate() {
-                // Convert the transaction index to a u32.
+            for transaction in transactions {
+                // Convert the index of the next confirmed transaction to a u32.
                 // Note: On failure, this will abort the entire atomic batch.
-                let index = u32::try_from(index).map_err(|_| "Failed to convert transaction index".to_string())?;
+                let index = u32::try_from(confirmed.len()).map_err(|_| "Failed to convert transaction index".to_string())?;
 
                 // Process the transaction in an isolated atomic batch.
                 // - If the transaction succeeds, the finalize operations are stored.
@@ -86,35 +95,48 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 let outcome = match transaction {
                     // The finalize operation here involves appending the 'stack',
                     // and adding the program to the finalize tree.
-                    Transaction::Deploy(_, program_owner, deployment, fee) => match process.finalize_deployment(store, deployment) {
+                    Transaction::Deploy(_, program_owner, deployment, fee) => match finalize_deployment_and_fee(&process, state, store, deployment, fee) {
                         // Construct the accepted deploy transaction.
-                        Ok((_, finalize)) => ConfirmedTransaction::accepted_deploy(index, transaction.clone(), finalize).map_err(|e| e.to_string()),
+                        Ok((_, finalize)) => ConfirmedTransaction::accepted_deploy(index, transaction.clone(), finalize).map(Some).map_err(|e| e.to_string()),
                         // Construct the rejected deploy transaction.
-                        Err(_error) => {
-                            // Construct the fee transaction.
-                            // Note: On failure, this will abort the entire atomic batch.
-                            let fee_tx = Transaction::from_fee(fee.clone()).map_err(|e| e.to_string())?;
-                            // Construct the rejected deployment.
-                            let rejected = Rejected::new_deployment(*program_owner, *deployment.clone());
+                        Err(_error) => match process.finalize_fee(state, store, fee) {
                             // Construct the rejected deploy transaction.
-                            ConfirmedTransaction::rejected_deploy(index, fee_tx, rejected).map_err(|e| e.to_string())
-                        }
+                            Ok(_) => {
+                                // Construct the fee transaction.
+                                // Note: On failure, this will abort the entire atomic batch.
+                                let fee_tx = Transaction::from_fee(fee.clone()).map_err(|e| e.to_string())?;
+                                // Construct the rejected deployment.
+                                let rejected = Rejected::new_deployment(*program_owner, *deployment.clone());
+                                // Construct the rejected deploy transaction.
+                                ConfirmedTransaction::rejected_deploy(index, fee_tx, rejected).map(Some).map_err(|e| e.to_string())
+                            }
+                            // The fee cannot be paid (e.g. the public balance of the payer is insufficient),
+                            // so the transaction is dropped from the block.
+                            Err(_) => Ok(None),
+                        },
                     }
                     // The finalize operation here involves calling 'update_key_value',
                     // and update the respective leaves of the finalize tree.
-                    Transaction::Execute(_, execution, fee) => match process.finalize_execution(state, store, execution) {
+                    Transaction::Execute(_, execution, fee) => match finalize_execution_and_fee(&process, state, store, execution, fee.as_ref()) {
                         // Construct the accepted execute transaction.
-                        Ok(finalize) => ConfirmedTransaction::accepted_execute(index, transaction.clone(), finalize).map_err(|e| e.to_string()),
+                        Ok((finalize, events)) => ConfirmedTransaction::accepted_execute(index, transaction.clone(), finalize, events).map(Some).map_err(|e| e.to_string()),
                         // Construct the rejected execute transaction.
-                        Err(_error) => match fee {
-                            Some(fee) => {
-                                // Construct the fee transaction.
-                                // Note: On failure, this will abort the entire atomic batch.
-                                let fee_tx = Transaction::from_fee(fee.clone()).map_err(|e| e.to_string())?;
-                                // Construct the rejected execution.
-                                let rejected = Rejected::new_execution(execution.clone());
+                        Err(error) => match fee {
+                            Some(fee) => match process.finalize_fee(state, store, fee) {
                                 // Construct the rejected execute transaction.
-                                ConfirmedTransaction::rejected_execute(index, fee_tx, rejected).map_err(|e| e.to_string())
+                                Ok(_) => {
+                                    // Construct the fee transaction.
+                                    // Note: On failure, this will abort the entire atomic batch.
+                                    let fee_tx = Transaction::from_fee(fee.clone()).map_err(|e| e.to_string())?;
+                                    // Construct the rejected execution, with the reason a command failed (if any).
+                                    let reason = error.downcast_ref::<RejectionReason<N>>().cloned();
+                                    let rejected = Rejected::new_execution(execution.clone(), reason);
+                                    // Construct the rejected execute transaction.
+                                    ConfirmedTransaction::rejected_execute(index, fee_tx, rejected).map(Some).map_err(|e| e.to_string())
+                                }
+                                // The fee cannot be paid (e.g. the public balance of the payer is insufficient),
+                                // so the transaction is dropped from the block.
+                                Err(_) => Ok(None),
                             },
                             // This is a foundational bug - the caller is violating protocol rules.
                             // Note: This will abort the entire atomic batch.
@@ -129,7 +151,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
                 match outcome {
                     // If the transaction succeeded, store it and continue to the next transaction.
-                    Ok(confirmed_transaction) => confirmed.push(confirmed_transaction),
+                    Ok(Some(confirmed_transaction)) => confirmed.push(confirmed_transaction),
+                    // If the transaction was dropped, continue to the next transaction.
+                    Ok(None) => num_dropped += 1,
                     // If the transaction failed, abort the entire batch.
                     Err(error) => {
                         eprintln!("Critical bug in speculate: {error}\n\n{transaction}");
@@ -140,7 +164,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
 
             // Ensure all transactions were processed.
-            if confirmed.len() != num_transactions {
+            if confirmed.len() + num_dropped != num_transactions {
                 // Note: This will abort the entire atomic batch.
                 return Err("Not all transactions were processed in 'VM::atomic_speculate'".to_string());
             }
@@ -169,6 +193,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             // Initialize a list for the deployed stacks.
             let mut stacks = Vec::new();
+            // Initialize a map of the key-value IDs for each mapping, used to record the finalize diffs.
+            #[cfg(feature = "history")]
+            let mut key_value_ids = IndexMap::new();
 
             // Finalize the transactions.
             for (index, transaction) in transactions.iter().enumerate() {
@@ -179,26 +206,29 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Process the transaction in an isolated atomic batch.
                 // - If the transaction succeeds, the finalize operations are stored.
                 // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
-                let outcome: Result<(), String> = match transaction {
+                let outcome: Result<Vec<FinalizeOperation<N>>, String> = match transaction {
                     ConfirmedTransaction::AcceptedDeploy(idx, transaction, finalize) => {
                         // Ensure the index matches the expected index.
                         if index != *idx {
                             // Note: This will abort the entire atomic batch.
                             return Err("Mismatch in accepted deploy transaction index".to_string());
                         }
-                        // Extract the deployment from the transaction.
-                        let deployment = match transaction {
-                            Transaction::Deploy(_, _, deployment, _) => deployment,
+                        // Extract the deployment and fee from the transaction.
+                        let (deployment, fee) = match transaction {
+                            Transaction::Deploy(_, _, deployment, fee) => (deployment, fee),
                             // Note: This will abort the entire atomic batch.
                             _ => return Err("Expected deploy transaction".to_string()),
                         };
                         // The finalize operation here involves appending the 'stack',
                         // and adding the program to the finalize tree.
-                        match process.finalize_deployment(store, deployment) {
+                        match finalize_deployment_and_fee(&process, state, store, deployment, fee) {
                             // Ensure the finalize operations match the expected.
                             Ok((stack, finalize_operations)) => match finalize == &finalize_operations {
                                 // Store the stack.
-                                true => stacks.push(stack),
+                                true => {
+                                    stacks.push(stack);
+                                    Ok(finalize_operations)
+                                }
                                 // Note: This will abort the entire atomic batch.
                                 false => {
                                     return Err("Mismatch in finalize operations for an accepted deploy".to_string());
@@ -208,39 +238,42 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                             Err(error) => {
                                 return Err(format!("Failed to finalize an accepted deploy transaction - {error}"));
                             }
-                        };
-                        Ok(())
+                        }
                     }
-                    ConfirmedTransaction::AcceptedExecute(idx, transaction, finalize) => {
+                    ConfirmedTransaction::AcceptedExecute(idx, transaction, finalize, events) => {
                         // Ensure the index matches the expected index.
                         if index != *idx {
                             // Note: This will abort the entire atomic batch.
                             return Err("Mismatch in accepted execute transaction index".to_string());
                         }
-                        // Extract the execution from the transaction.
-                        let execution = match transaction {
-                            Transaction::Execute(_, execution, _) => execution,
+                        // Extract the execution and fee from the transaction.
+                        let (execution, fee) = match transaction {
+                            Transaction::Execute(_, execution, fee) => (execution, fee.as_ref()),
                             // Note: This will abort the entire atomic batch.
                             _ => return Err("Expected execute transaction".to_string()),
                         };
                         // The finalize operation here involves calling 'update_key_value',
                         // and update the respective leaves of the finalize tree.
-                        match process.finalize_execution(state, store, execution) {
-                            // Ensure the finalize operations match the expected.
-                            Ok(finalize_operations) => {
+                        match finalize_execution_and_fee(&process, state, store, execution, fee) {
+                            // Ensure the finalize operations and events match the expected.
+                            Ok((finalize_operations, emitted_events)) => {
                                 if finalize != &finalize_operations {
                                     // Note: This will abort the entire atomic batch.
                                     return Err("Mismatch in finalize operations for an accepted execute".to_string());
                                 }
+                                if events != &emitted_events {
+                                    // Note: This will abort the entire atomic batch.
+                                    return Err("Mismatch in events for an accepted execute".to_string());
+                                }
+                                Ok(finalize_operations)
                             }
                             // Note: This will abort the entire atomic batch.
                             Err(error) => {
                                 return Err(format!("Failed to finalize an accepted execute transaction - {error}"));
                             }
                         }
-                        Ok(())
                     }
-                    ConfirmedTransaction::RejectedDeploy(idx, _fee_transaction, rejected) => {
+                    ConfirmedTransaction::RejectedDeploy(idx, fee_transaction, rejected) => {
                         // Ensure the index matches the expected index.
                         if index != *idx {
                             // Note: This will abort the entire atomic batch.
@@ -252,16 +285,28 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                             // Note: This will abort the entire atomic batch.
                             return Err("Expected rejected deployment".to_string());
                         };
+                        // Extract the fee from the fee transaction.
+                        let Transaction::Fee(_, fee) = fee_transaction else {
+                            // Note: This will abort the entire atomic batch.
+                            return Err("Expected fee transaction".to_string());
+                        };
                         // TODO (howardwu): Ensure this fee corresponds to the deployment.
                         // Attempt to finalize the deployment, which should fail.
                         #[cfg(debug_assertions)]
-                        if let Ok(..) = process.finalize_deployment(store, deployment) {
+                        if let Ok(..) = finalize_deployment_and_fee(&process, state, store, deployment, fee) {
                             // Note: This will abort the entire atomic batch.
                             return Err("Failed to reject a rejected deploy transaction".to_string());
                         }
-                        Ok(())
+                        // Charge the fee on its own.
+                        match process.finalize_fee(state, store, fee) {
+                            Ok(finalize_operations) => Ok(finalize_operations),
+                            // Note: This will abort the entire atomic batch.
+                            Err(error) => {
+                                return Err(format!("Failed to charge the fee for a rejected deploy - {error}"));
+                            }
+                        }
                     }
-                    ConfirmedTransaction::RejectedExecute(idx, _fee_transaction, rejected) => {
+                    ConfirmedTransaction::RejectedExecute(idx, fee_transaction, rejected) => {
                         // Ensure the index matches the expected index.
                         if index != *idx {
                             // Note: This will abort the entire atomic batch.
@@ -273,21 +318,44 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                             // Note: This will abort the entire atomic batch.
                             return Err("Expected rejected execution".to_string());
                         };
+                        // Extract the fee from the fee transaction.
+                        let Transaction::Fee(_, fee) = fee_transaction else {
+                            // Note: This will abort the entire atomic batch.
+                            return Err("Expected fee transaction".to_string());
+                        };
                         // TODO (howardwu): Ensure this fee corresponds to the execution.
                         // Attempt to finalize the execution, which should fail.
                         #[cfg(debug_assertions)]
-                        if let Ok(..) = process.finalize_execution(state, store, execution) {
+                        if let Ok(..) = finalize_execution_and_fee(&process, state, store, execution, Some(fee)) {
                             // Note: This will abort the entire atomic batch.
                             return Err("Failed to reject a rejected execute transaction".to_string());
                         }
-                        Ok(())
+                        // Charge the fee on its own.
+                        match process.finalize_fee(state, store, fee) {
+                            Ok(finalize_operations) => Ok(finalize_operations),
+                            // Note: This will abort the entire atomic batch.
+                            Err(error) => {
+                                return Err(format!("Failed to charge the fee for a rejected execute - {error}"));
+                            }
+                        }
                     }
                 };
                 lap!(timer, "Finalizing transaction {}", transaction.id());
 
                 match outcome {
                     // If the transaction succeeded to finalize, continue to the next transaction.
-                    Ok(()) => (),
+                    #[cfg(not(feature = "history"))]
+                    Ok(_) => (),
+                    // If the transaction succeeded to finalize, record its finalize diff,
+                    // and continue to the next transaction.
+                    #[cfg(feature = "history")]
+                    Ok(finalize_operations) => {
+                        let result = store.insert_diff(transaction.id(), &finalize_operations, &mut key_value_ids);
+                        if let Err(error) = result {
+                            // Note: This will abort the entire atomic batch.
+                            return Err(format!("Failed to record the finalize diff - {error}"));
+                        }
+                    }
                     // If the transaction failed to finalize, abort and continue to the next transaction.
                     Err(error) => {
                         eprintln!("Critical bug in finalize: {error}\n\n{transaction}");
@@ -311,6 +379,57 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     }
 }
 
+/// Finalizes the given deployment, runs its constructor (if any), and then charges its fee, in one atomic scope.
+/// If any fails, none are applied, so the caller may reject the deployment and charge the fee on its own.
+fn finalize_deployment_and_fee<N: Network, P: FinalizeStorage<N>>(
+    process: &Process<N>,
+    state: FinalizeGlobalState,
+    store: &FinalizeStore<N, P>,
+    deployment: &Deployment<N>,
+    fee: &Fee<N>,
+) -> Result<(Stack<N>, Vec<FinalizeOperation<N>>)> {
+    atomic_batch_scope!(store, {
+        // Finalize the deployment.
+        let (stack, mut finalize_operations) = process.finalize_deployment(store, deployment)?;
+        // Run the constructor, which initializes the program mappings.
+        // Note: The fee transition ID is used to seed the constructor, as the deployment has no transition.
+        finalize_operations.extend(process.finalize_constructor(state, store, &stack, *fee.transition_id())?);
+        // Charge the fee, which only updates the finalize store if the fee is public.
+        finalize_operations.extend(process.finalize_fee(state, store, fee)?);
+        Ok((stack, finalize_operations))
+    })
+}
+
+/// Finalizes the given execution, and then charges its fee (if any), in one atomic scope.
+/// If either fails, or once storage pricing is active, the fee does not cover the storage cost of the
+/// finalize operations, neither is applied, so the caller may reject the execution and charge the fee on its own.
+/// On success, returns the finalize operations and the events emitted by the execution.
+fn finalize_execution_and_fee<N: Network, P: FinalizeStorage<N>>(
+    process: &Process<N>,
+    state: FinalizeGlobalState,
+    store: &FinalizeStore<N, P>,
+    execution: &Execution<N>,
+    fee: Option<&Fee<N>>,
+) -> Result<(Vec<FinalizeOperation<N>>, Vec<Event<N>>)> {
+    atomic_batch_scope!(store, {
+        // Finalize the execution.
+        let (mut finalize_operations, events) = process.finalize_execution(state, store, execution)?;
+        if let Some(fee) = fee {
+            // Ensure the fee covers the minimum cost, and the storage cost of the finalize operations.
+            if state.block_height() >= N::STORAGE_PRICING_HEIGHT {
+                let (minimum_cost, _) = execution_cost_in_process(process, execution, state.block_height())?;
+                let (storage_cost, _) = storage_cost_in_microcredits(&finalize_operations)?;
+                let cost = minimum_cost.checked_add(storage_cost).ok_or(anyhow!("The execution cost overflowed"))?;
+                let fee_amount = *fee.amount()?;
+                ensure!(fee_amount >= cost, "The fee does not cover the storage cost - expected {cost} microcredits");
+            }
+            // Charge the fee, which only updates the finalize store if the fee is public.
+            finalize_operations.extend(process.finalize_fee(state, store, fee)?);
+        }
+        Ok((finalize_operations, events))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -556,12 +675,16 @@ finalize transfer_public:
     }
 
     /// A helper method to construct the rejected transaction format for `atomic_finalize`.
-    fn reject(index: u32, transaction: &Transaction<CurrentNetwork>) -> ConfirmedTransaction<CurrentNetwork> {
+    fn reject(
+        index: u32,
+        transaction: &Transaction<CurrentNetwork>,
+        reason: Option<RejectionReason<CurrentNetwork>>,
+    ) -> ConfirmedTransaction<CurrentNetwork> {
         match transaction {
             Transaction::Execute(_, execution, fee) => ConfirmedTransaction::RejectedExecute(
                 index,
                 Transaction::from_fee(fee.clone().unwrap()).unwrap(),
-                Rejected::new_execution(execution.clone()),
+                Rejected::new_execution(execution.clone(), reason),
             ),
             _ => panic!("only reject execution transactions"),
         }
@@ -689,6 +812,16 @@ finalize transfer_public:
             rng,
         );
 
+        // The reason a transfer is rejected, which is the underflow of the `sub` command in `transfer_public`.
+        let underflow = RejectionReason::new(
+            ProgramID::from_str(&program_id).unwrap(),
+            Identifier::from_str("transfer_public").unwrap(),
+            2,
+            "sub",
+            None,
+        )
+        .unwrap();
+
         // TODO (raychu86): Confirm that the finalize_operations here are correct.
 
         // Starting Balance = 20
@@ -742,7 +875,7 @@ finalize transfer_public:
             assert!(confirmed_transactions[1].is_rejected());
 
             assert_eq!(confirmed_transactions[0].transaction(), &transfer_20);
-            assert_eq!(confirmed_transactions[1], reject(1, &transfer_10));
+            assert_eq!(confirmed_transactions[1], reject(1, &transfer_10, Some(underflow.clone())));
         }
 
         // Starting Balance = 20
@@ -764,7 +897,7 @@ finalize transfer_public:
 
             assert_eq!(confirmed_transactions[0].transaction(), &mint_20);
             assert_eq!(confirmed_transactions[1].transaction(), &transfer_30);
-            assert_eq!(confirmed_transactions[2], reject(2, &transfer_20));
+            assert_eq!(confirmed_transactions[2], reject(2, &transfer_20, Some(underflow)));
             assert_eq!(confirmed_transactions[3].transaction(), &transfer_10);
         }
     }
@@ -854,7 +987,7 @@ function ped_hash:
             if let Transaction::Execute(_, execution, fee) = transaction {
                 let fee_transaction = Transaction::from_fee(fee.unwrap()).unwrap();
                 let expected_confirmed_transaction =
-                    ConfirmedTransaction::RejectedExecute(0, fee_transaction, Rejected::new_execution(execution));
+                    ConfirmedTransaction::RejectedExecute(0, fee_transaction, Rejected::new_execution(execution, None));
 
                 let confirmed_transaction = confirmed_transactions.iter().next().unwrap();
                 assert_eq!(confirmed_transaction, &expected_confirmed_transaction);
@@ -994,4 +1127,548 @@ finalize compute:
         let expected = Value::<CurrentNetwork>::from_str("3u8").unwrap();
         assert_eq!(value, expected);
     }
+
+    #[test]
+    fn test_finalize_constructor() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key, view key, and address for the caller.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Deploy a new program.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Initialize a program whose constructor seeds the admin and the supply,
+        // and a program whose constructor halts.
+        let constructors = [
+            ("seeded.aleo", format!("set {caller_address} into admin[0u8];")),
+            ("halted.aleo", "div 1u64 0u64 into r0;".to_string()),
+        ];
+        let programs = constructors.map(|(program_id, command)| {
+            Program::<CurrentNetwork>::from_str(&format!(
+                "
+program {program_id};
+
+mapping admin:
+    key id as u8.public;
+    value owner as address.public;
+
+mapping supply:
+    key id as u8.public;
+    value amount as u64.public;
+
+function noop:
+    input r0 as u8.public;
+
+constructor:
+    {command}
+    set 1000u64 into supply[0u8];"
+            ))
+            .unwrap()
+        });
+
+        // Deploy the programs.
+        let transactions = programs
+            .iter()
+            .map(|program| {
+                let credits = unspent_records.pop().unwrap().decrypt(&caller_view_key).unwrap();
+                vm.deploy(&caller_private_key, program, (credits, 10), None, rng).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // Construct the deployment block.
+        let deployment_block =
+            sample_next_block(&vm, &caller_private_key, &transactions, &genesis, &mut unspent_records, rng).unwrap();
+
+        // Ensure the first deployment is accepted, and records the finalize operations of its constructor.
+        let mut confirmed_transactions = deployment_block.transactions().iter();
+        let confirmed = confirmed_transactions.next().unwrap();
+        assert!(matches!(confirmed, ConfirmedTransaction::AcceptedDeploy(..)));
+        let finalize_operations = confirmed.finalize_operations().unwrap();
+        let num_inserts = finalize_operations
+            .iter()
+            .filter(|operation| matches!(operation, FinalizeOperation::InsertKeyValue(..)))
+            .count();
+        assert_eq!(num_inserts, 2);
+        // Ensure the second deployment is rejected, as its constructor halts.
+        assert!(matches!(confirmed_transactions.next().unwrap(), ConfirmedTransaction::RejectedDeploy(..)));
+
+        // Add the deployment block to the VM.
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Ensure the constructor initialized the mappings of the accepted program.
+        let program_id = ProgramID::from_str("seeded.aleo").unwrap();
+        let key = Plaintext::from(Literal::U8(console::types::U8::new(0)));
+        let get_value = |mapping_name: &str| {
+            vm.finalize_store()
+                .get_value_confirmed(&program_id, &Identifier::from_str(mapping_name).unwrap(), &key)
+                .unwrap()
+                .unwrap()
+        };
+        assert_eq!(get_value("admin"), Value::from_str(&caller_address.to_string()).unwrap());
+        assert_eq!(get_value("supply"), Value::from_str("1000u64").unwrap());
+
+        // Ensure the rejected program was not deployed.
+        assert!(!vm.contains_program(&ProgramID::from_str("halted.aleo").unwrap()));
+    }
+
+    #[test]
+    fn test_nested_finalize() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Deploy a new program.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Generate more records to use for the next block.
+        let splits_block = generate_splits(&vm, &private_key, &genesis, &mut unspent_records, rng).unwrap();
+
+        // Add the splits block to the VM.
+        vm.add_next_block(&splits_block).unwrap();
+
+        // Initialize the callee, which outputs the updated counter from its finalize scope.
+        // Note: The finalize scope halts if the amount is zero.
+        let callee = Program::<CurrentNetwork>::from_str(
+            "
+program child.aleo;
+
+mapping counter:
+    key id as u8.public;
+    value count as u64.public;
+
+function bump:
+    input r0 as u64.public;
+    finalize r0;
+
+finalize bump:
+    input r0 as u64.public;
+    div 100u64 r0 into r1;
+    get.or_use counter[0u8] 0u64 into r2;
+    add r2 r0 into r3;
+    set r3 into counter[0u8];
+    output r3 as u64.public;",
+        )
+        .unwrap();
+
+        // Initialize the caller, which receives the finalize outputs of its callees, in order of the calls.
+        // Note: The finalize scope halts if the total exceeds 100.
+        let caller = Program::<CurrentNetwork>::from_str(
+            "
+import child.aleo;
+
+program parent.aleo;
+
+mapping totals:
+    key id as u8.public;
+    value total as u64.public;
+
+function bump_twice:
+    input r0 as u64.public;
+    input r1 as u64.public;
+    call child.aleo/bump r0;
+    call child.aleo/bump r1;
+    finalize r1;
+
+finalize bump_twice:
+    input r0 as u64.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    sub r2 r1 into r3;
+    assert.eq r3 r0;
+    lte r2 100u64 into r4;
+    assert.eq r4 true \"the total exceeds 100\";
+    set r2 into totals[0u8];",
+        )
+        .unwrap();
+
+        // Deploy the callee, and then the caller.
+        let mut previous_block = splits_block;
+        for program in [&callee, &caller] {
+            // Prepare the additional fee.
+            let view_key = ViewKey::<CurrentNetwork>::try_from(private_key).unwrap();
+            let credits = unspent_records.pop().unwrap().decrypt(&view_key).unwrap();
+            let additional_fee = (credits, 10);
+
+            // Deploy.
+            let transaction = vm.deploy(&private_key, program, additional_fee, None, rng).unwrap();
+
+            // Construct the deployment block, and add it to the VM.
+            let deployment_block =
+                sample_next_block(&vm, &private_key, &[transaction], &previous_block, &mut unspent_records, rng)
+                    .unwrap();
+            vm.add_next_block(&deployment_block).unwrap();
+            previous_block = deployment_block;
+        }
+
+        // Generate more records to use for the next block.
+        let splits_block = generate_splits(&vm, &private_key, &previous_block, &mut unspent_records, rng).unwrap();
+
+        // Add the splits block to the VM.
+        vm.add_next_block(&splits_block).unwrap();
+
+        // A helper to create an execution of the caller.
+        let mut bump_twice = |first: &str, second: &str| {
+            let inputs = vec![Value::from_str(first).unwrap(), Value::from_str(second).unwrap()];
+            create_execution(&vm, private_key, "parent.aleo", "bump_twice", inputs, &mut unspent_records, rng)
+        };
+
+        // Create an execution that will be accepted.
+        let accepted = bump_twice("1u64", "2u64");
+        // Create an execution that will be rejected, as the caller finalize scope fails.
+        let caller_fails = bump_twice("50u64", "60u64");
+        // Create an execution that will be rejected, as a callee finalize scope fails.
+        let callee_fails = bump_twice("0u64", "1u64");
+
+        // Construct the next block.
+        let next_block = sample_next_block(
+            &vm,
+            &private_key,
+            &[accepted, caller_fails, callee_fails],
+            &splits_block,
+            &mut unspent_records,
+            rng,
+        )
+        .unwrap();
+
+        // Check that only the first transaction was accepted.
+        let mut transactions = next_block.transactions().iter();
+        assert!(transactions.next().unwrap().is_accepted());
+        // Check that the rejections record the failed command, and its message (if any).
+        let reason = |program_id: &str, name: &str, index: u16, opcode: &str, message: Option<&str>| {
+            let (program_id, name) = (ProgramID::from_str(program_id).unwrap(), Identifier::from_str(name).unwrap());
+            RejectionReason::new(program_id, name, index, opcode, message).unwrap()
+        };
+        let rejected = transactions.next().unwrap();
+        assert!(rejected.is_rejected());
+        let expected = reason("parent.aleo", "bump_twice", 3, "assert.eq", Some("the total exceeds 100"));
+        assert_eq!(rejected.rejection_reason(), Some(&expected));
+        let rejected = transactions.next().unwrap();
+        assert!(rejected.is_rejected());
+        assert_eq!(rejected.rejection_reason(), Some(&reason("child.aleo", "bump", 0, "div", None)));
+
+        // Add the next block to the VM.
+        vm.add_next_block(&next_block).unwrap();
+
+        // Check that the storage reflects only the accepted execution.
+        let key = Plaintext::from(Literal::U8(console::types::U8::new(0)));
+        let get_value = |program_id: &str, mapping_name: &str| {
+            vm.finalize_store()
+                .get_value_confirmed(
+                    &ProgramID::from_str(program_id).unwrap(),
+                    &Identifier::from_str(mapping_name).unwrap(),
+                    &key,
+                )
+                .unwrap()
+                .unwrap()
+        };
+        let expected = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+        assert_eq!(get_value("child.aleo", "counter"), expected);
+        assert_eq!(get_value("parent.aleo", "totals"), expected);
+    }
+
+    #[test]
+    fn test_finalize_fee_public() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Generate more records to use for the next block.
+        let splits_block = generate_splits(&vm, &private_key, &genesis, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&splits_block).unwrap();
+
+        // A helper to retrieve the public balance of the caller.
+        let public_balance = |vm: &VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>>| {
+            let program_id = ProgramID::from_str("credits.aleo").unwrap();
+            let mapping_name = Identifier::from_str("account").unwrap();
+            let key = Plaintext::from(Literal::Address(address));
+            match vm.finalize_store().get_value_confirmed(&program_id, &mapping_name, &key).unwrap() {
+                Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => *balance,
+                _ => 0,
+            }
+        };
+
+        // Fund the public balance of the caller.
+        let view_key = ViewKey::<CurrentNetwork>::try_from(private_key).unwrap();
+        let record = unspent_records.pop().unwrap().decrypt(&view_key).unwrap();
+        let inputs = vec![
+            Value::Record(record),
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("10_000_000u64").unwrap(),
+        ];
+        let transaction = create_execution(
+            &vm,
+            private_key,
+            "credits.aleo",
+            "transfer_private_to_public",
+            inputs,
+            &mut unspent_records,
+            rng,
+        );
+        let funding_block =
+            sample_next_block(&vm, &private_key, &[transaction], &splits_block, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&funding_block).unwrap();
+        assert_eq!(public_balance(&vm), 10_000_000);
+
+        // Sample a recipient.
+        let recipient = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // A helper to execute a public transfer, and pay for it with a public fee.
+        let transfer_public = |amount: u64, rng: &mut TestRng| {
+            let inputs = [
+                Value::<CurrentNetwork>::from_str(&recipient.to_string()).unwrap(),
+                Value::<CurrentNetwork>::from_str(&format!("{amount}u64")).unwrap(),
+            ];
+            let transaction =
+                vm.execute(&private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, None, rng);
+            let execution = transaction.unwrap().execution().unwrap().clone();
+            // Compute the public fee, which covers the entries written by the transfer.
+            let (cost, _) = execution_cost(&vm, &execution).unwrap();
+            let cost = cost + max_execution_storage_cost(&vm, &execution).unwrap();
+            let fee = vm
+                .execute_fee_public_raw(&private_key, cost, execution.to_execution_id().unwrap(), None, rng)
+                .unwrap()
+                .1;
+            assert!(fee.is_fee_public());
+            assert_eq!(fee.payer(), Some(address));
+            // Construct and verify the transaction.
+            let transaction = Transaction::from_execution(execution, Some(fee)).unwrap();
+            assert!(vm.verify_transaction(&transaction, None));
+            (transaction, cost)
+        };
+
+        // Transfer within the balance, which is accepted and charges the amount and the fee.
+        let (transaction, cost) = transfer_public(1, rng);
+        let next_block =
+            sample_next_block(&vm, &private_key, &[transaction], &funding_block, &mut unspent_records, rng).unwrap();
+        assert!(next_block.transactions().iter().next().unwrap().is_accepted());
+        vm.add_next_block(&next_block).unwrap();
+        let balance = public_balance(&vm);
+        assert_eq!(balance, 10_000_000 - 1 - cost);
+
+        // Transfer more than the balance, which is rejected, and still charges the fee.
+        let (transaction, cost) = transfer_public(balance + 1, rng);
+        let next_block =
+            sample_next_block(&vm, &private_key, &[transaction], &next_block, &mut unspent_records, rng).unwrap();
+        assert!(next_block.transactions().iter().next().unwrap().is_rejected());
+        vm.add_next_block(&next_block).unwrap();
+        assert_eq!(public_balance(&vm), balance - cost);
+
+        // Ensure a public fee that exceeds the balance cannot be created.
+        let execution_id = Field::rand(rng);
+        assert!(vm.execute_fee_public(&private_key, public_balance(&vm) + 1, execution_id, None, rng).is_err());
+
+        // Spend the entire balance, and then transfer again with a fee that can no longer be paid.
+        let balance = public_balance(&vm);
+        let (_, cost) = transfer_public(1, rng);
+        let (first, _) = transfer_public(balance - cost, rng);
+        let (second, _) = transfer_public(1, rng);
+
+        // Ensure the second transaction is dropped, instead of aborting the speculation.
+        let confirmed_transactions = vm.speculate(sample_finalize_state(1), [first, second].iter()).unwrap();
+        assert_eq!(confirmed_transactions.len(), 1);
+        let confirmed = confirmed_transactions.iter().next().unwrap();
+        assert!(confirmed.is_accepted());
+        assert_eq!(confirmed.index(), 0);
+    }
+
+    #[test]
+    fn test_finalize_storage_usage() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key.
+        let private_key = test_helpers::sample_genesis_private_key(rng);
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Retrieve the genesis block.
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Generate more records to use for the next block.
+        let splits_block = generate_splits(&vm, &private_key, &genesis, &mut unspent_records, rng).unwrap();
+
+        // Add the splits block to the VM.
+        vm.add_next_block(&splits_block).unwrap();
+
+        // Initialize a program that stores and deletes entries in a mapping.
+        let program = Program::<CurrentNetwork>::from_str(
+            "
+program storage.aleo;
+
+mapping entries:
+    key id as u8.public;
+    value amount as u64.public;
+
+function store:
+    input r0 as u8.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize store:
+    input r0 as u8.public;
+    input r1 as u64.public;
+    set r1 into entries[r0];
+
+function delete:
+    input r0 as u8.public;
+    finalize r0;
+
+finalize delete:
+    input r0 as u8.public;
+    remove entries[r0];",
+        )
+        .unwrap();
+
+        // Deploy the program.
+        let view_key = ViewKey::<CurrentNetwork>::try_from(private_key).unwrap();
+        let credits = unspent_records.pop().unwrap().decrypt(&view_key).unwrap();
+        let transaction = vm.deploy(&private_key, &program, (credits, 10), None, rng).unwrap();
+        let deployment_block =
+            sample_next_block(&vm, &private_key, &[transaction], &splits_block, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Generate more records to use for the next blocks.
+        let splits_block = generate_splits(&vm, &private_key, &deployment_block, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&splits_block).unwrap();
+
+        // A helper to retrieve the confirmed usage of the mapping.
+        let (program_id, mapping_name) =
+            (ProgramID::from_str("storage.aleo").unwrap(), Identifier::from_str("entries").unwrap());
+        let usage = || vm.finalize_store().get_mapping_usage_confirmed(&program_id, &mapping_name).unwrap().unwrap();
+        // A helper to compute the size in bytes of an entry.
+        let size = |key: &str, value: &str| {
+            let key = Plaintext::<CurrentNetwork>::from_str(key).unwrap();
+            let value = Value::<CurrentNetwork>::from_str(value).unwrap();
+            (key.to_bytes_le().unwrap().len() + value.to_bytes_le().unwrap().len()) as u64
+        };
+        assert_eq!(usage(), (0, 0));
+
+        // Store two new entries, before storage pricing is active.
+        let transactions = [("1u8", "5u64"), ("2u8", "6u64")].map(|(key, value)| {
+            let inputs = vec![Value::from_str(key).unwrap(), Value::from_str(value).unwrap()];
+            create_execution(&vm, private_key, "storage.aleo", "store", inputs, &mut unspent_records, rng)
+        });
+        let next_block =
+            sample_next_block(&vm, &private_key, &transactions, &splits_block, &mut unspent_records, rng).unwrap();
+
+        // Ensure each execution writes a legacy update, which does not commit to the size of the entry.
+        for confirmed in next_block.transactions().iter() {
+            let finalize_operations = confirmed.finalize_operations().unwrap();
+            assert!(matches!(finalize_operations.as_slice(), [FinalizeOperation::UpdateKeyValue(..)]));
+        }
+        vm.add_next_block(&next_block).unwrap();
+        // Ensure the usage is tracked regardless.
+        assert_eq!(usage(), (2, size("1u8", "5u64") + size("2u8", "6u64")));
+
+        // Overwrite the first entry, and delete the second entry, once storage pricing is active.
+        let height = CurrentNetwork::STORAGE_PRICING_HEIGHT;
+        let inputs = vec![Value::from_str("1u8").unwrap(), Value::from_str("7u64").unwrap()];
+        let update = create_execution(&vm, private_key, "storage.aleo", "store", inputs, &mut unspent_records, rng);
+        let inputs = vec![Value::from_str("2u8").unwrap()];
+        let remove = create_execution(&vm, private_key, "storage.aleo", "delete", inputs, &mut unspent_records, rng);
+        let transactions = vm.speculate(sample_finalize_state(height), [update, remove].iter()).unwrap();
+
+        // Ensure the update commits to the sizes of the entry, and is charged less than a new entry.
+        let mut confirmed = transactions.iter();
+        let finalize_operations = confirmed.next().unwrap().finalize_operations().unwrap();
+        let (previous_size, new_size) = (size("1u8", "5u64"), size("1u8", "7u64"));
+        assert!(matches!(
+            finalize_operations.as_slice(),
+            [FinalizeOperation::UpdateSizedKeyValue(_, 0, _, _, p, s)] if *p == previous_size && *s == new_size
+        ));
+        assert_eq!(storage_cost_in_microcredits(finalize_operations).unwrap(), (100_000, (100_000, 0)));
+        // Ensure the removal commits to the size of the entry, and is only a discount.
+        let finalize_operations = confirmed.next().unwrap().finalize_operations().unwrap();
+        let removed_size = size("2u8", "6u64");
+        assert!(matches!(
+            finalize_operations.as_slice(),
+            [FinalizeOperation::RemoveSizedKeyValue(_, 1, s)] if *s == removed_size
+        ));
+        assert_eq!(storage_cost_in_microcredits(finalize_operations).unwrap(), (0, (0, 500_000)));
+        vm.finalize(sample_finalize_state(height), &transactions).unwrap();
+
+        // Ensure the usage reflects the remaining entry.
+        assert_eq!(usage(), (1, size("1u8", "7u64")));
+        assert_eq!(vm.finalize_store().get_program_usage_confirmed(&program_id).unwrap(), Some(usage()));
+
+        // Generate more records to use for the next block.
+        let splits_block = generate_splits(&vm, &private_key, &next_block, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&splits_block).unwrap();
+
+        // A helper to store an entry, with a fee that covers an update, but not an insertion, at the activation height.
+        let mut store = |key: &str, rng: &mut TestRng| {
+            let inputs = [Value::<CurrentNetwork>::from_str(key).unwrap(), Value::from_str("8u64").unwrap()];
+            let transaction =
+                vm.execute(&private_key, ("storage.aleo", "store"), inputs.into_iter(), None, None, rng).unwrap();
+            let execution = transaction.execution().unwrap().clone();
+            let (cost, _) = execution_cost_in_process(&vm.process().read(), &execution, height).unwrap();
+            let credits = unspent_records.pop().unwrap().decrypt(&view_key).unwrap();
+            let execution_id = execution.to_execution_id().unwrap();
+            let fee = vm.execute_fee_raw(&private_key, credits, cost + MAPPING_UPDATE_COST, execution_id, None, rng);
+            Transaction::from_execution(execution, Some(fee.unwrap().1)).unwrap()
+        };
+        let transactions = [store("1u8", rng), store("3u8", rng)];
+
+        // Ensure both are accepted before storage pricing is active.
+        let confirmed = vm.speculate(sample_finalize_state(height - 1), transactions.iter()).unwrap();
+        assert!(confirmed.iter().all(|confirmed| confirmed.is_accepted()));
+
+        // Ensure the update is accepted, and the insertion is rejected for an insufficient fee.
+        let transactions = vm.speculate(sample_finalize_state(height), transactions.iter()).unwrap();
+        let mut transactions = transactions.iter();
+        assert!(transactions.next().unwrap().is_accepted());
+        assert!(transactions.next().unwrap().is_rejected());
+    }
 }
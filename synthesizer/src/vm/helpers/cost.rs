@@ -16,11 +16,19 @@ use crate::VM;
 use console::{prelude::*, program::LiteralType};
 use ledger_block::{Deployment, Execution};
 use ledger_store::ConsensusStorage;
-use synthesizer_program::{Command, Finalize, Instruction};
+use synthesizer_process::Process;
+use synthesizer_program::{Command, Finalize, FinalizeOperation, Instruction};
 
-use std::collections::HashMap;
+/// The cost in microcredits to insert a new entry into a mapping.
+pub const MAPPING_INSERT_COST: u64 = 1_000_000;
+/// The cost in microcredits to overwrite an existing entry in a mapping.
+pub const MAPPING_UPDATE_COST: u64 = 100_000;
+/// The discount in microcredits for removing an entry from a mapping.
+/// Note: The discount only offsets the storage charge of the same execution, and is never paid out.
+pub const MAPPING_REMOVE_DISCOUNT: u64 = 500_000;
 
 /// Returns the *minimum* cost in microcredits to publish the given deployment (total cost, (storage cost, namespace cost)).
+/// Note: The total cost includes the cost to run the constructor of the program (if any).
 pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (u64, u64))> {
     // Determine the number of bytes in the deployment.
     let size_in_bytes = deployment.size_in_bytes()?;
@@ -40,44 +48,91 @@ pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (
         .ok_or(anyhow!("The namespace cost computation overflowed for a deployment"))?
         .saturating_mul(1_000_000); // 1 microcredit = 1e-6 credits.
 
+    // Compute the constructor cost in microcredits.
+    // Note: A constructor has no legacy pricing, so it is priced as if storage pricing is active,
+    // and as it writes to new mappings, each `set` is charged as an insertion.
+    let constructor_cost = match deployment.program().constructor() {
+        Some(constructor) => cost_in_microcredits(constructor.logic(), N::STORAGE_PRICING_HEIGHT)?
+            .checked_add(max_storage_cost_in_microcredits(constructor.logic())?)
+            .ok_or(anyhow!("The constructor cost computation overflowed for a deployment"))?,
+        None => 0,
+    };
+
     // Compute the total cost in microcredits.
     let total_cost = storage_cost
         .checked_add(namespace_cost)
+        .and_then(|cost| cost.checked_add(constructor_cost))
         .ok_or(anyhow!("The total cost computation overflowed for a deployment"))?;
 
     Ok((total_cost, (storage_cost, namespace_cost)))
 }
 
-/// Returns the *minimum* cost in microcredits to publish the given execution (total cost, (storage cost, namespace cost)).
+/// Returns the *minimum* cost in microcredits to publish the given execution in the next block
+/// (total cost, (storage cost, finalize cost)).
+/// Note: Once storage pricing is active, the mapping entries written by the execution are charged in finalize,
+/// by `storage_cost_in_microcredits`.
 pub fn execution_cost<N: Network, C: ConsensusStorage<N>>(
     vm: &VM<N, C>,
     execution: &Execution<N>,
+) -> Result<(u64, (u64, u64))> {
+    execution_cost_in_process(&vm.process().read(), execution, next_block_height(vm))
+}
+
+/// Returns the *maximum* cost in microcredits of the mapping entries written by the given execution in the next block,
+/// which charges each `set` as an insertion, for the maximum number of times it may be executed.
+/// Note: Before storage pricing is active, the entries are covered by the cost of each `set`, so this is zero.
+pub fn max_execution_storage_cost<N: Network, C: ConsensusStorage<N>>(
+    vm: &VM<N, C>,
+    execution: &Execution<N>,
+) -> Result<u64> {
+    match next_block_height(vm) >= N::STORAGE_PRICING_HEIGHT {
+        true => finalize_cost_in_process(&vm.process().read(), execution, max_storage_cost_in_microcredits),
+        false => Ok(0),
+    }
+}
+
+/// Returns the height of the next block in the given VM.
+fn next_block_height<N: Network, C: ConsensusStorage<N>>(vm: &VM<N, C>) -> u32 {
+    vm.block_store().current_block_height().saturating_add(1)
+}
+
+/// Returns the *minimum* cost in microcredits to publish the given execution at the given block height,
+/// using the programs in the given process.
+pub(crate) fn execution_cost_in_process<N: Network>(
+    process: &Process<N>,
+    execution: &Execution<N>,
+    block_height: u32,
 ) -> Result<(u64, (u64, u64))> {
     // Compute the storage cost in microcredits.
     let storage_cost = execution.size_in_bytes()?;
 
-    // Prepare the program lookup.
-    let lookup = execution
-        .transitions()
-        .map(|transition| {
-            let program_id = transition.program_id();
-            Ok((*program_id, vm.process().read().get_program(program_id)?.clone()))
-        })
-        .collect::<Result<HashMap<_, _>>>()?;
+    // Compute the finalize cost in microcredits.
+    let finalize_cost =
+        finalize_cost_in_process(process, execution, |finalize| cost_in_microcredits(finalize, block_height))?;
+
+    // Compute the total cost in microcredits.
+    let total_cost = storage_cost
+        .checked_add(finalize_cost)
+        .ok_or(anyhow!("The total cost computation overflowed for an execution"))?;
+
+    Ok((total_cost, (storage_cost, finalize_cost)))
+}
 
+/// Returns the sum of the given cost over the finalize scopes of the given execution.
+fn finalize_cost_in_process<N: Network>(
+    process: &Process<N>,
+    execution: &Execution<N>,
+    cost: impl Fn(&Finalize<N>) -> Result<u64>,
+) -> Result<u64> {
     // Compute the finalize cost in microcredits.
     let mut finalize_cost = 0u64;
     // Iterate over the transitions to accumulate the finalize cost.
     for transition in execution.transitions() {
-        // Retrieve the program ID.
-        let program_id = transition.program_id();
-        // Retrieve the function name.
-        let function_name = transition.function_name();
-        // Retrieve the program.
-        let program = lookup.get(program_id).ok_or(anyhow!("Program '{program_id}' is missing"))?;
+        // Retrieve the function.
+        let function = process.get_program(transition.program_id())?.get_function(transition.function_name())?;
         // Retrieve the finalize cost.
-        let cost = match program.get_function(function_name)?.finalize() {
-            Some((_, finalize)) => cost_in_microcredits(finalize)?,
+        let cost = match function.finalize() {
+            Some((_, finalize)) => cost(finalize)?,
             None => continue,
         };
         // Accumulate the finalize cost.
@@ -85,17 +140,12 @@ pub fn execution_cost<N: Network, C: ConsensusStorage<N>>(
             .checked_add(cost)
             .ok_or(anyhow!("The finalize cost computation overflowed for an execution"))?;
     }
-
-    // Compute the total cost in microcredits.
-    let total_cost = storage_cost
-        .checked_add(finalize_cost)
-        .ok_or(anyhow!("The total cost computation overflowed for an execution"))?;
-
-    Ok((total_cost, (storage_cost, finalize_cost)))
+    Ok(finalize_cost)
 }
 
-/// Returns the minimum number of microcredits required to run the finalize.
-pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
+/// Returns the minimum number of microcredits required to run the finalize at the given block height.
+/// Note: The commands in the body of a loop are charged for the maximum number of iterations.
+pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>, block_height: u32) -> Result<u64> {
     // Defines the cost of each command.
     let cost = |command: &Command<N>| match command {
         Command::Instruction(Instruction::Abs(_)) => Ok(2_000),
@@ -115,7 +165,9 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::CommitPED128(_)) => Ok(100_000),
         Command::Instruction(Instruction::Div(_)) => Ok(10_000),
         Command::Instruction(Instruction::DivWrapped(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Decrypt(_)) => bail!("`decrypt` is not supported in finalize."),
         Command::Instruction(Instruction::Double(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Encrypt(_)) => bail!("`encrypt` is not supported in finalize."),
         Command::Instruction(Instruction::GreaterThan(_)) => Ok(2_000),
         Command::Instruction(Instruction::GreaterThanOrEqual(_)) => Ok(2_000),
         Command::Instruction(Instruction::HashBHP256(_)) => Ok(200_000),
@@ -179,9 +231,138 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::GetOrUse(_) => Ok(500_000),
         Command::RandChaCha(_) => Ok(500_000),
         Command::Remove(_) => Ok(10_000),
-        Command::Set(_) => Ok(1_000_000),
+        // Note: Once storage pricing is active, the entries written are charged in finalize,
+        // by `storage_cost_in_microcredits`, instead of each `set` being charged as an insertion.
+        Command::Set(_) => match block_height >= N::STORAGE_PRICING_HEIGHT {
+            true => Ok(10_000),
+            false => Ok(MAPPING_INSERT_COST),
+        },
         Command::BranchEq(_) | Command::BranchNeq(_) => Ok(5_000),
         Command::Position(_) => Ok(1_000),
+        Command::Emit(_) => Ok(100_000),
     };
-    finalize.commands().iter().map(|command| cost(command)).sum()
+    // Charge each command for the maximum number of times it may be executed.
+    finalize.commands().iter().zip_eq(finalize.max_executions()).try_fold(0u64, |total, (command, max_executions)| {
+        let command_cost: u64 = cost(command)?;
+        command_cost
+            .checked_mul(max_executions)
+            .and_then(|cost| total.checked_add(cost))
+            .ok_or(anyhow!("The finalize cost computation overflowed"))
+    })
+}
+
+/// Returns the maximum storage cost in microcredits of the given finalize,
+/// which charges each `set` as an insertion, for the maximum number of times it may be executed.
+pub fn max_storage_cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
+    finalize.commands().iter().zip_eq(finalize.max_executions()).try_fold(0u64, |total, (command, max_executions)| {
+        match command {
+            Command::Set(_) => MAPPING_INSERT_COST
+                .checked_mul(max_executions)
+                .and_then(|cost| total.checked_add(cost))
+                .ok_or(anyhow!("The storage cost computation overflowed")),
+            _ => Ok(total),
+        }
+    })
+}
+
+/// Returns the storage cost in microcredits of the given finalize operations (total cost, (charge, discount)).
+/// Inserting a new entry is charged more than updating an existing entry, and each removed entry
+/// discounts the charge, without the total cost going below zero.
+pub fn storage_cost_in_microcredits<N: Network>(
+    finalize_operations: &[FinalizeOperation<N>],
+) -> Result<(u64, (u64, u64))> {
+    // Count the number of inserted, updated, and removed entries.
+    let (mut num_inserts, mut num_updates, mut num_removes) = (0u64, 0u64, 0u64);
+    for operation in finalize_operations {
+        match operation {
+            FinalizeOperation::InsertKeyValue(..) | FinalizeOperation::InsertSizedKeyValue(..) => num_inserts += 1,
+            FinalizeOperation::UpdateKeyValue(..) | FinalizeOperation::UpdateSizedKeyValue(..) => num_updates += 1,
+            FinalizeOperation::RemoveKeyValue(..) | FinalizeOperation::RemoveSizedKeyValue(..) => num_removes += 1,
+            FinalizeOperation::InitializeMapping(..) | FinalizeOperation::RemoveMapping(..) => (),
+        }
+    }
+
+    // Compute the charge for the inserted and updated entries.
+    let charge = num_inserts
+        .checked_mul(MAPPING_INSERT_COST)
+        .and_then(|insert_cost| num_updates.checked_mul(MAPPING_UPDATE_COST)?.checked_add(insert_cost))
+        .ok_or(anyhow!("The storage cost computation overflowed"))?;
+    // Compute the discount for the removed entries.
+    let discount = num_removes
+        .checked_mul(MAPPING_REMOVE_DISCOUNT)
+        .ok_or(anyhow!("The storage discount computation overflowed"))?;
+
+    // Compute the total cost in microcredits.
+    Ok((charge.saturating_sub(discount), (charge, discount)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::Identifier};
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_cost_in_microcredits_bounded_loop() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program looper.aleo;
+
+mapping counter:
+    key id as u8.public;
+    value count as u8.public;
+
+function run:
+    input r0 as u8.public;
+    finalize r0;
+
+finalize run:
+    input r0 as u8.public;
+    position start;
+    get.or_use counter[0u8] 0u8 into r1;
+    add r1 1u8 into r2;
+    set r2 into counter[0u8];
+    branch.neq r2 r0 to start max 4;",
+        )
+        .unwrap();
+
+        // Retrieve the finalize scope.
+        let function = program.get_function(&Identifier::from_str("run").unwrap()).unwrap();
+        let (_, finalize) = function.finalize().unwrap();
+
+        // Ensure the loop body is charged for the initial pass and the maximum number of iterations.
+        let cost_per_pass = 1_000 + 500_000 + 2_000 + 10_000 + 5_000;
+        let block_height = CurrentNetwork::STORAGE_PRICING_HEIGHT;
+        assert_eq!(cost_in_microcredits(finalize, block_height).unwrap(), 5 * cost_per_pass);
+        // Ensure the `set` is charged as an insertion before storage pricing is active.
+        let legacy_cost_per_pass = cost_per_pass - 10_000 + MAPPING_INSERT_COST;
+        assert_eq!(cost_in_microcredits(finalize, block_height - 1).unwrap(), 5 * legacy_cost_per_pass);
+        // Ensure the `set` in the loop body is charged as an insertion on every pass.
+        assert_eq!(max_storage_cost_in_microcredits(finalize).unwrap(), 5 * MAPPING_INSERT_COST);
+    }
+
+    #[test]
+    fn test_storage_cost_in_microcredits() {
+        let rng = &mut TestRng::default();
+
+        // Sample the finalize operations.
+        let initialize = FinalizeOperation::<CurrentNetwork>::InitializeMapping(Uniform::rand(rng));
+        let (mapping_id, key_id) = (Uniform::rand(rng), Uniform::rand(rng));
+        let insert = FinalizeOperation::InsertSizedKeyValue(mapping_id, key_id, Uniform::rand(rng), 16);
+        let update = FinalizeOperation::UpdateSizedKeyValue(mapping_id, 0, key_id, Uniform::rand(rng), 16, 24);
+        let remove = FinalizeOperation::RemoveSizedKeyValue(mapping_id, 0, 24);
+
+        // Ensure mappings are not charged as entries.
+        assert_eq!(storage_cost_in_microcredits(&[initialize]).unwrap(), (0, (0, 0)));
+        // Ensure new entries are charged more than updates.
+        assert_eq!(storage_cost_in_microcredits(&[insert]).unwrap(), (1_000_000, (1_000_000, 0)));
+        assert_eq!(storage_cost_in_microcredits(&[update]).unwrap(), (100_000, (100_000, 0)));
+        // Ensure removed entries discount the charge.
+        let operations = [initialize, insert, insert, update, remove];
+        assert_eq!(storage_cost_in_microcredits(&operations).unwrap(), (1_600_000, (2_100_000, 500_000)));
+        // Ensure the discount does not take the total cost below zero.
+        assert_eq!(storage_cost_in_microcredits(&[update, remove]).unwrap(), (0, (100_000, 500_000)));
+    }
 }
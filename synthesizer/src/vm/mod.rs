@@ -49,6 +49,9 @@ use aleo_std::prelude::{finish, lap, timer};
 use parking_lot::RwLock;
 use std::sync::Arc;
 
+#[cfg(feature = "history")]
+use synthesizer_program::FinalizeDiff;
+
 #[derive(Clone)]
 pub struct VM<N: Network, C: ConsensusStorage<N>> {
     /// The process.
@@ -127,8 +130,19 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             load_deployment_and_imports(&mut process, transaction_store, *transaction_id)?;
         }
 
+        // Initialize the VM.
+        let vm = Self { process: Arc::new(RwLock::new(process)), store };
+
+        // Synthesize the 'credits.aleo' circuit keys that are not shipped with the parameters.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                $process.synthesize_credits_keys::<$aleo, _>(&mut rand::thread_rng())
+            }};
+        }
+        process!(vm, logic)?;
+
         // Return the new VM.
-        Ok(Self { process: Arc::new(RwLock::new(process)), store })
+        Ok(vm)
     }
 
     /// Returns `true` if a program with the given program ID exists.
@@ -142,6 +156,17 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     pub fn process(&self) -> Arc<RwLock<Process<N>>> {
         self.process.clone()
     }
+
+    /// Returns the finalize diffs for the given confirmed `transaction ID`, resolving each change to a mapping
+    /// entry into its program ID, mapping name, key, old value, and new value.
+    /// Note: The finalize diffs are only recorded when the `history` feature is enabled.
+    #[cfg(feature = "history")]
+    pub fn get_finalize_diffs(&self, transaction_id: &N::TransactionID) -> Result<Vec<FinalizeDiff<N>>> {
+        match self.finalize_store().get_diff(transaction_id)? {
+            Some(finalize_diffs) => Ok(finalize_diffs),
+            None => bail!("Missing finalize diffs for transaction '{transaction_id}'"),
+        }
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -188,7 +213,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Execute the mint function.
                 let transaction = self.execute(private_key, locator, inputs.iter(), None, None, rng)?;
                 // Prepare the confirmed transaction.
-                ConfirmedTransaction::accepted_execute(index, transaction, vec![])
+                ConfirmedTransaction::accepted_execute(index, transaction, vec![], vec![])
             })
             .collect::<Result<Transactions<_>>>()?;
 
@@ -216,6 +241,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
             block.height(),
+            block.timestamp(),
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
@@ -260,7 +286,7 @@ pub(crate) mod test_helpers {
 
     /// Samples a new finalize state.
     pub(crate) fn sample_finalize_state(block_height: u32) -> FinalizeGlobalState {
-        FinalizeGlobalState::from(block_height, [0u8; 32])
+        FinalizeGlobalState::from(block_height, 0, [0u8; 32], [0u8; 32])
     }
 
     pub(crate) fn sample_ratifications_root() -> Field<CurrentNetwork> {
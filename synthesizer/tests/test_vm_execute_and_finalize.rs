@@ -163,7 +163,7 @@ fn test_vm_execute_and_finalize() {
                             output.insert(
                                 serde_yaml::Value::String("speculate".to_string()),
                                 serde_yaml::Value::String(match transactions.iter().next().unwrap() {
-                                    ConfirmedTransaction::AcceptedExecute(_, _, _) => {
+                                    ConfirmedTransaction::AcceptedExecute(_, _, _, _) => {
                                         "the execution was accepted".to_string()
                                     }
                                     ConfirmedTransaction::RejectedExecute(_, _, _) => {
@@ -368,11 +368,14 @@ fn construct_finalize_global_state<C: ConsensusStorage<CurrentNetwork>>(
     let next_round = latest_round.saturating_add(1);
     // Compute the next height.
     let next_height = latest_height.saturating_add(1);
+    // Compute the next timestamp, which matches the timestamp used in `construct_next_block`.
+    let next_timestamp = CurrentNetwork::GENESIS_TIMESTAMP + 1;
 
     // Construct the finalize state.
     FinalizeGlobalState::new::<CurrentNetwork>(
         next_round,
         next_height,
+        next_timestamp,
         latest_cumulative_weight,
         0u128,
         latest_block.hash(),
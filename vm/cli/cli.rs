@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::cli::commands::{Build, Clean, Execute, New, Run, Update};
+use crate::cli::commands::{Account, Build, Clean, Execute, New, Run, Update};
 
 use anstyle::{AnsiColor, Color, Style};
 use anyhow::Result;
 use clap::{builder::Styles, Parser};
 
+#[cfg(all(feature = "history", feature = "rocks"))]
+use crate::cli::commands::Diff;
+
 const HEADER_COLOR: Option<Color> = Some(Color::Ansi(AnsiColor::Yellow));
 const LITERAL_COLOR: Option<Color> = Some(Color::Ansi(AnsiColor::Green));
 const STYLES: Styles = Styles::plain()
@@ -38,10 +41,15 @@ pub struct CLI {
 
 #[derive(Debug, Parser)]
 pub enum Command {
+    #[clap(subcommand)]
+    Account(Account),
     #[clap(name = "build")]
     Build(Build),
     #[clap(name = "clean")]
     Clean(Clean),
+    #[cfg(all(feature = "history", feature = "rocks"))]
+    #[clap(name = "diff")]
+    Diff(Diff),
     #[clap(name = "execute")]
     Execute(Execute),
     #[clap(name = "new")]
@@ -56,8 +64,11 @@ impl Command {
     /// Parse the command.
     pub fn parse(self) -> Result<String> {
         match self {
+            Self::Account(command) => command.parse(),
             Self::Build(command) => command.parse(),
             Self::Clean(command) => command.parse(),
+            #[cfg(all(feature = "history", feature = "rocks"))]
+            Self::Diff(command) => command.parse(),
             Self::Execute(command) => command.parse(),
             Self::New(command) => command.parse(),
             Self::Run(command) => command.parse(),
@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    cli::helpers::{keystore_path, unlock_keystore, write_keystore, PasswordSource},
+    console::account::{Address, Keystore, PrivateKey},
+};
+
+use anyhow::bail;
+use std::{io::BufRead, path::PathBuf};
+
+/// Manages encrypted account keystores
+#[derive(Debug, Parser)]
+pub enum Account {
+    /// Creates a new account in an encrypted keystore
+    #[clap(name = "new")]
+    New {
+        /// The keystore path [default: './keystore.json' or $KEYSTORE].
+        #[clap(long)]
+        keystore: Option<PathBuf>,
+    },
+    /// Imports a private key into an encrypted keystore
+    #[clap(name = "import")]
+    Import {
+        /// Reads the private key from stdin, instead of prompting for it.
+        #[clap(long)]
+        stdin: bool,
+        /// The keystore path [default: './keystore.json' or $KEYSTORE].
+        #[clap(long)]
+        keystore: Option<PathBuf>,
+    },
+    /// Unlocks an encrypted keystore to check its password
+    #[clap(name = "unlock")]
+    Unlock {
+        /// The keystore path [default: './keystore.json' or $KEYSTORE].
+        #[clap(long)]
+        keystore: Option<PathBuf>,
+    },
+}
+
+impl Account {
+    /// Creates, imports, or unlocks an encrypted keystore.
+    pub fn parse(self) -> Result<String> {
+        self.run(&PasswordSource::Prompt)
+    }
+
+    /// Creates, imports, or unlocks an encrypted keystore, with the password from the given source.
+    fn run(self, password: &PasswordSource) -> Result<String> {
+        match self {
+            Self::New { keystore } => {
+                // Sample a new private key.
+                let private_key = PrivateKey::<CurrentNetwork>::new(&mut rand::thread_rng())?;
+                Self::create(&private_key, keystore, password)
+            }
+            Self::Import { stdin, keystore } => {
+                // Read the private key from stdin, or prompt for it.
+                // Note: The private key is never taken as an argument, so it is not recorded in the shell history.
+                let private_key = match stdin {
+                    true => {
+                        let mut line = String::new();
+                        std::io::stdin().lock().read_line(&mut line)?;
+                        line
+                    }
+                    false => rpassword::prompt_password("Private key: ")?,
+                };
+                Self::create(&PrivateKey::from_str(private_key.trim())?, keystore, password)
+            }
+            Self::Unlock { keystore } => {
+                let path = Self::path(keystore)?;
+                // Unlock the keystore.
+                let address = Address::try_from(&unlock_keystore(&path, password)?)?;
+
+                // Prepare the path string.
+                let path_string = format!("(in \"{}\")", path.display());
+
+                Ok(format!("✅ Unlocked the keystore for '{}' {}", address.to_string().bold(), path_string.dimmed()))
+            }
+        }
+    }
+
+    /// Encrypts the given private key into a new keystore.
+    fn create(
+        private_key: &PrivateKey<CurrentNetwork>,
+        keystore: Option<PathBuf>,
+        password: &PasswordSource,
+    ) -> Result<String> {
+        let path = Self::path(keystore)?;
+        // Ensure an existing keystore is not overwritten, before prompting for a password.
+        if path.exists() {
+            bail!("A keystore already exists at '{}'", path.display())
+        }
+
+        // Encrypt the private key.
+        let keystore = Keystore::new(private_key, &password.password(true)?, &mut rand::thread_rng())?;
+        // Write the keystore.
+        write_keystore(&path, &keystore)?;
+
+        // Prepare the path string.
+        let path_string = format!("(in \"{}\")", path.display());
+
+        Ok(format!("✅ Created a keystore for '{}' {}", keystore.address().to_string().bold(), path_string.dimmed()))
+    }
+
+    /// Returns the given keystore path, or the default keystore path.
+    fn path(keystore: Option<PathBuf>) -> Result<PathBuf> {
+        match keystore {
+            Some(path) => Ok(path),
+            None => keystore_path(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::helpers::KEYSTORE_FILE_NAME;
+
+    #[test]
+    fn test_account_keystore() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join(KEYSTORE_FILE_NAME);
+        let password = PasswordSource::Given("correct horse battery staple".to_string());
+
+        // Import a private key into a new keystore.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rand::thread_rng()).unwrap();
+        assert!(Account::create(&private_key, Some(path.clone()), &password).is_ok());
+        assert_eq!(private_key, unlock_keystore(&path, &password).unwrap());
+
+        // Ensure the keystore is not overwritten.
+        assert!(Account::New { keystore: Some(path.clone()) }.run(&password).is_err());
+        assert_eq!(private_key, unlock_keystore(&path, &password).unwrap());
+
+        // Ensure the keystore is only accessible by its owner.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+
+        // Ensure the keystore unlocks with its password.
+        assert!(Account::Unlock { keystore: Some(path.clone()) }.run(&password).is_ok());
+
+        // Ensure the keystore does not unlock with a different password.
+        let password = PasswordSource::Given("incorrect horse battery staple".to_string());
+        assert!(Account::Unlock { keystore: Some(path) }.run(&password).is_err());
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    console::network::Network,
+    ledger::store::{helpers::rocksdb::FinalizeDB, FinalizeStore},
+    synthesizer::program::FinalizeDiff,
+};
+
+use anyhow::bail;
+
+/// Prints the mapping changes of a confirmed transaction, from a local ledger that records the finalize history
+#[derive(Debug, Parser)]
+pub struct Diff {
+    /// The confirmed transaction ID.
+    transaction_id: <CurrentNetwork as Network>::TransactionID,
+    /// Uses the ledger of the specified development node.
+    #[clap(long)]
+    dev: Option<u16>,
+    /// Prints the diff as JSON.
+    #[clap(long)]
+    json: bool,
+}
+
+impl Diff {
+    /// Resolves the finalize diff of the transaction from the local ledger.
+    /// Note: The ledger must have been synced with the `history` feature, and must not be open in another process.
+    pub fn parse(self) -> Result<String> {
+        // Open the finalize store of the local ledger.
+        let store = FinalizeStore::<CurrentNetwork, FinalizeDB<CurrentNetwork>>::open(self.dev)?;
+
+        // Retrieve the finalize diff.
+        let diffs = match store.get_diff(&self.transaction_id)? {
+            Some(diffs) => diffs,
+            None => bail!("Missing the finalize diff for transaction '{}'", self.transaction_id),
+        };
+
+        match self.json {
+            true => Ok(serde_json::to_string_pretty(&diffs)?),
+            false => Ok(Self::render(&self.transaction_id, &diffs)),
+        }
+    }
+
+    /// Renders the finalize diff as one line for each changed mapping entry.
+    fn render(
+        transaction_id: &<CurrentNetwork as Network>::TransactionID,
+        diffs: &[FinalizeDiff<CurrentNetwork>],
+    ) -> String {
+        let mut output = format!("🔎 Finalize diff of '{}'\n", transaction_id.to_string().bold());
+        if diffs.is_empty() {
+            output.push_str(&format!("\n{}", " •  No mapping entries were changed".dimmed()));
+        }
+        for diff in diffs {
+            // Prepare the mapping entry string.
+            let entry = format!("{}/{}[{}]", diff.program_id(), diff.mapping_name(), diff.key());
+            // Prepare the change string.
+            let change = match (diff.old_value(), diff.new_value()) {
+                (None, Some(new_value)) => format!("{} {new_value}", "inserted".green()),
+                (Some(old_value), Some(new_value)) => format!("{} {old_value} → {new_value}", "updated".yellow()),
+                (Some(old_value), None) => format!("{} {old_value}", "removed".red()),
+                (None, None) => unreachable!("A finalize diff must contain an old or new value"),
+            };
+            output.push_str(&format!("\n •  {} {change}", entry.bold()));
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::program::{Plaintext, Value};
+
+    #[test]
+    fn test_render() {
+        let transaction_id = <CurrentNetwork as Network>::TransactionID::default();
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("1field").unwrap();
+        let (value_1, value_2) = (Value::from_str("1u64").unwrap(), Value::from_str("2u64").unwrap());
+
+        let diffs = vec![
+            FinalizeDiff::new(program_id, mapping_name, key.clone(), None, Some(value_1.clone())).unwrap(),
+            FinalizeDiff::new(program_id, mapping_name, key.clone(), Some(value_1), Some(value_2.clone())).unwrap(),
+            FinalizeDiff::new(program_id, mapping_name, key, Some(value_2), None).unwrap(),
+        ];
+
+        // Ensure each change is rendered on its own line.
+        colored::control::set_override(false);
+        let output = Diff::render(&transaction_id, &diffs);
+        let lines = output.lines().skip(2).collect::<Vec<_>>();
+        assert_eq!(lines, [
+            " •  token.aleo/account[1field] inserted 1u64",
+            " •  token.aleo/account[1field] updated 1u64 → 2u64",
+            " •  token.aleo/account[1field] removed 2u64",
+        ]);
+
+        // Ensure an empty diff is rendered.
+        assert!(Diff::render(&transaction_id, &[]).contains("No mapping entries were changed"));
+    }
+}
@@ -12,12 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod account;
+pub use account::*;
+
 pub mod build;
 pub use build::*;
 
 pub mod clean;
 pub use clean::*;
 
+#[cfg(all(feature = "history", feature = "rocks"))]
+pub mod diff;
+#[cfg(all(feature = "history", feature = "rocks"))]
+pub use diff::*;
+
 pub mod execute;
 pub use execute::*;
 
@@ -35,7 +35,11 @@ impl Run {
         // Load the package.
         let package = Package::open(&path)?;
         // Load the private key.
-        let private_key = crate::cli::helpers::dotenv_private_key()?;
+        let (private_key, keystore) =
+            crate::cli::helpers::load_private_key(&crate::cli::helpers::PasswordSource::Prompt)?;
+        if let Some(keystore) = keystore {
+            println!("🔐 Unlocked the keystore at '{}'\n", keystore.display());
+        }
 
         // Initialize an RNG.
         let rng = &mut rand::thread_rng();
@@ -30,7 +30,7 @@ fn dotenv_load() -> Result<()> {
     // Fails if .env file not found, not readable or invalid.
     dotenvy::dotenv().map_err(|_| {
         anyhow!(
-            "Missing a '.env' file. Create the '.env' file in your package's root directory with the following:\n\n{}\n",
+            "Missing a '.env' file. Create the '.env' file in your package's root directory with the following:\n\n{}\n\nOr create an encrypted keystore with 'snarkvm account new'.\n",
             env_template()
         )
     })?;
@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    cli::CurrentNetwork,
+    console::account::{Keystore, PrivateKey},
+};
+
+use anyhow::{anyhow, bail, Result};
+use core::str::FromStr;
+use std::{
+    fs::OpenOptions,
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+/// The file name of the keystore in the package's root directory.
+pub const KEYSTORE_FILE_NAME: &str = "keystore.json";
+
+/// Returns the keystore path, from the `KEYSTORE` environment variable if it is set,
+/// or in the current directory otherwise.
+pub fn keystore_path() -> Result<PathBuf> {
+    match std::env::var_os("KEYSTORE") {
+        Some(path) => Ok(PathBuf::from(path)),
+        None => Ok(std::env::current_dir()?.join(KEYSTORE_FILE_NAME)),
+    }
+}
+
+/// The source of a keystore password.
+#[derive(Clone, Debug)]
+pub enum PasswordSource {
+    /// Reads the password from the `KEYSTORE_PASSWORD` environment variable if it is set,
+    /// or prompts for it otherwise.
+    Prompt,
+    /// Uses the given password.
+    Given(String),
+}
+
+impl PasswordSource {
+    /// Returns the keystore password. If `confirm` is set, a prompted password is asked for twice,
+    /// and the password is checked to be suitable for a new keystore.
+    pub fn password(&self, confirm: bool) -> Result<String> {
+        let password = match self {
+            Self::Prompt => match std::env::var("KEYSTORE_PASSWORD") {
+                Ok(password) => password,
+                Err(_) => {
+                    let password = rpassword::prompt_password("Keystore password: ")?;
+                    if confirm && rpassword::prompt_password("Confirm keystore password: ")? != password {
+                        bail!("The keystore passwords do not match")
+                    }
+                    password
+                }
+            },
+            Self::Given(password) => password.clone(),
+        };
+        if confirm {
+            ensure_password(&password)?;
+        }
+        Ok(password)
+    }
+}
+
+/// Ensures the given password is suitable for a new keystore.
+fn ensure_password(password: &str) -> Result<()> {
+    match password.chars().count() < 8 {
+        true => bail!("The keystore password must be at least 8 characters"),
+        false => Ok(()),
+    }
+}
+
+/// Reads the keystore at the given path.
+pub fn read_keystore(path: &Path) -> Result<Keystore<CurrentNetwork>> {
+    let keystore = std::fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read the keystore at '{}' - {e}", path.display()))?;
+    Keystore::from_str(&keystore)
+}
+
+/// Writes the given keystore to the given path, without overwriting an existing file.
+/// On unix, the keystore is only readable and writable by its owner.
+pub fn write_keystore(path: &Path, keystore: &Keystore<CurrentNetwork>) -> Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(path).map_err(|e| match e.kind() {
+        ErrorKind::AlreadyExists => anyhow!("A keystore already exists at '{}'", path.display()),
+        _ => anyhow!("Failed to create the keystore at '{}' - {e}", path.display()),
+    })?;
+    file.write_all(serde_json::to_string_pretty(keystore)?.as_bytes())?;
+    Ok(())
+}
+
+/// Unlocks the keystore at the given path, with the password from the given source.
+pub fn unlock_keystore(path: &Path, password: &PasswordSource) -> Result<PrivateKey<CurrentNetwork>> {
+    read_keystore(path)?.decrypt(&password.password(false)?)
+}
+
+/// Loads the private key from the keystore if one exists, or from the '.env' file otherwise.
+/// Returns the keystore path with the private key, if the private key was unlocked from a keystore.
+pub fn load_private_key(password: &PasswordSource) -> Result<(PrivateKey<CurrentNetwork>, Option<PathBuf>)> {
+    let path = keystore_path()?;
+    match path.exists() {
+        true => Ok((unlock_keystore(&path, password)?, Some(path))),
+        false => Ok((crate::cli::helpers::dotenv_private_key()?, None)),
+    }
+}
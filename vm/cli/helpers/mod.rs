@@ -15,5 +15,8 @@
 pub mod env;
 pub use env::*;
 
+pub mod keystore;
+pub use keystore::*;
+
 pub mod updater;
 pub use updater::*;